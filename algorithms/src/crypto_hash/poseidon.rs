@@ -88,6 +88,15 @@ impl<F: PrimeField, const RATE: usize> Poseidon<F, RATE> {
         sponge.squeeze_native_field_elements(num_outputs).to_vec()
     }
 
+    /// Evaluate the cryptographic hash function over a list of field elements as input,
+    /// with the given domain separator placed in the capacity element of the initial state.
+    pub fn evaluate_with_domain(&self, domain: &F, input: &[F]) -> F {
+        let mut sponge = PoseidonSponge::<F, RATE, 1>::new_with_parameters(&self.parameters);
+        sponge.state.capacity_state[0] = *domain;
+        sponge.absorb_native_field_elements(input);
+        sponge.squeeze_native_field_elements(1)[0]
+    }
+
     /// Evaluate the cryptographic hash function over a non-fixed-length vector,
     /// in which the length also needs to be hashed.
     pub fn evaluate_with_len(&self, input: &[F]) -> F {
@@ -13,7 +13,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{crypto_hash::PoseidonSponge, AlgebraicSponge, DuplexSpongeMode};
+use crate::{
+    crypto_hash::{Poseidon, PoseidonSponge},
+    AlgebraicSponge,
+    DuplexSpongeMode,
+};
 use snarkvm_curves::bls12_377::Fr;
 use snarkvm_fields::{PoseidonDefaultField, PoseidonGrainLFSR};
 
@@ -70,6 +74,21 @@ fn test_poseidon_sponge_consistency() {
     }
 }
 
+#[test]
+fn test_poseidon_evaluate_with_domain() {
+    const RATE: usize = 4;
+    let poseidon = Poseidon::<Fr, RATE>::setup();
+    let input = [Fr::from(1u64), Fr::from(2u64), Fr::from(3u64)];
+
+    // A zero domain matches the initial state of `evaluate`.
+    assert_eq!(poseidon.evaluate_with_domain(&Fr::from(0u64), &input), poseidon.evaluate(&input));
+    // Distinct domains produce distinct digests.
+    assert_ne!(
+        poseidon.evaluate_with_domain(&Fr::from(1u64), &input),
+        poseidon.evaluate_with_domain(&Fr::from(2u64), &input)
+    );
+}
+
 #[test]
 fn bls12_377_fr_poseidon_default_parameters_test() {
     fn single_rate_test<const RATE: usize>() {
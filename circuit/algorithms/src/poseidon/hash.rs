@@ -15,12 +15,25 @@
 
 use super::*;
 
-impl<E: Environment, const RATE: usize> Hash for Poseidon<E, RATE> {
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> Hash for Poseidon<E, RATE, CAPACITY> {
     type Input = Field<E>;
     type Output = Field<E>;
 
+    /// Returns the cryptographic hash for a list of field elements as input.
+    ///
+    /// If every input is a constant, the digest is computed natively,
+    /// and is returned as a constant that adds no variables or constraints.
     #[inline]
     fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // If every input is a constant, compute the digest natively.
+        #[cfg(console)]
+        if input.iter().all(|element| element.is_constant()) {
+            let input = input.eject_value();
+            match console::Hash::hash(&self.native, &input) {
+                Ok(digest) => return Field::constant(digest),
+                Err(error) => E::halt(format!("Failed to compute the native Poseidon hash: {error}")),
+            }
+        }
         self.hash_many(input, 1).swap_remove(0)
     }
 }
@@ -115,4 +128,89 @@ mod tests {
         check_hash(Mode::Private, 9, 1, 0, 1060, 1060, &mut rng)?;
         check_hash(Mode::Private, 10, 1, 0, 1060, 1060, &mut rng)
     }
+
+    #[test]
+    fn test_hash_constant_is_folded() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native);
+
+        let native_input =
+            (0..10).map(|_| console::Field::<<Circuit as Environment>::Network>::rand(&mut rng)).collect::<Vec<_>>();
+
+        // Compute the hash over the witnessed input.
+        let input = native_input.iter().map(|v| Field::<Circuit>::new(Mode::Private, *v)).collect::<Vec<_>>();
+        let expected = poseidon.hash(&input).eject_value();
+        Circuit::reset();
+
+        // Compute the hash over the constant input.
+        let input = native_input.iter().map(|v| Field::<Circuit>::new(Mode::Constant, *v)).collect::<Vec<_>>();
+        Circuit::scope("Poseidon constant folding", || {
+            let candidate = poseidon.hash(&input);
+            // Ensure the digest is a constant, and matches the witnessed path.
+            assert!(candidate.is_constant());
+            assert_eq!(expected, candidate.eject_value());
+            // Ensure no variables or constraints were added.
+            assert_scope!(1, 0, 0, 0);
+        });
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_capacity_2() -> Result<()> {
+        use console::Hash as H;
+
+        let mut rng = TestRng::default();
+
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE, 2>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE, 2>::constant(native.clone());
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_inputs in 0..=2 * RATE {
+                // Prepare the preimage.
+                let native_input = (0..num_inputs)
+                    .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(&mut rng))
+                    .collect::<Vec<_>>();
+                let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+                // Ensure the circuit hash matches the native capacity-2 hash.
+                let expected = native.hash(&native_input)?;
+                let candidate = poseidon.hash(&input);
+                assert_eq!(expected, candidate.eject_value(), "(mode = {mode}, num_inputs = {num_inputs})");
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_rate_16() -> Result<()> {
+        use console::Hash as H;
+
+        let mut rng = TestRng::default();
+
+        let native = console::Poseidon16::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let poseidon = Poseidon16::<Circuit>::constant(native.clone());
+
+        for i in 0..100 {
+            // Prepare a preimage of varying length, spanning up to three rate-16 chunks.
+            let mode = [Mode::Constant, Mode::Public, Mode::Private][i % 3];
+            let num_inputs = rng.gen_range(0..=48);
+            let native_input = (0..num_inputs)
+                .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(&mut rng))
+                .collect::<Vec<_>>();
+            let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Ensure the circuit hash matches the native hash.
+            let expected = native.hash(&native_input)?;
+            let candidate = poseidon.hash(&input);
+            assert_eq!(expected, candidate.eject_value(), "(mode = {mode}, num_inputs = {num_inputs})");
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
 }
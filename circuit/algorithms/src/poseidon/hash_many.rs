@@ -15,7 +15,7 @@
 
 use super::*;
 
-impl<E: Environment, const RATE: usize> HashMany for Poseidon<E, RATE> {
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> HashMany for Poseidon<E, RATE, CAPACITY> {
     type Input = Field<E>;
     type Output = Field<E>;
 
@@ -39,10 +39,10 @@ impl<E: Environment, const RATE: usize> HashMany for Poseidon<E, RATE> {
 }
 
 #[allow(clippy::needless_borrow)]
-impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> Poseidon<E, RATE, CAPACITY> {
     /// Absorbs the input elements into state.
     #[inline]
-    fn absorb(&self, state: &mut [Field<E>], mode: &mut DuplexSpongeMode, input: &[Field<E>]) {
+    pub(super) fn absorb(&self, state: &mut [Field<E>], mode: &mut DuplexSpongeMode, input: &[Field<E>]) {
         if !input.is_empty() {
             // Determine the absorb index.
             let (mut absorb_index, should_permute) = match *mode {
@@ -55,7 +55,7 @@ impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
 
             // Proceed to permute the state, if necessary.
             if should_permute {
-                self.permute(state);
+                self.permute_in_place(state);
             }
 
             let mut remaining = input;
@@ -77,7 +77,7 @@ impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
                 remaining.iter().enumerate().take(num_absorbed).for_each(|(i, element)| state[start + i] += element);
 
                 // Permute the state.
-                self.permute(state);
+                self.permute_in_place(state);
 
                 // Repeat with the updated input slice and absorb index.
                 remaining = &remaining[num_absorbed..];
@@ -88,7 +88,12 @@ impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
 
     /// Squeeze the specified number of state elements into the output.
     #[inline]
-    fn squeeze(&self, state: &mut [Field<E>], mode: &mut DuplexSpongeMode, num_outputs: u16) -> Vec<Field<E>> {
+    pub(super) fn squeeze(
+        &self,
+        state: &mut [Field<E>],
+        mode: &mut DuplexSpongeMode,
+        num_outputs: u16,
+    ) -> Vec<Field<E>> {
         let mut output = vec![Field::zero(); num_outputs as usize];
         if num_outputs != 0 {
             self.squeeze_internal(state, mode, &mut output);
@@ -98,7 +103,12 @@ impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
 
     /// Squeeze the state elements into the output.
     #[inline]
-    fn squeeze_internal(&self, state: &mut [Field<E>], mode: &mut DuplexSpongeMode, output: &mut [Field<E>]) {
+    pub(super) fn squeeze_internal(
+        &self,
+        state: &mut [Field<E>],
+        mode: &mut DuplexSpongeMode,
+        output: &mut [Field<E>],
+    ) {
         // Determine the squeeze index.
         let (mut squeeze_index, should_permute) = match *mode {
             DuplexSpongeMode::Absorbing { .. } => (0, true),
@@ -110,7 +120,7 @@ impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
 
         // Proceed to permute the state, if necessary.
         if should_permute {
-            self.permute(state);
+            self.permute_in_place(state);
         }
 
         let mut remaining = output;
@@ -132,7 +142,7 @@ impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
             remaining[..num_squeezed].clone_from_slice(&state[start..(start + num_squeezed)]);
 
             // Permute.
-            self.permute(state);
+            self.permute_in_place(state);
 
             // Repeat with the updated output slice and squeeze index.
             remaining = &mut remaining[num_squeezed..];
@@ -178,7 +188,7 @@ impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
 
     /// Apply the permutation for all rounds in-place.
     #[inline]
-    fn permute(&self, state: &mut [Field<E>]) {
+    pub(super) fn permute_in_place(&self, state: &mut [Field<E>]) {
         // Determine the partial rounds range bound.
         let full_rounds_over_2 = self.full_rounds / 2;
         let partial_round_range = full_rounds_over_2..(full_rounds_over_2 + self.partial_rounds);
@@ -186,9 +196,13 @@ impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
         // Iterate through all rounds to permute.
         for i in 0..(self.partial_rounds + self.full_rounds) {
             let is_full_round = !partial_round_range.contains(&i);
+            #[cfg(feature = "debug")]
+            let num_constraints = E::num_constraints();
             self.apply_ark(state, i);
             self.apply_s_box(state, is_full_round);
             self.apply_mds(state);
+            #[cfg(feature = "debug")]
+            self.record_round_cost(is_full_round, E::num_constraints() - num_constraints);
         }
     }
 }
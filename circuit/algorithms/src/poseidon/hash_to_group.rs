@@ -15,12 +15,17 @@
 
 use super::*;
 
-impl<E: Environment, const RATE: usize> HashToGroup for Poseidon<E, RATE> {
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> HashToGroup for Poseidon<E, RATE, CAPACITY> {
     type Group = Group<E>;
     type Input = Field<E>;
     type Scalar = Scalar<E>;
 
     /// Returns an affine group element from hashing the input.
+    ///
+    /// The output is `Elligator2(h0) + Elligator2(h1)`, for `(h0, h1) := HashMany(input, 2)`, matching the native
+    /// `hash_to_group`. Unlike scaling the generator by a hashed scalar, this does not reveal the discrete logarithm
+    /// of the output with respect to the generator. Elligator2 clears the cofactor, so the output is in the
+    /// prime-order subgroup.
     #[inline]
     fn hash_to_group(&self, input: &[Self::Input]) -> Self::Group {
         // Ensure that the input is not empty.
@@ -85,6 +90,31 @@ mod tests {
         }};
     }
 
+    #[test]
+    fn test_hash_to_group_matches_console_for_varying_lengths() -> Result<()> {
+        let native = console::Poseidon4::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = Poseidon4::<Circuit>::constant(native.clone());
+
+        let rng = &mut TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_fields in 1..=8 {
+                // Sample a random input, and compute the expected hash.
+                let input = (0..num_fields).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+                let expected = console::HashToGroup::hash_to_group(&native, &input)?;
+
+                // Ensure the circuit hash matches, and is in the prime-order subgroup.
+                let circuit_input: Vec<Field<_>> = Inject::new(mode, input);
+                let candidate = circuit.hash_to_group(&circuit_input).eject_value();
+                assert_eq!(expected, candidate, "(mode = {mode}, num_fields = {num_fields})");
+                assert!((*candidate).to_affine().is_in_correct_subgroup_assuming_on_curve());
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_poseidon2_hash_to_group_constant() -> Result<()> {
         check_hash_to_group!(Poseidon2, Constant, 2, (1059, 0, 0, 0))
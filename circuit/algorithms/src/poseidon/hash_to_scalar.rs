@@ -15,7 +15,7 @@
 
 use super::*;
 
-impl<E: Environment, const RATE: usize> HashToScalar for Poseidon<E, RATE> {
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> HashToScalar for Poseidon<E, RATE, CAPACITY> {
     type Input = Field<E>;
     type Scalar = Scalar<E>;
 
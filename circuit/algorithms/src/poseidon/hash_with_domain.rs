@@ -0,0 +1,89 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> Poseidon<E, RATE, CAPACITY> {
+    /// Returns the cryptographic hash for a list of field elements as input,
+    /// with the given domain separator placed in the first capacity element of the initial state.
+    ///
+    /// Unlike `hash`, the preimage is not prefixed with a domain separator or the input length,
+    /// which matches `Poseidon::evaluate_with_domain` in `snarkvm_algorithms`.
+    pub fn hash_with_domain(&self, domain: &Field<E>, input: &[Field<E>]) -> Field<E> {
+        // Initialize a new sponge, with the domain separator in the first capacity element.
+        let mut state = vec![Field::zero(); RATE + CAPACITY];
+        state[0] = domain.clone();
+        let mut mode = DuplexSpongeMode::Absorbing { next_absorb_index: 0 };
+
+        // Absorb the input and squeeze the output.
+        self.absorb(&mut state, &mut mode, input);
+        self.squeeze(&mut state, &mut mode, 1).swap_remove(0)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "PoseidonCircuit0";
+    const ITERATIONS: usize = 10;
+    const RATE: usize = 4;
+
+    #[test]
+    fn test_hash_with_domain() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let native = snarkvm_algorithms::crypto_hash::Poseidon::<<Circuit as Environment>::BaseField, RATE>::setup();
+        let poseidon = Poseidon::<Circuit, RATE>::constant(console::Poseidon::setup(DOMAIN)?);
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_inputs in 0..=2 * RATE {
+                // Prepare the preimage.
+                let native_input = (0..num_inputs)
+                    .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(&mut rng))
+                    .collect::<Vec<_>>();
+                let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+                let mut digests = Vec::with_capacity(ITERATIONS);
+                let mut num_constraints = Vec::with_capacity(ITERATIONS);
+                for _ in 0..ITERATIONS {
+                    let native_domain = console::Field::<<Circuit as Environment>::Network>::rand(&mut rng);
+                    let domain = Field::<Circuit>::new(mode, native_domain);
+
+                    // Compute the circuit hash, along with its constraint count.
+                    let start = Circuit::num_constraints();
+                    let candidate = poseidon.hash_with_domain(&domain, &input);
+                    num_constraints.push(Circuit::num_constraints() - start);
+
+                    // Ensure the output matches the native hash.
+                    let native_input = native_input.iter().map(|v| **v).collect::<Vec<_>>();
+                    assert_eq!(native.evaluate_with_domain(&native_domain, &native_input), *candidate.eject_value());
+                    digests.push(candidate.eject_value());
+                }
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+
+                // Ensure distinct domains produce distinct digests.
+                assert!(digests.iter().all_unique(), "Digests collided across domains");
+                // Ensure the constraint count does not depend on the domain.
+                assert!(num_constraints.iter().all_equal(), "Constraint counts differ across domains");
+            }
+        }
+        Ok(())
+    }
+}
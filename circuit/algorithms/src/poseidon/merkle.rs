@@ -0,0 +1,158 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_circuit_types::Boolean;
+
+/// A Merkle path verifier over Poseidon, where each parent is the hash of its `(left, right)` children.
+///
+/// Unlike a `MerklePath` in the collections crate, the path is of any length, and the leaf is not hashed
+/// before it is combined with its first sibling, so a leaf is a node of the tree as is.
+#[derive(Clone)]
+pub struct PoseidonMerkle<E: Environment, const RATE: usize> {
+    /// The Poseidon hash function, which computes each parent from its children.
+    poseidon: Poseidon<E, RATE>,
+}
+
+impl<E: Environment, const RATE: usize> PoseidonMerkle<E, RATE> {
+    /// Initializes a new Poseidon Merkle path verifier.
+    pub const fn new(poseidon: Poseidon<E, RATE>) -> Self {
+        Self { poseidon }
+    }
+
+    /// Returns the parent of the given child nodes, as `Poseidon::hash(&[left, right])`.
+    pub fn hash_children(&self, left: &Field<E>, right: &Field<E>) -> Field<E> {
+        self.poseidon.hash(&[left.clone(), right.clone()])
+    }
+
+    /// Returns `true` if the path of `siblings`, from the leaf level up, leads from `leaf` to `root`.
+    ///
+    /// On each level, the index bit is `true` if the current node is the right child, in which case the
+    /// level hashes `(sibling, current)`, and `false` if it is the left child, in which case it hashes
+    /// `(current, sibling)`. The path and the indices must be of the same length.
+    pub fn verify_path(&self, leaf: Field<E>, path: &[Field<E>], indices: &[Boolean<E>], root: Field<E>) -> Boolean<E> {
+        // Ensure there is an index bit for each level of the path.
+        if path.len() != indices.len() {
+            E::halt(format!("Found a Merkle path of {} levels with {} index bits", path.len(), indices.len()))
+        }
+
+        // Compute the hash of each level, starting from the leaf.
+        let current = path.iter().zip(indices).fold(leaf, |current, (sibling, is_right)| {
+            // Order the current node and its sibling by the index bit.
+            let left = Field::ternary(is_right, sibling, &current);
+            let right = Field::ternary(is_right, &current, sibling);
+            // Compute the parent.
+            self.hash_children(&left, &right)
+        });
+
+        // Ensure the final hash matches the given root.
+        root.is_equal(&current)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    type NativeField = console::Field<<Circuit as Environment>::Network>;
+    type NativePoseidon = console::Poseidon<<Circuit as Environment>::Network, RATE>;
+
+    const DOMAIN: &str = "PoseidonCircuit0";
+    const RATE: usize = 2;
+
+    /// Returns the levels of a native Merkle tree over the given leaves, from the leaves up to the root.
+    fn native_tree(native: &NativePoseidon, leaves: &[NativeField]) -> Result<Vec<Vec<NativeField>>> {
+        use console::Hash as H;
+
+        let mut levels = vec![leaves.to_vec()];
+        while levels[levels.len() - 1].len() > 1 {
+            let level = levels[levels.len() - 1].chunks(2).map(|pair| native.hash(pair)).collect::<Result<Vec<_>>>()?;
+            levels.push(level);
+        }
+        Ok(levels)
+    }
+
+    /// Returns the siblings and the index bits of the leaf at the given index, from the leaf level up.
+    fn native_path(levels: &[Vec<NativeField>], mut index: usize) -> (Vec<NativeField>, Vec<bool>) {
+        let mut path = Vec::with_capacity(levels.len() - 1);
+        let mut indices = Vec::with_capacity(levels.len() - 1);
+        for level in &levels[..levels.len() - 1] {
+            path.push(level[index ^ 1]);
+            indices.push(index & 1 == 1);
+            index /= 2;
+        }
+        (path, indices)
+    }
+
+    #[test]
+    fn test_verify_path() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let native = NativePoseidon::setup(DOMAIN)?;
+        let merkle = PoseidonMerkle::new(Poseidon::<Circuit, RATE>::constant(native.clone()));
+
+        for depth in 0..=3 {
+            // Compute a native tree over known leaves.
+            let leaves = (0..1u64 << depth).map(NativeField::from_u64).collect::<Vec<_>>();
+            let levels = native_tree(&native, &leaves)?;
+            let native_root = levels[depth][0];
+
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                for (index, native_leaf) in leaves.iter().enumerate() {
+                    let (native_path, native_indices) = native_path(&levels, index);
+
+                    // Inject the leaf, the path, the index bits, and the root.
+                    let leaf = Field::<Circuit>::new(mode, *native_leaf);
+                    let path = native_path.iter().map(|node| Field::new(mode, *node)).collect::<Vec<_>>();
+                    let indices = native_indices.iter().map(|bit| Boolean::new(mode, *bit)).collect::<Vec<_>>();
+                    let root = Field::<Circuit>::new(mode, native_root);
+
+                    // Ensure the path is valid for the leaf.
+                    let candidate = merkle.verify_path(leaf.clone(), &path, &indices, root.clone());
+                    assert!(candidate.eject_value(), "(mode = {mode}, depth = {depth}, index = {index})");
+
+                    // Ensure the path is invalid for another leaf.
+                    let other_leaf = Field::new(mode, NativeField::rand(&mut rng));
+                    assert!(!merkle.verify_path(other_leaf, &path, &indices, root.clone()).eject_value());
+                    // Ensure the path is invalid for another root.
+                    let other_root = Field::new(mode, NativeField::rand(&mut rng));
+                    assert!(!merkle.verify_path(leaf.clone(), &path, &indices, other_root).eject_value());
+                    // Ensure the path is invalid with a flipped index bit.
+                    if depth > 0 {
+                        let mut flipped = indices.clone();
+                        flipped[0] = !&flipped[0];
+                        assert!(!merkle.verify_path(leaf, &path, &flipped, root).eject_value());
+                    }
+
+                    assert!(Circuit::is_satisfied());
+                    Circuit::reset();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "Found a Merkle path of 2 levels with 1 index bits")]
+    fn test_verify_path_mismatched_indices() {
+        let merkle = PoseidonMerkle::new(Poseidon::<Circuit, RATE>::constant(NativePoseidon::setup(DOMAIN).unwrap()));
+
+        // Ensure a path with a missing index bit is rejected.
+        merkle.verify_path(Field::zero(), &[Field::zero(), Field::zero()], &[Boolean::constant(false)], Field::zero());
+    }
+}
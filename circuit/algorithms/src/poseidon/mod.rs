@@ -17,7 +17,16 @@ mod hash;
 mod hash_many;
 mod hash_to_group;
 mod hash_to_scalar;
+mod hash_with_domain;
+mod merkle;
+mod permute;
 mod prf;
+#[cfg(feature = "debug")]
+mod round_costs;
+mod sponge;
+
+pub use merkle::PoseidonMerkle;
+pub use sponge::*;
 
 #[cfg(all(test, console))]
 use snarkvm_circuit_types::environment::assert_scope;
@@ -33,8 +42,11 @@ pub type Poseidon2<E> = Poseidon<E, 2>;
 pub type Poseidon4<E> = Poseidon<E, 4>;
 /// Poseidon8 is a cryptographic hash function of input rate 8.
 pub type Poseidon8<E> = Poseidon<E, 8>;
-
-const CAPACITY: usize = 1;
+/// Poseidon16 is a cryptographic hash function of input rate 16.
+///
+/// Note: The digest differs from that of `Poseidon8`, including when the input is split into two rate-8 chunks,
+/// as the permutation width and round constants depend on the rate.
+pub type Poseidon16<E> = Poseidon<E, 16>;
 
 /// The mode structure for duplex sponges.
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -51,8 +63,11 @@ pub enum DuplexSpongeMode {
     },
 }
 
+/// The Poseidon hash function, of input rate `RATE` and capacity `CAPACITY` (which defaults to 1).
+///
+/// The permutation operates on a state of `RATE + CAPACITY` field elements.
 #[derive(Clone)]
-pub struct Poseidon<E: Environment, const RATE: usize> {
+pub struct Poseidon<E: Environment, const RATE: usize, const CAPACITY: usize = 1> {
     /// The domain separator for the Poseidon hash function.
     domain: Field<E>,
     /// The number of rounds in a full-round operation.
@@ -66,11 +81,17 @@ pub struct Poseidon<E: Environment, const RATE: usize> {
     ark: Vec<Vec<Field<E>>>,
     /// The Maximally Distance Separating (MDS) matrix.
     mds: Vec<Vec<Field<E>>>,
+    /// The native Poseidon instance, for hashing inputs that are all constants.
+    #[cfg(console)]
+    native: console::Poseidon<E::Network, RATE, CAPACITY>,
+    /// The number of constraints accumulated in the full and partial rounds, if round costs are being recorded.
+    #[cfg(feature = "debug")]
+    round_costs: core::cell::Cell<Option<(u64, u64)>>,
 }
 
 #[cfg(console)]
-impl<E: Environment, const RATE: usize> Inject for Poseidon<E, RATE> {
-    type Primitive = console::Poseidon<E::Network, RATE>;
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> Inject for Poseidon<E, RATE, CAPACITY> {
+    type Primitive = console::Poseidon<E::Network, RATE, CAPACITY>;
 
     fn new(_mode: Mode, poseidon: Self::Primitive) -> Self {
         // Initialize the domain separator.
@@ -80,6 +101,7 @@ impl<E: Environment, const RATE: usize> Inject for Poseidon<E, RATE> {
         let parameters = poseidon.parameters();
         let full_rounds = parameters.full_rounds;
         let partial_rounds = parameters.partial_rounds;
+
         let alpha = Field::constant(console::Field::from_u128(parameters.alpha as u128));
         // Cache the bits for the field element.
         alpha.to_bits_le();
@@ -88,18 +110,56 @@ impl<E: Environment, const RATE: usize> Inject for Poseidon<E, RATE> {
             .iter()
             .take(full_rounds + partial_rounds)
             .map(|round| {
-                round.iter().take(RATE + 1).copied().map(|field| Field::constant(console::Field::new(field))).collect()
+                round
+                    .iter()
+                    .take(RATE + CAPACITY)
+                    .copied()
+                    .map(|field| Field::constant(console::Field::new(field)))
+                    .collect()
             })
             .collect();
         let mds = parameters
             .mds
             .iter()
-            .take(RATE + 1)
+            .take(RATE + CAPACITY)
             .map(|round| {
-                round.iter().take(RATE + 1).copied().map(|field| Field::constant(console::Field::new(field))).collect()
+                round
+                    .iter()
+                    .take(RATE + CAPACITY)
+                    .copied()
+                    .map(|field| Field::constant(console::Field::new(field)))
+                    .collect()
             })
             .collect();
 
-        Self { domain, full_rounds, partial_rounds, alpha, ark, mds }
+        Self {
+            domain,
+            full_rounds,
+            partial_rounds,
+            alpha,
+            ark,
+            mds,
+            native: poseidon,
+            #[cfg(feature = "debug")]
+            round_costs: Default::default(),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "PoseidonCircuit0";
+    const RATE: usize = 4;
+
+    #[test]
+    fn test_new_accepts_default_parameters() -> Result<()> {
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let _ = Poseidon::<Circuit, RATE>::constant(native);
+        Ok(())
     }
 }
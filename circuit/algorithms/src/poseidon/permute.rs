@@ -0,0 +1,77 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> Poseidon<E, RATE, CAPACITY> {
+    /// Applies the Poseidon permutation once to the given state of `RATE + CAPACITY` field elements,
+    /// and returns the permuted state.
+    ///
+    /// The state is ordered as the capacity elements followed by the rate elements, as in the sponge.
+    /// This allows building custom gadgets (e.g. a Merkle tree of a specific arity) on the permutation.
+    pub fn permute(&self, state: &[Field<E>]) -> Vec<Field<E>> {
+        // Ensure the state is of the correct size.
+        if state.len() != RATE + CAPACITY {
+            E::halt(format!("Poseidon{RATE} expects a state of {} elements, found {}", RATE + CAPACITY, state.len()))
+        }
+        let mut state = state.to_vec();
+        self.permute_in_place(&mut state);
+        state
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "PoseidonCircuit0";
+    const RATE: usize = 4;
+
+    #[test]
+    fn test_permute() -> Result<()> {
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native.clone());
+
+        // Prepare a known state.
+        let native_state = (0..RATE + 1)
+            .map(|i| console::Field::<<Circuit as Environment>::Network>::from_u64(i as u64))
+            .collect::<Vec<_>>();
+        // Compute two native permutations.
+        let expected = native.permute(&native.permute(&native_state)?)?;
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let state = native_state.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Ensure two circuit permutations match the two native permutations exactly.
+            let candidate = poseidon.permute(&poseidon.permute(&state));
+            assert_eq!(expected, candidate.eject_value(), "(mode = {mode})");
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "Poseidon4 expects a state of 5 elements, found 4")]
+    fn test_permute_wrong_size() {
+        let poseidon = Poseidon::<Circuit, RATE>::constant(console::Poseidon::setup(DOMAIN).unwrap());
+
+        // Ensure a state of the wrong size is rejected.
+        poseidon.permute(&vec![Field::zero(); RATE]);
+    }
+}
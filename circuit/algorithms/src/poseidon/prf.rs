@@ -15,7 +15,7 @@
 
 use super::*;
 
-impl<E: Environment, const RATE: usize> PRF for Poseidon<E, RATE> {
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> PRF for Poseidon<E, RATE, CAPACITY> {
     type Input = Field<E>;
     type Output = Field<E>;
     type Seed = Field<E>;
@@ -32,6 +32,28 @@ impl<E: Environment, const RATE: usize> PRF for Poseidon<E, RATE> {
     }
 }
 
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> Poseidon<E, RATE, CAPACITY> {
+    /// Returns `num_outputs` PRF outputs for the given seed and input, e.g. to derive a keystream.
+    ///
+    /// The preimage `seed || input` is absorbed as in `prf`, and the outputs are squeezed from the sponge,
+    /// so the first output is equal to `prf(seed, input)`.
+    pub fn prf_many(&self, seed: &Field<E>, input: &[Field<E>], num_outputs: usize) -> Vec<Field<E>> {
+        // Ensure the number of outputs is within the bounds of the sponge.
+        let num_outputs = match u16::try_from(num_outputs) {
+            Ok(num_outputs) => num_outputs,
+            Err(_) => E::halt(format!("Cannot squeeze {num_outputs} PRF outputs, the maximum is {}", u16::MAX)),
+        };
+
+        // Construct the preimage: seed || input.
+        let mut preimage = Vec::with_capacity(1 + input.len());
+        preimage.push(seed.clone());
+        preimage.extend_from_slice(input);
+
+        // Hash the preimage to derive the PRF outputs.
+        self.hash_many(&preimage, num_outputs)
+    }
+}
+
 #[cfg(all(test, console))]
 mod tests {
     use super::*;
@@ -82,6 +104,50 @@ mod tests {
         Ok(())
     }
 
+    fn check_prf_many(mode: Mode, num_inputs: usize, num_outputs: usize, rng: &mut TestRng) -> Result<()> {
+        use console::PRF as P;
+
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Prepare the seed.
+            let native_seed = Uniform::rand(rng);
+            let seed = Field::new(mode, native_seed);
+
+            // Prepare the preimage.
+            let native_input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+            let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native outputs.
+            let expected = native.prf_many(&native_seed, &native_input, num_outputs)?;
+            assert_eq!(expected[0], native.prf(&native_seed, &native_input)?);
+
+            // Compute the circuit outputs.
+            Circuit::scope(format!("Poseidon PRF many {mode} {i}"), || {
+                let candidate = poseidon.prf_many(&seed, &input, num_outputs);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_prf_many() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_inputs in [0, 1, RATE, 2 * RATE + 1] {
+                for num_outputs in [1, 2, RATE + 1] {
+                    check_prf_many(mode, num_inputs, num_outputs, &mut rng)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_prf_constant() -> Result<()> {
         let mut rng = TestRng::default();
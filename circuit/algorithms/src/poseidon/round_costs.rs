@@ -0,0 +1,95 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> Poseidon<E, RATE, CAPACITY> {
+    /// Returns the cryptographic hash for a list of field elements as input, as in `Hash::hash`,
+    /// along with the number of constraints synthesized in the full rounds and in the partial rounds.
+    ///
+    /// As the additive round keys and the MDS matrix are linear, the constraints of a permutation come
+    /// entirely from its S-boxes. This allows a developer to weigh the cost of full and partial rounds
+    /// when tuning the Poseidon parameters.
+    pub fn hash_with_round_costs(&self, input: &[Field<E>]) -> (Field<E>, u64, u64) {
+        self.round_costs.set(Some((0, 0)));
+        let output = self.hash(input);
+        let (full_round_costs, partial_round_costs) = self.round_costs.take().unwrap_or_default();
+        (output, full_round_costs, partial_round_costs)
+    }
+
+    /// Records the number of constraints synthesized in a round, if round costs are being recorded.
+    pub(super) fn record_round_cost(&self, is_full_round: bool, num_constraints: u64) {
+        if let Some((full_round_costs, partial_round_costs)) = self.round_costs.get() {
+            self.round_costs.set(Some(match is_full_round {
+                true => (full_round_costs + num_constraints, partial_round_costs),
+                false => (full_round_costs, partial_round_costs + num_constraints),
+            }));
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "PoseidonCircuit0";
+    const RATE: usize = 4;
+
+    #[test]
+    fn test_hash_with_round_costs() -> Result<()> {
+        use console::Hash as H;
+
+        let mut rng = TestRng::default();
+
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native.clone());
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_inputs in 0..=2 * RATE {
+                // Prepare the preimage.
+                let native_input = (0..num_inputs)
+                    .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(&mut rng))
+                    .collect::<Vec<_>>();
+                let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+                // Compute the circuit hash, along with the round costs.
+                let num_constraints = Circuit::num_constraints();
+                let (candidate, full_round_costs, partial_round_costs) = poseidon.hash_with_round_costs(&input);
+                let num_constraints = Circuit::num_constraints() - num_constraints;
+
+                // Ensure the output matches the native hash.
+                assert_eq!(native.hash(&native_input)?, candidate.eject_value());
+                // Ensure the round costs sum to the overall count.
+                assert_eq!(full_round_costs + partial_round_costs, num_constraints);
+                if mode.is_constant() || num_inputs == 0 {
+                    assert_eq!((full_round_costs, partial_round_costs), (0, 0));
+                } else {
+                    assert!(full_round_costs > 0 && partial_round_costs > 0);
+                }
+                Circuit::reset();
+            }
+        }
+
+        // Ensure the round costs are only recorded when requested.
+        let input = [Field::<Circuit>::new(Mode::Private, console::Field::rand(&mut rng))];
+        poseidon.hash(&input);
+        assert_eq!(poseidon.round_costs.get(), None);
+        Circuit::reset();
+        Ok(())
+    }
+}
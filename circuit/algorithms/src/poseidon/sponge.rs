@@ -0,0 +1,150 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A duplex sponge over the Poseidon permutation, for protocols that interleave
+/// the absorption of inputs with the squeezing of outputs (e.g. Fiat-Shamir transcripts).
+#[derive(Clone)]
+pub struct PoseidonSponge<E: Environment, const RATE: usize, const CAPACITY: usize = 1> {
+    /// The Poseidon permutation.
+    poseidon: Poseidon<E, RATE, CAPACITY>,
+    /// The sponge state, as `[ CAPACITY || RATE ]` field elements.
+    state: Vec<Field<E>>,
+    /// The current mode (absorbing or squeezing).
+    mode: DuplexSpongeMode,
+}
+
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> Poseidon<E, RATE, CAPACITY> {
+    /// Initializes a new sponge, for interleaving the absorption of inputs with the squeezing of outputs.
+    ///
+    /// Note: Unlike `hash`, the sponge does not absorb the domain separator or the input length.
+    pub fn start_sponge(&self) -> PoseidonSponge<E, RATE, CAPACITY> {
+        PoseidonSponge {
+            poseidon: self.clone(),
+            state: vec![Field::zero(); RATE + CAPACITY],
+            mode: DuplexSpongeMode::Absorbing { next_absorb_index: 0 },
+        }
+    }
+}
+
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> PoseidonSponge<E, RATE, CAPACITY> {
+    /// Absorbs the given input elements into the sponge.
+    pub fn absorb(&mut self, input: &[Field<E>]) {
+        self.poseidon.absorb(&mut self.state, &mut self.mode, input);
+    }
+
+    /// Squeezes the given number of field elements from the sponge.
+    pub fn squeeze(&mut self, num_elements: usize) -> Vec<Field<E>> {
+        let mut output = vec![Field::zero(); num_elements];
+        if num_elements != 0 {
+            self.poseidon.squeeze_internal(&mut self.state, &mut self.mode, &mut output);
+        }
+        output
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "PoseidonCircuit0";
+    const RATE: usize = 4;
+
+    #[test]
+    fn test_sponge_matches_native() -> Result<()> {
+        use console::AlgebraicSponge;
+
+        let mut rng = TestRng::default();
+
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native.clone());
+
+        // A mixed sequence of absorbs and squeezes, as `(num_absorbed, num_squeezed)` pairs.
+        let transcript = [(1, 1), (RATE, 2), (0, RATE + 1), (2 * RATE + 1, 0), (3, RATE), (0, 1)];
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let mut native_sponge = native.start_sponge();
+            let mut sponge = poseidon.start_sponge();
+
+            for (num_absorbed, num_squeezed) in transcript {
+                // Absorb the input.
+                let native_input = (0..num_absorbed)
+                    .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(&mut rng))
+                    .collect::<Vec<_>>();
+                let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+                native_sponge.absorb(&native_input);
+                sponge.absorb(&input);
+
+                // Squeeze the output, and ensure it matches the native sponge.
+                let expected = native_sponge.squeeze(num_squeezed as u16);
+                let candidate = sponge.squeeze(num_squeezed);
+                assert_eq!(expected.to_vec(), candidate.eject_value());
+            }
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sponge_permutes_once_per_block() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native);
+        let input =
+            (0..RATE).map(|_| Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng))).collect::<Vec<_>>();
+
+        let mut sponge = poseidon.start_sponge();
+
+        // Absorbing a rate-sized block does not permute the state.
+        let num_constraints = Circuit::num_constraints();
+        sponge.absorb(&input);
+        assert_eq!(Circuit::num_constraints(), num_constraints);
+        assert_eq!(sponge.mode, DuplexSpongeMode::Absorbing { next_absorb_index: RATE });
+
+        // Squeezing flips the mode and permutes the state once.
+        let num_constraints = Circuit::num_constraints();
+        sponge.squeeze(1);
+        assert!(Circuit::num_constraints() > num_constraints);
+        assert_eq!(sponge.mode, DuplexSpongeMode::Squeezing { next_squeeze_index: 1 });
+
+        // Squeezing the rest of the block does not permute the state.
+        let num_constraints = Circuit::num_constraints();
+        sponge.squeeze(RATE - 1);
+        assert_eq!(Circuit::num_constraints(), num_constraints);
+        assert_eq!(sponge.mode, DuplexSpongeMode::Squeezing { next_squeeze_index: RATE });
+
+        // Squeezing past the block permutes the state once more.
+        let num_constraints = Circuit::num_constraints();
+        sponge.squeeze(1);
+        assert!(Circuit::num_constraints() > num_constraints);
+        assert_eq!(sponge.mode, DuplexSpongeMode::Squeezing { next_squeeze_index: 1 });
+
+        // Absorbing after squeezing flips the mode and permutes the state once.
+        let num_constraints = Circuit::num_constraints();
+        sponge.absorb(&input[..1]);
+        assert!(Circuit::num_constraints() > num_constraints);
+        assert_eq!(sponge.mode, DuplexSpongeMode::Absorbing { next_absorb_index: 1 });
+
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+}
@@ -37,6 +37,7 @@ use snarkvm_circuit_algorithms::{
     BHP256,
     BHP512,
     BHP768,
+    PRF,
 };
 use snarkvm_circuit_collections::merkle_tree::MerklePath;
 use snarkvm_circuit_types::{
@@ -301,6 +302,11 @@ impl Aleo for AleoCanaryV0 {
         POSEIDON_8.with(|poseidon| poseidon.hash_many(input, num_outputs))
     }
 
+    /// Returns the Poseidon PRF with an input rate of 2, on the given seed and input.
+    fn prf_psd2(seed: &Field<Self>, input: &[Field<Self>]) -> Field<Self> {
+        POSEIDON_2.with(|poseidon| poseidon.prf(seed, input))
+    }
+
     /// Returns the BHP hash with an input hasher of 256-bits.
     fn hash_to_group_bhp256(input: &[Boolean<Self>]) -> Group<Self> {
         BHP_256.with(|bhp| bhp.hash_uncompressed(input))
@@ -138,6 +138,9 @@ pub trait Aleo: Environment {
     /// Returns the extended Poseidon hash with an input rate of 8.
     fn hash_many_psd8(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>>;
 
+    /// Returns the Poseidon PRF with an input rate of 2, on the given seed and input.
+    fn prf_psd2(seed: &Field<Self>, input: &[Field<Self>]) -> Field<Self>;
+
     /// Returns the BHP hash with an input hasher of 256-bits.
     fn hash_to_group_bhp256(input: &[Boolean<Self>]) -> Group<Self>;
 
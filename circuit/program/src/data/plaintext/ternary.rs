@@ -0,0 +1,111 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> Ternary for Plaintext<A> {
+    type Boolean = Boolean<A>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    ///
+    /// Note: `first` and `second` must have the same structure; structs and arrays are selected member-wise.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        match (first, second) {
+            (Self::Literal(a, _), Self::Literal(b, _)) => {
+                Self::Literal(Literal::ternary(condition, a, b), Default::default())
+            }
+            (Self::Struct(a, _), Self::Struct(b, _)) => {
+                // Ensure the structs have the same number of members.
+                if a.len() != b.len() {
+                    A::halt(format!("Ternary expects structs with the same members, found {} and {}", a.len(), b.len()))
+                }
+                // Select each member.
+                let members = a
+                    .iter()
+                    .zip_eq(b.iter())
+                    .map(|((name_a, plaintext_a), (name_b, plaintext_b))| {
+                        // Ensure the member names match. Note: identifiers are constant, so this adds no constraints.
+                        if !name_a.is_equal(name_b).eject_value() {
+                            A::halt("Ternary expects structs with the same member names")
+                        }
+                        (name_a.clone(), Plaintext::ternary(condition, plaintext_a, plaintext_b))
+                    })
+                    .collect();
+                Self::Struct(members, Default::default())
+            }
+            (Self::Array(a, _), Self::Array(b, _)) => {
+                // Ensure the arrays have the same length.
+                if a.len() != b.len() {
+                    A::halt(format!("Ternary expects arrays of the same length, found {} and {}", a.len(), b.len()))
+                }
+                // Select each element.
+                let elements = a
+                    .iter()
+                    .zip_eq(b.iter())
+                    .map(|(plaintext_a, plaintext_b)| Plaintext::ternary(condition, plaintext_a, plaintext_b))
+                    .collect();
+                Self::Array(elements, Default::default())
+            }
+            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => {
+                A::halt("Ternary expects plaintexts with the same structure")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    fn sample_plaintext(mode: Mode, string: &str) -> Plaintext<Circuit> {
+        let plaintext = console::Plaintext::<<Circuit as Environment>::Network>::from_str(string).unwrap();
+        Plaintext::new(mode, plaintext)
+    }
+
+    fn check_ternary(condition_mode: Mode, mode: Mode) {
+        let first = sample_plaintext(mode, "{ a: true, b: 1field, c: { d: [1u8, 2u8] } }");
+        let second = sample_plaintext(mode, "{ a: false, b: 2field, c: { d: [3u8, 4u8] } }");
+
+        for flag in [true, false] {
+            Circuit::scope(format!("{condition_mode} {mode} {flag}"), || {
+                let condition = Boolean::new(condition_mode, flag);
+                let candidate = Plaintext::ternary(&condition, &first, &second);
+                let expected = if flag { &first } else { &second };
+                assert!(candidate.is_equal(expected).eject_value());
+                assert_eq!(expected.eject_value(), candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_ternary() {
+        for condition_mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_ternary(condition_mode, mode);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ternary_mismatched_structure() {
+        let first = sample_plaintext(Mode::Private, "{ a: true, b: 1field }");
+        let second = sample_plaintext(Mode::Private, "{ a: true, c: 1field }");
+        let _ = Plaintext::ternary(&Boolean::new(Mode::Private, true), &first, &second);
+    }
+}
@@ -29,6 +29,7 @@ mod find;
 mod num_randomizers;
 mod serial_number;
 mod tag;
+mod ternary;
 mod to_bits;
 mod to_commitment;
 mod to_fields;
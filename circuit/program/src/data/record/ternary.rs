@@ -0,0 +1,67 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_circuit_types::Address;
+
+impl<A: Aleo> Ternary for Record<A, Plaintext<A>> {
+    type Boolean = Boolean<A>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    ///
+    /// Note: `first` and `second` must have the same entries and visibilities; each entry is selected member-wise.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        // Select the owner.
+        let owner = match (&first.owner, &second.owner) {
+            (Owner::Public(a), Owner::Public(b)) => Owner::Public(Address::ternary(condition, a, b)),
+            (Owner::Private(a), Owner::Private(b)) => Owner::Private(Plaintext::ternary(condition, a, b)),
+            _ => A::halt("Ternary expects records with the same owner visibility"),
+        };
+
+        // Ensure the records have the same number of entries.
+        if first.data.len() != second.data.len() {
+            A::halt(format!(
+                "Ternary expects records with the same entries, found {} and {}",
+                first.data.len(),
+                second.data.len()
+            ))
+        }
+        // Select each entry.
+        let data = first
+            .data
+            .iter()
+            .zip_eq(second.data.iter())
+            .map(|((name_a, entry_a), (name_b, entry_b))| {
+                // Ensure the entry names match. Note: identifiers are constant, so this adds no constraints.
+                if !name_a.is_equal(name_b).eject_value() {
+                    A::halt("Ternary expects records with the same entry names")
+                }
+                let entry = match (entry_a, entry_b) {
+                    (Entry::Constant(a), Entry::Constant(b)) => Entry::Constant(Plaintext::ternary(condition, a, b)),
+                    (Entry::Public(a), Entry::Public(b)) => Entry::Public(Plaintext::ternary(condition, a, b)),
+                    (Entry::Private(a), Entry::Private(b)) => Entry::Private(Plaintext::ternary(condition, a, b)),
+                    _ => A::halt("Ternary expects records with the same entry visibilities"),
+                };
+                (name_a.clone(), entry)
+            })
+            .collect();
+
+        // Select the nonce.
+        let nonce = Group::ternary(condition, &first.nonce, &second.nonce);
+
+        Self { owner, data, nonce }
+    }
+}
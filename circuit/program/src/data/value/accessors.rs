@@ -0,0 +1,132 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> Value<A> {
+    /// Returns the plaintext, if the value is a plaintext.
+    pub const fn as_plaintext(&self) -> Option<&Plaintext<A>> {
+        match self {
+            Self::Plaintext(plaintext) => Some(plaintext),
+            _ => None,
+        }
+    }
+
+    /// Returns the record, if the value is a record.
+    pub const fn as_record(&self) -> Option<&Record<A, Plaintext<A>>> {
+        match self {
+            Self::Record(record) => Some(record),
+            _ => None,
+        }
+    }
+
+    /// Returns the future, if the value is a future.
+    pub const fn as_future(&self) -> Option<&Future<A>> {
+        match self {
+            Self::Future(future) => Some(future),
+            _ => None,
+        }
+    }
+
+    /// Returns the literal, if the value is a plaintext literal.
+    pub const fn as_literal(&self) -> Option<&Literal<A>> {
+        match self {
+            Self::Plaintext(Plaintext::Literal(literal, ..)) => Some(literal),
+            _ => None,
+        }
+    }
+
+    /// Returns the address, if the value is an address literal.
+    pub const fn as_address(&self) -> Option<&Address<A>> {
+        match self.as_literal() {
+            Some(Literal::Address(address)) => Some(address),
+            _ => None,
+        }
+    }
+
+    /// Returns the boolean, if the value is a boolean literal.
+    pub const fn as_boolean(&self) -> Option<&Boolean<A>> {
+        match self.as_literal() {
+            Some(Literal::Boolean(boolean)) => Some(boolean),
+            _ => None,
+        }
+    }
+
+    /// Returns the field, if the value is a field literal.
+    pub const fn as_field(&self) -> Option<&Field<A>> {
+        match self.as_literal() {
+            Some(Literal::Field(field)) => Some(field),
+            _ => None,
+        }
+    }
+
+    /// Returns the group, if the value is a group literal.
+    pub const fn as_group(&self) -> Option<&Group<A>> {
+        match self.as_literal() {
+            Some(Literal::Group(group)) => Some(group),
+            _ => None,
+        }
+    }
+
+    /// Returns the scalar, if the value is a scalar literal.
+    pub const fn as_scalar(&self) -> Option<&Scalar<A>> {
+        match self.as_literal() {
+            Some(Literal::Scalar(scalar)) => Some(scalar),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    #[test]
+    fn test_accessors() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Commit to a random input, as a `commit.bhp256` execution does.
+        let input = Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+        let randomizer = Scalar::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+        let commitment = Circuit::commit_bhp256(&input.to_bits_le(), &randomizer);
+        let value = Value::Plaintext(Plaintext::Literal(Literal::Field(commitment.clone()), Default::default()));
+
+        // Ensure the field literal is extracted.
+        assert_eq!(value.as_field().map(Eject::eject_value), Some(commitment.eject_value()));
+        assert!(value.as_plaintext().is_some());
+        assert!(value.as_literal().is_some());
+        assert!(value.as_record().is_none());
+        assert!(value.as_future().is_none());
+        assert!(value.as_group().is_none());
+        assert!(value.as_scalar().is_none());
+        assert!(value.as_address().is_none());
+        assert!(value.as_boolean().is_none());
+
+        // Ensure the record is extracted.
+        let record = console::Record::<<Circuit as Environment>::Network, console::Plaintext<_>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, _nonce: 0group.public }",
+        )?;
+        let value = Value::<Circuit>::new(Mode::Private, console::Value::Record(record.clone()));
+        assert_eq!(value.as_record().map(Eject::eject_value), Some(record));
+        assert!(value.as_plaintext().is_none());
+        assert!(value.as_literal().is_none());
+        assert!(value.as_field().is_none());
+        Ok(())
+    }
+}
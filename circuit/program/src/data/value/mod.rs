@@ -13,14 +13,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod accessors;
 mod equal;
 mod find;
+mod ternary;
 mod to_bits;
 mod to_fields;
 
-use crate::{Access, Entry, Future, Plaintext, Record};
+use crate::{Access, Entry, Future, Literal, Plaintext, Record};
 use snarkvm_circuit_network::Aleo;
-use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field};
+use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Field, Group, Scalar};
 
 #[derive(Clone)]
 pub enum Value<A: Aleo> {
@@ -0,0 +1,33 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> Ternary for Value<A> {
+    type Boolean = Boolean<A>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        match (first, second) {
+            (Self::Plaintext(a), Self::Plaintext(b)) => Self::Plaintext(Plaintext::ternary(condition, a, b)),
+            (Self::Record(a), Self::Record(b)) => Self::Record(Record::ternary(condition, a, b)),
+            (Self::Future(..), Self::Future(..)) => A::halt("Ternary is not supported on futures"),
+            (Self::Plaintext(..), _) | (Self::Record(..), _) | (Self::Future(..), _) => {
+                A::halt("Ternary expects values of the same variant")
+            }
+        }
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> AbsDiff<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns the absolute difference of `self` and `other`.
+    #[inline]
+    fn abs_diff(&self, other: &Integer<E, I>) -> Self::Output {
+        // Select the larger and smaller of the two values.
+        let is_less_than = self.is_less_than(other);
+        let larger = Self::ternary(&is_less_than, other, self);
+        let smaller = Self::ternary(&is_less_than, self, other);
+        // Subtract the smaller value from the larger value, which only overflows for signed integers.
+        larger.sub_checked(&smaller)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 64;
+
+    fn check_abs_diff<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let (larger, smaller) = if first >= second { (first, second) } else { (second, first) };
+        match larger.checked_sub(&smaller) {
+            Some(expected) => Circuit::scope(name, || {
+                let candidate = a.abs_diff(&b);
+                assert_eq!(expected, *candidate.eject_value());
+                assert_eq!(candidate.eject_value(), b.abs_diff(&a).eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            }),
+            None => match mode_a.is_constant() && mode_b.is_constant() {
+                true => check_operation_halts(&a, &b, Integer::abs_diff),
+                false => Circuit::scope(name, || {
+                    let _candidate = a.abs_diff(&b);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("AbsDiff: {mode_a} - {mode_b} {i}");
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            check_abs_diff::<I>(&name, first, second, mode_a, mode_b);
+        }
+
+        // Ordered and reversed operands.
+        check_abs_diff::<I>("MAX - 0", console::Integer::MAX, console::Integer::zero(), mode_a, mode_b);
+        check_abs_diff::<I>("0 - MAX", console::Integer::zero(), console::Integer::MAX, mode_a, mode_b);
+        // Overflow
+        if I::is_signed() {
+            check_abs_diff::<I>("MAX - MIN", console::Integer::MAX, console::Integer::MIN, mode_a, mode_b);
+        }
+    }
+
+    test_integer_binary!(run_test, i8, abs_diff);
+    test_integer_binary!(run_test, i16, abs_diff);
+    test_integer_binary!(run_test, i32, abs_diff);
+    test_integer_binary!(run_test, i64, abs_diff);
+    test_integer_binary!(run_test, i128, abs_diff);
+
+    test_integer_binary!(run_test, u8, abs_diff);
+    test_integer_binary!(run_test, u16, abs_diff);
+    test_integer_binary!(run_test, u32, abs_diff);
+    test_integer_binary!(run_test, u64, abs_diff);
+    test_integer_binary!(run_test, u128, abs_diff);
+}
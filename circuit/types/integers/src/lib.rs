@@ -20,6 +20,7 @@
 mod helpers;
 
 pub mod abs_checked;
+pub mod abs_diff;
 pub mod abs_wrapped;
 pub mod add_checked;
 pub mod add_wrapped;
@@ -16,8 +16,9 @@
 #[macro_use]
 extern crate criterion;
 
-use snarkvm_console_account::{Address, PrivateKey, ViewKey};
+use snarkvm_console_account::{Address, ComputeKey, PrivateKey, Signature, ViewKey};
 use snarkvm_console_network::{environment::prelude::*, MainnetV0};
+use snarkvm_console_types::Field;
 
 use criterion::Criterion;
 
@@ -57,10 +58,37 @@ fn account_address(c: &mut Criterion) {
     });
 }
 
+fn account_sign_with(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    const NUM_SIGNS: usize = 1000;
+
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let message = (0..4).map(|_| Field::rand(rng)).collect::<Vec<_>>();
+
+    c.bench_function(&format!("account_sign_{NUM_SIGNS}"), |b| {
+        b.iter(|| {
+            for _ in 0..NUM_SIGNS {
+                let _signature = Signature::sign(&private_key, &message, rng).unwrap();
+            }
+        })
+    });
+
+    c.bench_function(&format!("account_sign_with_{NUM_SIGNS}"), |b| {
+        b.iter(|| {
+            // Derive the compute key once, for all of the signatures.
+            let compute_key = ComputeKey::try_from(&private_key).unwrap();
+            for _ in 0..NUM_SIGNS {
+                let _signature = Signature::sign_with(&compute_key, &private_key.sk_sig(), &message, rng).unwrap();
+            }
+        })
+    });
+}
+
 criterion_group! {
     name = account;
     config = Criterion::default().sample_size(20);
-    targets = account_private_key, account_view_key, account_address
+    targets = account_private_key, account_view_key, account_address, account_sign_with
 }
 
 criterion_main!(account);
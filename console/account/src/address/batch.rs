@@ -0,0 +1,65 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// A trait for deriving the account addresses of many private keys at once.
+pub trait BatchFromPrivateKeys<N: Network>: Sized {
+    /// Derives the account address of each of the given private keys, in the same order.
+    ///
+    /// This is equivalent to calling `Address::try_from` on each private key,
+    /// and fails if the address of any of the private keys cannot be derived.
+    fn batch_from_private_keys(private_keys: &[PrivateKey<N>]) -> Result<Vec<Self>>;
+}
+
+impl<N: Network> BatchFromPrivateKeys<N> for Address<N> {
+    /// Derives the account address of each of the given private keys, in the same order.
+    fn batch_from_private_keys(private_keys: &[PrivateKey<N>]) -> Result<Vec<Self>> {
+        cfg_iter!(private_keys).map(Self::try_from).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_batch_from_private_keys() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the private keys.
+        let private_keys =
+            (0..ITERATIONS).map(|_| PrivateKey::<CurrentNetwork>::new(&mut rng)).collect::<Result<Vec<_>>>()?;
+
+        // Ensure the batch derivation matches the sequential derivation.
+        let addresses = Address::batch_from_private_keys(&private_keys)?;
+        assert_eq!(addresses.len(), private_keys.len());
+        for (private_key, address) in private_keys.iter().zip_eq(&addresses) {
+            assert_eq!(Address::try_from(private_key)?, *address);
+        }
+
+        // Ensure an empty batch is supported.
+        assert!(Address::<CurrentNetwork>::batch_from_private_keys(&[])?.is_empty());
+        Ok(())
+    }
+}
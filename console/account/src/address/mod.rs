@@ -13,6 +13,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "private_key")]
+mod batch;
+#[cfg(feature = "private_key")]
+pub use batch::BatchFromPrivateKeys;
+
 mod try_from;
 
 #[cfg(feature = "compute_key")]
@@ -21,6 +21,8 @@
 pub use snarkvm_console_types::{environment::prelude::*, Address, Field, Group, Scalar};
 
 mod address;
+#[cfg(feature = "private_key")]
+pub use address::BatchFromPrivateKeys;
 
 #[cfg(feature = "compute_key")]
 pub mod compute_key;
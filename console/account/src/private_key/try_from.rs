@@ -14,7 +14,7 @@
 // limitations under the License.
 
 use super::*;
-use snarkvm_console_types::Field;
+use snarkvm_console_types::{Field, Scalar};
 
 static ACCOUNT_SK_SIG_DOMAIN: &str = "AleoAccountSignatureSecretKey0";
 static ACCOUNT_R_SIG_DOMAIN: &str = "AleoAccountSignatureRandomizer0";
@@ -23,6 +23,9 @@ impl<N: Network> PrivateKey<N> {
     /// Returns the account private key from an account seed.
     #[inline]
     pub fn try_from(seed: Field<N>) -> Result<Self> {
+        // Ensure the account seed is not zero.
+        ensure!(!seed.is_zero(), "The account seed must be nonzero");
+
         // Construct the sk_sig domain separator.
         let sk_sig_domain = Field::<N>::new_domain_separator(ACCOUNT_SK_SIG_DOMAIN);
 
@@ -30,10 +33,54 @@ impl<N: Network> PrivateKey<N> {
         let r_sig_input = format!("{}.{}", ACCOUNT_R_SIG_DOMAIN, 0);
         let r_sig_domain = Field::new_domain_separator(&r_sig_input);
 
-        Ok(Self {
-            seed,
-            sk_sig: N::hash_to_scalar_psd2(&[sk_sig_domain, seed])?,
-            r_sig: N::hash_to_scalar_psd2(&[r_sig_domain, seed])?,
-        })
+        // Derive the signature secret key and randomizer.
+        let sk_sig = N::hash_to_scalar_psd2(&[sk_sig_domain, seed])?;
+        let r_sig = N::hash_to_scalar_psd2(&[r_sig_domain, seed])?;
+
+        // Ensure the derived scalars are not weak, as signatures under them would be forgeable.
+        ensure!(!is_weak_scalar(&sk_sig), "The derived signature secret key is weak");
+        ensure!(!is_weak_scalar(&r_sig), "The derived signature randomizer is weak");
+
+        Ok(Self { seed, sk_sig, r_sig })
+    }
+}
+
+/// Returns `true` if the given scalar is zero or one, whose public counterparts are trivially known.
+fn is_weak_scalar<N: Network>(scalar: &Scalar<N>) -> bool {
+    scalar.is_zero() || scalar.is_one()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_try_from() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Ensure a zero seed is rejected.
+        assert!(PrivateKey::<CurrentNetwork>::try_from(Field::zero()).is_err());
+
+        for _ in 0..ITERATIONS {
+            // Ensure a random seed is accepted.
+            let seed = Field::rand(&mut rng);
+            let private_key = PrivateKey::<CurrentNetwork>::try_from(seed)?;
+            assert_eq!(seed, private_key.seed());
+            assert!(!is_weak_scalar(&private_key.sk_sig()));
+            assert!(!is_weak_scalar(&private_key.r_sig()));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_weak_scalar() {
+        assert!(is_weak_scalar::<CurrentNetwork>(&Scalar::zero()));
+        assert!(is_weak_scalar::<CurrentNetwork>(&Scalar::one()));
+        assert!(!is_weak_scalar::<CurrentNetwork>(&(Scalar::one() + Scalar::one())));
     }
 }
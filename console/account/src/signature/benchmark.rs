@@ -0,0 +1,80 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::time::{Duration, Instant};
+
+impl<N: Network> Signature<N> {
+    /// Returns the time taken to verify `n` random signatures.
+    ///
+    /// The signatures are sampled before the timer starts. Use `Signature::signatures_per_second`
+    /// to convert the elapsed time into a throughput.
+    pub fn benchmark_verify<R: Rng + CryptoRng>(n: usize, rng: &mut R) -> Result<Duration> {
+        let batch = Self::sample_benchmark_batch(n, rng)?;
+
+        // Verify each signature.
+        let timer = Instant::now();
+        let is_valid = batch.iter().all(|(address, message, signature)| signature.verify(address, message));
+        let elapsed = timer.elapsed();
+
+        ensure!(is_valid, "Failed to verify a sampled signature");
+        Ok(elapsed)
+    }
+
+    /// Returns the number of signatures verified per second, given `n` signatures verified in `elapsed` time.
+    pub fn signatures_per_second(n: usize, elapsed: Duration) -> f64 {
+        n as f64 / elapsed.as_secs_f64()
+    }
+
+    /// Samples `n` signatures, each by a new private key over a 4-field message.
+    fn sample_benchmark_batch<R: Rng + CryptoRng>(
+        n: usize,
+        rng: &mut R,
+    ) -> Result<Vec<(Address<N>, Vec<Field<N>>, Signature<N>)>> {
+        (0..n)
+            .map(|_| {
+                let private_key = PrivateKey::<N>::new(rng)?;
+                let message = (0..4).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+                let signature = Signature::sign(&private_key, &message, rng)?;
+                Ok((Address::try_from(&private_key)?, message, signature))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const NUM_SIGNATURES: usize = 16;
+
+    #[test]
+    fn test_benchmark_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Ensure every sampled signature verifies.
+        Signature::<CurrentNetwork>::benchmark_verify(NUM_SIGNATURES, rng)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_signatures_per_second() {
+        assert_eq!(Signature::<CurrentNetwork>::signatures_per_second(10, Duration::from_secs(2)), 5.0);
+    }
+}
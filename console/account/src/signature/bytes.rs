@@ -60,4 +60,53 @@ mod tests {
         }
         Ok(())
     }
+
+    /// Returns the little-endian encoding of `scalar + MODULUS`, which is congruent to the scalar, but not reduced.
+    fn non_canonical_encoding(scalar: &[u8]) -> Result<Vec<u8>> {
+        let modulus = <CurrentNetwork as Environment>::Scalar::modulus().to_bytes_le()?;
+        let mut carry = 0u16;
+        let encoding = scalar
+            .iter()
+            .zip_eq(&modulus)
+            .map(|(a, b)| {
+                let sum = *a as u16 + *b as u16 + carry;
+                carry = sum >> 8;
+                sum as u8
+            })
+            .collect();
+        // Ensure the sum fits in the encoding.
+        ensure!(carry == 0, "The non-canonical encoding overflows");
+        Ok(encoding)
+    }
+
+    #[test]
+    fn test_bytes_rejects_non_canonical_scalars() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let num_scalar_bytes = Scalar::<CurrentNetwork>::size_in_bytes();
+        for i in 0..ITERATIONS {
+            // Sample a new signature.
+            let signature = test_helpers::sample_signature(i, &mut rng);
+            let signature_bytes = signature.to_bytes_le()?;
+
+            // Ensure a challenge or response that is not reduced modulo the scalar field is rejected.
+            for offset in [0, num_scalar_bytes] {
+                let scalar = &signature_bytes[offset..offset + num_scalar_bytes];
+                let mut candidate = signature_bytes.clone();
+                candidate[offset..offset + num_scalar_bytes].copy_from_slice(&non_canonical_encoding(scalar)?);
+                assert!(Signature::<CurrentNetwork>::read_le(&candidate[..]).is_err());
+            }
+
+            // Ensure a challenge or response encoded as the modulus (i.e. a non-reduced zero) is rejected from bits.
+            let signature_bits = signature.to_bits_le();
+            let num_scalar_bits = Scalar::<CurrentNetwork>::size_in_bits();
+            let modulus_bits = <CurrentNetwork as Environment>::Scalar::modulus().to_bits_le();
+            for offset in [0, num_scalar_bits] {
+                let mut candidate = signature_bits.clone();
+                candidate[offset..offset + num_scalar_bits].copy_from_slice(&modulus_bits[..num_scalar_bits]);
+                assert!(Signature::<CurrentNetwork>::from_bits_le(&candidate).is_err());
+            }
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,76 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Group;
+
+impl<N: Network> Signature<N> {
+    /// Returns the challenge preimage for the given points and message, as `(points.x, message)`.
+    ///
+    /// Each point is mapped to its x-coordinate, followed by the message. For account signatures,
+    /// the points are `(g_r, pk_sig, pr_sig, address)`, as used by `Signature::sign` and `Signature::verify`.
+    pub fn build_challenge_preimage(points: &[Group<N>], message: &[Field<N>]) -> Vec<Field<N>> {
+        let mut preimage = Vec::with_capacity(points.len() + message.len());
+        preimage.extend(points.iter().map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+        preimage
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_build_challenge_preimage() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Sign a message.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(&mut rng)).collect();
+            let signature = Signature::sign(&private_key, &message, &mut rng)?;
+
+            // Recompute `g_r`, and build the preimage.
+            let compute_key = signature.compute_key();
+            let g_r = CurrentNetwork::g_scalar_multiply(&signature.response())
+                + (compute_key.pk_sig() * signature.challenge());
+            let points = [g_r, compute_key.pk_sig(), compute_key.pr_sig(), *address];
+            let preimage = Signature::build_challenge_preimage(&points, &message);
+
+            // Ensure the builder reproduces the signing preimage layout.
+            let layout: Vec<_> =
+                signature.message_layout(&address, &message).into_iter().map(|(_, field)| field).collect();
+            assert_eq!(preimage, layout);
+            assert_eq!(signature.challenge(), CurrentNetwork::hash_to_scalar_psd8(&preimage)?);
+        }
+
+        // Ensure the builder supports other leading points.
+        let points = [Group::<CurrentNetwork>::generator(), Group::rand(&mut rng)];
+        let message = [Field::rand(&mut rng)];
+        let preimage = Signature::build_challenge_preimage(&points, &message);
+        assert_eq!(preimage, vec![points[0].to_x_coordinate(), points[1].to_x_coordinate(), message[0]]);
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Signature<N> {
+    /// Returns the size of the compact signature format in bytes.
+    pub fn compact_size_in_bytes() -> usize {
+        Scalar::<N>::size_in_bytes() + Scalar::<N>::size_in_bytes()
+    }
+
+    /// Returns the signature in the compact format, as `(challenge, response)` without the compute key.
+    ///
+    /// The compact format is half the size of the full signature, at the cost of requiring the verifier to supply
+    /// the compute key, address, and message to reconstruct it. Note that the compute key cannot be recovered from
+    /// the address and message alone, as the challenge binds to both `pk_sig` and `pr_sig`, and the address only
+    /// commits to them through a one-way derivation.
+    pub fn to_compact_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::with_capacity(Self::compact_size_in_bytes());
+        self.challenge.write_le(&mut bytes)?;
+        self.response.write_le(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Reconstructs a signature from the compact format, using the given compute key.
+    ///
+    /// The reconstructed signature is verified against the given address and message,
+    /// so a mismatched compute key, address, or message is rejected.
+    pub fn from_compact_bytes(
+        bytes: &[u8],
+        compute_key: ComputeKey<N>,
+        address: &Address<N>,
+        message: &[Field<N>],
+    ) -> Result<Self> {
+        // Ensure the compact signature is the correct size.
+        ensure!(
+            bytes.len() == Self::compact_size_in_bytes(),
+            "Invalid compact signature: expected {} bytes, found {} bytes",
+            Self::compact_size_in_bytes(),
+            bytes.len()
+        );
+        // Read the challenge and response.
+        let mut reader = bytes;
+        let challenge = Scalar::read_le(&mut reader)?;
+        let response = Scalar::read_le(&mut reader)?;
+        // Reconstruct the signature.
+        let signature = Self { challenge, response, compute_key };
+        // Ensure the reconstructed signature is valid.
+        signature.verify_and_report(address, message)?;
+        Ok(signature)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_compact_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a private key, its compute key and address, and a signature.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let compute_key = ComputeKey::try_from(&private_key)?;
+            let address = Address::try_from(&compute_key)?;
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signature = Signature::sign(&private_key, &message, rng)?;
+
+            // Check the compact format is half the size of the full format.
+            let compact = signature.to_compact_bytes()?;
+            assert_eq!(compact.len(), Signature::<CurrentNetwork>::compact_size_in_bytes());
+            assert_eq!(2 * compact.len(), signature.to_bytes_le()?.len());
+
+            // Check the round trip reconstructs a valid signature.
+            let reconstructed = Signature::from_compact_bytes(&compact, compute_key, &address, &message)?;
+            assert_eq!(signature, reconstructed);
+            assert!(reconstructed.verify(&address, &message));
+
+            // Check that a truncated compact signature is rejected.
+            assert!(Signature::from_compact_bytes(&compact[1..], compute_key, &address, &message).is_err());
+            // Check that an incorrect compute key is rejected.
+            let other_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let other_compute_key = ComputeKey::try_from(&other_private_key)?;
+            let other_address = Address::try_from(&other_compute_key)?;
+            assert!(Signature::from_compact_bytes(&compact, other_compute_key, &address, &message).is_err());
+            assert!(Signature::from_compact_bytes(&compact, other_compute_key, &other_address, &message).is_err());
+            // Check that an incorrect message is rejected.
+            let failure_message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            if message != failure_message {
+                assert!(Signature::from_compact_bytes(&compact, compute_key, &address, &failure_message).is_err());
+            }
+        }
+        Ok(())
+    }
+}
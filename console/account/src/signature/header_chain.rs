@@ -0,0 +1,106 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+impl<N: Network> Signature<N> {
+    /// Verifies a chain of signed headers, given as `(signer, header_hash, signature)`, starting from `previous_hash`.
+    ///
+    /// Each signature must be over the message `[previous_hash, header_hash]`, where `previous_hash` is the
+    /// hash of the preceding header (or the given `previous_hash` for the first header). This binds every header
+    /// to its predecessor, so a reordered, removed, or substituted header breaks the chain.
+    pub fn verify_header_chain(previous_hash: Field<N>, headers: &[(Address<N>, Field<N>, Signature<N>)]) -> bool {
+        // Pair each header with the hash of its predecessor.
+        let previous_hashes = std::iter::once(previous_hash).chain(headers.iter().map(|(_, hash, _)| *hash));
+        let links = headers.iter().zip(previous_hashes).collect::<Vec<_>>();
+
+        // Verify each signature over its link in the chain.
+        cfg_iter!(links)
+            .all(|((signer, hash, signature), previous_hash)| signature.verify(signer, &[*previous_hash, *hash]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const CHAIN_LENGTH: usize = 8;
+
+    /// Samples a valid chain of signed headers, returning the genesis hash and the headers.
+    fn sample_chain(
+        rng: &mut TestRng,
+    ) -> Result<(Field<CurrentNetwork>, Vec<(Address<CurrentNetwork>, Field<CurrentNetwork>, Signature<CurrentNetwork>)>)>
+    {
+        let genesis_hash = Field::rand(rng);
+
+        let mut headers = Vec::with_capacity(CHAIN_LENGTH);
+        let mut previous_hash = genesis_hash;
+        for _ in 0..CHAIN_LENGTH {
+            // Sample a signer and a header hash.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let hash = Field::rand(rng);
+            // Sign the link from the previous header.
+            let signature = Signature::sign(&private_key, &[previous_hash, hash], rng)?;
+            headers.push((Address::try_from(&private_key)?, hash, signature));
+            previous_hash = hash;
+        }
+        Ok((genesis_hash, headers))
+    }
+
+    #[test]
+    fn test_verify_header_chain() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Ensure a valid chain is accepted.
+        let (genesis_hash, headers) = sample_chain(&mut rng)?;
+        assert!(Signature::verify_header_chain(genesis_hash, &headers));
+
+        // Ensure an empty chain is accepted.
+        assert!(Signature::<CurrentNetwork>::verify_header_chain(genesis_hash, &[]));
+
+        // Ensure the chain is rejected from a different starting hash.
+        assert!(!Signature::verify_header_chain(Field::rand(&mut rng), &headers));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_header_chain_broken_link() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let (genesis_hash, headers) = sample_chain(&mut rng)?;
+
+        // Break a link by substituting a header hash; its signature remains valid for its own link.
+        let mut broken = headers.clone();
+        broken[3].1 = Field::rand(&mut rng);
+        assert!(!Signature::verify_header_chain(genesis_hash, &broken));
+
+        // Break a link by removing a header.
+        let mut broken = headers.clone();
+        broken.remove(4);
+        assert!(!Signature::verify_header_chain(genesis_hash, &broken));
+
+        // Break a link by reordering two headers.
+        let mut broken = headers;
+        broken.swap(5, 6);
+        assert!(!Signature::verify_header_chain(genesis_hash, &broken));
+        Ok(())
+    }
+}
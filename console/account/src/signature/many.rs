@@ -0,0 +1,142 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Signature<N> {
+    /// Returns a signature covering several messages, each given as `(domain, message)`, using the private key.
+    ///
+    /// The messages are signed as the single message returned by `Signature::encode_many`.
+    #[cfg(feature = "private_key")]
+    pub fn sign_many<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        messages: &[(Field<N>, &[Field<N>])],
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Encode the messages, and sign the encoding.
+        Self::sign(private_key, &Self::encode_many(messages)?, rng)
+    }
+
+    /// Verifies a signature covering several messages, each given as `(domain, message)`, for the given address.
+    pub fn verify_many(&self, address: &Address<N>, messages: &[(Field<N>, &[Field<N>])]) -> bool {
+        match Self::encode_many(messages) {
+            Ok(message) => self.verify(address, &message),
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the canonical encoding of the given `(domain, message)` segments, as a single message:
+    ///     (num_segments, domain_0, len_0, message_0, ..., domain_n, len_n, message_n)
+    ///
+    /// Every segment is length-prefixed, so two different splits of the same field elements encode differently.
+    pub fn encode_many(messages: &[(Field<N>, &[Field<N>])]) -> Result<Vec<Field<N>>> {
+        // Compute the size of the encoding.
+        let num_fields = messages.iter().fold(1usize, |acc, (_, message)| acc.saturating_add(2 + message.len()));
+        // Ensure the encoding does not exceed the maximum allowed size.
+        if num_fields > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot encode the messages: the encoding exceeds maximum allowed size")
+        }
+
+        // Encode the number of segments.
+        let mut encoding = Vec::with_capacity(num_fields);
+        encoding.push(Field::from_u64(messages.len() as u64));
+        // Encode each segment as (domain, length, message).
+        for (domain, message) in messages {
+            encoding.push(*domain);
+            encoding.push(Field::from_u64(message.len() as u64));
+            encoding.extend_from_slice(message);
+        }
+        Ok(encoding)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_sign_and_verify_many() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Sample the messages, each with its own domain.
+            let messages: Vec<(Field<CurrentNetwork>, Vec<Field<CurrentNetwork>>)> =
+                (0..i).map(|j| (Uniform::rand(&mut rng), (0..j).map(|_| Uniform::rand(&mut rng)).collect())).collect();
+            let messages: Vec<_> = messages.iter().map(|(domain, message)| (*domain, message.as_slice())).collect();
+
+            // Ensure the signature verifies for the messages, and matches a signature over their encoding.
+            let signature = Signature::sign_many(&private_key, &messages, &mut rng)?;
+            assert!(signature.verify_many(&address, &messages));
+            assert!(signature.verify(&address, &Signature::encode_many(&messages)?));
+
+            // Ensure the signature does not verify for a different domain.
+            if let Some((domain, _)) = messages.first().copied() {
+                let mut tampered = messages.clone();
+                tampered[0].0 = domain + Field::one();
+                assert!(!signature.verify_many(&address, &tampered));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_many_is_unambiguous() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Address::try_from(&private_key)?;
+
+        let [a, b, c, d0, d1]: [Field<CurrentNetwork>; 5] = [(); 5].map(|_| Uniform::rand(&mut rng));
+
+        // Two splits of the same fields, under the same domain.
+        let left = [(d0, &[a, b][..]), (d0, &[c][..])];
+        let right = [(d0, &[a][..]), (d0, &[b, c][..])];
+        // A domain and message that, concatenated without lengths, match the flattening of two segments.
+        let nested = [(d0, &[a, d1, b][..])];
+        let split = [(d0, &[a][..]), (d1, &[b][..])];
+        // An empty segment, which must not be dropped.
+        let empty = [(d0, &[a][..]), (d1, &[][..])];
+        let single = [(d0, &[a][..])];
+
+        for (first, second) in [(&left[..], &right[..]), (&nested[..], &split[..]), (&empty[..], &single[..])] {
+            // Ensure the encodings differ.
+            assert_ne!(Signature::encode_many(first)?, Signature::encode_many(second)?);
+            // Ensure a signature over one split does not verify for the other.
+            let signature = Signature::sign_many(&private_key, first, &mut rng)?;
+            assert!(signature.verify_many(&address, first));
+            assert!(!signature.verify_many(&address, second));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_many_rejects_oversized_messages() {
+        // Ensure an encoding exceeding the maximum allowed size is rejected.
+        let message = vec![Field::<CurrentNetwork>::zero(); CurrentNetwork::MAX_DATA_SIZE_IN_FIELDS as usize];
+        assert!(Signature::encode_many(&[(Field::zero(), &message[..])]).is_err());
+        // Ensure the largest encoding is accepted.
+        let message = &message[..CurrentNetwork::MAX_DATA_SIZE_IN_FIELDS as usize - 3];
+        assert!(Signature::encode_many(&[(Field::zero(), message)]).is_ok());
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Signature<N> {
+    /// Returns the labeled field elements of the challenge preimage for the given address and message,
+    /// in the order they are hashed by `Signature::verify`, i.e. `(g_r, pk_sig, pr_sig, address, message)`.
+    ///
+    /// This is intended for debugging signature verification mismatches across implementations.
+    pub fn message_layout(&self, address: &Address<N>, message: &[Field<N>]) -> Vec<(String, Field<N>)> {
+        // Retrieve pk_sig.
+        let pk_sig = self.compute_key.pk_sig();
+        // Retrieve pr_sig.
+        let pr_sig = self.compute_key.pr_sig();
+
+        // Compute `g_r` := (response * G) + (challenge * pk_sig).
+        let g_r = N::g_scalar_multiply(&self.response) + (pk_sig * self.challenge);
+
+        // Construct the labeled hash input as (r * G, pk_sig, pr_sig, address, message).
+        let mut layout = Vec::with_capacity(4 + message.len());
+        layout.extend(
+            [("g_r.x", g_r), ("pk_sig.x", pk_sig), ("pr_sig.x", pr_sig), ("address.x", **address)]
+                .map(|(label, point)| (label.to_string(), point.to_x_coordinate())),
+        );
+        layout.extend(message.iter().enumerate().map(|(index, field)| (format!("message[{index}]"), *field)));
+        layout
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_message_layout() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Sign a message.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(&mut rng)).collect();
+            let signature = Signature::sign(&private_key, &message, &mut rng)?;
+            assert!(signature.verify(&address, &message));
+
+            // Check the labels.
+            let layout = signature.message_layout(&address, &message);
+            let labels: Vec<_> = layout.iter().map(|(label, _)| label.as_str()).collect();
+            let mut expected_labels =
+                vec!["g_r.x".to_string(), "pk_sig.x".into(), "pr_sig.x".into(), "address.x".into()];
+            expected_labels.extend((0..i).map(|index| format!("message[{index}]")));
+            assert_eq!(labels, expected_labels);
+
+            // Check the values, which must hash to the challenge for a valid signature.
+            let compute_key = signature.compute_key();
+            assert_eq!(layout[1].1, compute_key.pk_sig().to_x_coordinate());
+            assert_eq!(layout[2].1, compute_key.pr_sig().to_x_coordinate());
+            assert_eq!(layout[3].1, address.to_x_coordinate());
+            assert_eq!(&layout[4..].iter().map(|(_, field)| *field).collect::<Vec<_>>(), &message);
+            let preimage: Vec<_> = layout.into_iter().map(|(_, field)| field).collect();
+            assert_eq!(signature.challenge(), CurrentNetwork::hash_to_scalar_psd8(&preimage)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Group;
+
+/// The reason a signature failed to verify, as reported by `Signature::verify_and_report`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignatureMismatch<N: Network> {
+    /// The challenge recomputed by the verifier differs from the challenge stored in the signature.
+    Challenge {
+        /// The challenge stored in the signature.
+        expected: Scalar<N>,
+        /// The challenge recomputed from the nonce commitment, the compute key, the address, and the message.
+        recomputed: Scalar<N>,
+        /// The nonce commitment `g_r` := (response * G) + (challenge * pk_sig), as reconstructed by the verifier.
+        g_r: Group<N>,
+    },
+    /// The address derived from the compute key differs from the expected address.
+    Address {
+        /// The address the signature was verified against.
+        expected: Address<N>,
+        /// The address derived from the compute key in the signature.
+        derived: Address<N>,
+    },
+    /// The signature could not be checked, e.g. because the message exceeds the maximum allowed size.
+    Invalid(String),
+}
+
+impl<N: Network> Display for SignatureMismatch<N> {
+    /// Prints the signature mismatch as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Challenge { expected, recomputed, .. } => {
+                write!(f, "Signature challenge mismatch: stored '{expected}', recomputed '{recomputed}'")
+            }
+            Self::Address { expected, derived } => {
+                write!(f, "Signature address mismatch: expected '{expected}', derived '{derived}'")
+            }
+            Self::Invalid(reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+impl<N: Network> std::error::Error for SignatureMismatch<N> {}
@@ -15,17 +15,41 @@
 
 mod bitwise;
 mod bytes;
+mod challenge_preimage;
+mod compact;
 mod from_bits;
+mod header_chain;
+mod many;
+mod message_layout;
+mod mismatch;
+pub use mismatch::*;
 mod parse;
 mod serialize;
 mod size_in_bits;
 mod size_in_bytes;
+mod sort_key;
+mod symmetric_key;
 mod to_bits;
 mod to_fields;
 mod verify;
 
+#[cfg(feature = "bench")]
+mod benchmark;
+
+#[cfg(feature = "private_key")]
+mod nonce_guard;
+#[cfg(feature = "private_key")]
+pub use nonce_guard::*;
+
+#[cfg(feature = "private_key")]
+mod nonce_source;
+#[cfg(feature = "private_key")]
+pub use nonce_source::*;
+
 #[cfg(feature = "private_key")]
 mod sign;
+#[cfg(feature = "private_key")]
+mod sign_deterministic;
 
 #[cfg(feature = "compute_key")]
 use crate::ComputeKey;
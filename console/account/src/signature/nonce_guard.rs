@@ -0,0 +1,155 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Group;
+
+use parking_lot::Mutex;
+use std::collections::{HashSet, VecDeque};
+
+/// An opt-in guard around `Signature::sign`, which refuses to emit two signatures with the same
+/// nonce commitment `g_r := nonce * G` in the current process.
+///
+/// Reusing a nonce for two distinct messages reveals the signing key, so this guard protects
+/// against a faulty RNG. The guard remembers the `capacity` most recent nonce commitments.
+pub struct NonceGuard<N: Network> {
+    /// The maximum number of nonce commitments to remember.
+    capacity: usize,
+    /// The nonce commitments, and the order in which they were seen.
+    seen: Mutex<(HashSet<Group<N>>, VecDeque<Group<N>>)>,
+}
+
+impl<N: Network> NonceGuard<N> {
+    /// The default number of nonce commitments to remember.
+    pub const DEFAULT_CAPACITY: usize = 1 << 16;
+
+    /// Initializes a new nonce guard, remembering up to `capacity` nonce commitments.
+    pub fn new(capacity: usize) -> Result<Self> {
+        // Ensure the capacity is nonzero.
+        ensure!(capacity > 0, "The nonce guard capacity must be nonzero");
+        // Return the nonce guard.
+        Ok(Self { capacity, seen: Mutex::new((HashSet::with_capacity(capacity), VecDeque::with_capacity(capacity))) })
+    }
+
+    /// Returns the number of nonce commitments currently remembered.
+    pub fn len(&self) -> usize {
+        self.seen.lock().1.len()
+    }
+
+    /// Returns `true` if no nonce commitments are currently remembered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a signature for the given message, as in `Signature::sign`,
+    /// or an error if the signature reuses a nonce commitment seen by this guard.
+    pub fn sign<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        message: &[Field<N>],
+        rng: &mut R,
+    ) -> Result<Signature<N>> {
+        // Sign the message.
+        let signature = Signature::sign(private_key, message, rng)?;
+        // Recover `g_r` := (response * G) + (challenge * pk_sig).
+        let g_r = N::g_scalar_multiply(&signature.response) + (signature.compute_key.pk_sig() * signature.challenge);
+
+        // Acquire the nonce commitments.
+        let mut seen = self.seen.lock();
+        let (set, order) = &mut *seen;
+        // Ensure the nonce commitment has not been seen.
+        ensure!(!set.contains(&g_r), "Refusing to emit a signature that reuses a nonce commitment");
+        // If the guard is full, forget the oldest nonce commitment.
+        if order.len() == self.capacity {
+            if let Some(oldest) = order.pop_front() {
+                set.remove(&oldest);
+            }
+        }
+        // Remember the nonce commitment.
+        set.insert(g_r);
+        order.push_back(g_r);
+
+        // Return the signature.
+        Ok(signature)
+    }
+}
+
+impl<N: Network> Default for NonceGuard<N> {
+    /// Initializes a new nonce guard with the default capacity.
+    fn default() -> Self {
+        Self { capacity: Self::DEFAULT_CAPACITY, seen: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_nonce_guard_rejects_reused_nonce() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a private key and two messages.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Address::try_from(&private_key)?;
+        let message_a: Vec<Field<CurrentNetwork>> = (0..4).map(|_| Uniform::rand(&mut rng)).collect();
+        let message_b: Vec<Field<CurrentNetwork>> = (0..4).map(|_| Uniform::rand(&mut rng)).collect();
+
+        let guard = NonceGuard::<CurrentNetwork>::default();
+
+        // Sign the first message, with a seeded RNG.
+        let signature = guard.sign(&private_key, &message_a, &mut TestRng::from_seed(7))?;
+        assert!(signature.verify(&address, &message_a));
+        assert_eq!(guard.len(), 1);
+
+        // Force a repeated nonce, by reusing the seeded RNG on a different message.
+        assert!(Signature::sign(&private_key, &message_b, &mut TestRng::from_seed(7))?.verify(&address, &message_b));
+        assert!(guard.sign(&private_key, &message_b, &mut TestRng::from_seed(7)).is_err());
+        assert_eq!(guard.len(), 1);
+
+        // Ensure a fresh nonce is accepted.
+        let signature = guard.sign(&private_key, &message_b, &mut rng)?;
+        assert!(signature.verify(&address, &message_b));
+        assert_eq!(guard.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_nonce_guard_is_bounded() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a private key and a message.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let message: Vec<Field<CurrentNetwork>> = (0..4).map(|_| Uniform::rand(&mut rng)).collect();
+
+        let guard = NonceGuard::<CurrentNetwork>::new(2)?;
+        assert!(NonceGuard::<CurrentNetwork>::new(0).is_err());
+
+        // Fill the guard, and then evict the first nonce commitment.
+        guard.sign(&private_key, &message, &mut TestRng::from_seed(1))?;
+        guard.sign(&private_key, &message, &mut TestRng::from_seed(2))?;
+        assert!(guard.sign(&private_key, &message, &mut TestRng::from_seed(1)).is_err());
+        guard.sign(&private_key, &message, &mut TestRng::from_seed(3))?;
+        assert_eq!(guard.len(), 2);
+
+        // Ensure the evicted nonce commitment is no longer remembered.
+        assert!(guard.sign(&private_key, &message, &mut TestRng::from_seed(1)).is_ok());
+        assert!(guard.sign(&private_key, &message, &mut TestRng::from_seed(3)).is_err());
+        Ok(())
+    }
+}
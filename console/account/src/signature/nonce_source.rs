@@ -0,0 +1,81 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A source of nonces for `Signature::sign_with_nonce_source`.
+///
+/// Every `Rng + CryptoRng` is a nonce source, which samples each nonce uniformly at random.
+/// Other implementations are intended for tests, where signatures must be reproducible.
+pub trait NonceSource<N: Network> {
+    /// Returns the next nonce.
+    fn next_nonce(&mut self) -> Result<Scalar<N>>;
+}
+
+impl<N: Network, R: Rng + CryptoRng> NonceSource<N> for R {
+    /// Samples a random nonce from the scalar field.
+    fn next_nonce(&mut self) -> Result<Scalar<N>> {
+        Ok(Scalar::rand(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    use std::collections::VecDeque;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// A nonce source that yields the given nonces, in order.
+    struct FixedNonces(VecDeque<Scalar<CurrentNetwork>>);
+
+    impl NonceSource<CurrentNetwork> for FixedNonces {
+        fn next_nonce(&mut self) -> Result<Scalar<CurrentNetwork>> {
+            self.0.pop_front().ok_or_else(|| anyhow!("The fixed nonce source is exhausted"))
+        }
+    }
+
+    #[test]
+    fn test_sign_with_fixed_nonce_source() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a private key, a message, and a nonce.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Address::try_from(&private_key)?;
+        let message: Vec<Field<CurrentNetwork>> = (0..4).map(|_| Uniform::rand(&mut rng)).collect();
+        let nonce = Scalar::rand(&mut rng);
+
+        // Sign the message with the fixed nonce, twice.
+        let mut nonces = FixedNonces(VecDeque::from([nonce, nonce]));
+        let signature = Signature::sign_with_nonce_source(&private_key, &message, &mut nonces)?;
+        assert!(signature.verify(&address, &message));
+        assert_eq!(signature, Signature::sign_with_nonce_source(&private_key, &message, &mut nonces)?);
+
+        // Ensure the signature is the one derived from the nonce.
+        let compute_key = ComputeKey::try_from(&private_key)?;
+        let g_r = CurrentNetwork::g_scalar_multiply(&nonce);
+        let preimage =
+            Signature::build_challenge_preimage(&[g_r, compute_key.pk_sig(), compute_key.pr_sig(), *address], &message);
+        let challenge = CurrentNetwork::hash_to_scalar_psd8(&preimage)?;
+        let response = nonce - (challenge * private_key.sk_sig());
+        assert_eq!(signature, Signature::from((challenge, response, compute_key)));
+
+        // Ensure signing fails once the nonce source is exhausted.
+        assert!(Signature::sign_with_nonce_source(&private_key, &message, &mut nonces).is_err());
+        Ok(())
+    }
+}
@@ -19,39 +19,66 @@ impl<N: Network> Signature<N> {
     /// Returns a signature `(challenge, response, compute_key)` for a given message and RNG, where:
     ///     challenge := HashToScalar(nonce * G, pk_sig, pr_sig, address, message)
     ///     response := nonce - challenge * private_key.sk_sig()
+    ///
+    /// The response, which involves `sk_sig`, is computed in constant time (see `Scalar::ct_mul_sub`).
     pub fn sign<R: Rng + CryptoRng>(private_key: &PrivateKey<N>, message: &[Field<N>], rng: &mut R) -> Result<Self> {
+        // Sign the message, sampling the nonce from the RNG.
+        Self::sign_with_nonce_source(private_key, message, rng)
+    }
+
+    /// Returns a signature for a given message, as in `Signature::sign`, taking the nonce from the given nonce source.
+    ///
+    /// This allows tests to supply fixed nonces, so that signatures are reproducible; see `NonceSource`.
+    pub fn sign_with_nonce_source<S: NonceSource<N>>(
+        private_key: &PrivateKey<N>,
+        message: &[Field<N>],
+        nonces: &mut S,
+    ) -> Result<Self> {
+        // Derive the compute key from the private key.
+        let compute_key = ComputeKey::try_from(private_key)?;
+        // Sign the message.
+        Self::sign_with(&compute_key, &private_key.sk_sig(), message, nonces)
+    }
+
+    /// Returns a signature for a given message and nonce source, as in `Signature::sign`,
+    /// using the given compute key and signature secret key `sk_sig`.
+    ///
+    /// This allows callers that sign many messages with the same key to derive the compute key once.
+    /// The compute key must be the one derived from the private key of `sk_sig`, or the signature will not verify.
+    pub fn sign_with<S: NonceSource<N>>(
+        compute_key: &ComputeKey<N>,
+        sk_sig: &Scalar<N>,
+        message: &[Field<N>],
+        rng: &mut S,
+    ) -> Result<Self> {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
             bail!("Cannot sign the message: the message exceeds maximum allowed size")
         }
 
-        // Sample a random nonce from the scalar field.
-        let nonce = Scalar::rand(rng);
+        // Retrieve the nonce from the nonce source.
+        let nonce = rng.next_nonce()?;
         // Compute `g_r` as `nonce * G`.
         let g_r = N::g_scalar_multiply(&nonce);
 
-        // Derive the compute key from the private key.
-        let compute_key = ComputeKey::try_from(private_key)?;
         // Retrieve pk_sig.
         let pk_sig = compute_key.pk_sig();
         // Retrieve pr_sig.
         let pr_sig = compute_key.pr_sig();
 
         // Derive the address from the compute key.
-        let address = Address::try_from(compute_key)?;
+        let address = compute_key.to_address();
 
         // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
-        let mut preimage = Vec::with_capacity(4 + message.len());
-        preimage.extend([g_r, pk_sig, pr_sig, *address].map(|point| point.to_x_coordinate()));
-        preimage.extend(message);
+        let preimage = Self::build_challenge_preimage(&[g_r, pk_sig, pr_sig, *address], message);
 
         // Compute the verifier challenge.
         let challenge = N::hash_to_scalar_psd8(&preimage)?;
-        // Compute the prover response.
-        let response = nonce - (challenge * private_key.sk_sig());
+        // Compute the prover response, in constant time, as it involves the secret `sk_sig`.
+        let response = nonce.ct_mul_sub(&challenge, sk_sig);
 
         // Output the signature.
-        Ok(Self { challenge, response, compute_key })
+        Ok(Self { challenge, response, compute_key: *compute_key })
     }
 
     /// Returns a signature for the given message (as bytes) using the private key.
@@ -70,10 +97,119 @@ impl<N: Network> Signature<N> {
         message: &[bool],
         rng: &mut R,
     ) -> Result<Signature<N>> {
+        // Ensure the message does not exceed the maximum allowed size, before packing it into field elements.
+        Self::check_message_size_in_bits(message.len())?;
         // Pack the bits into field elements.
         let fields =
             message.chunks(Field::<N>::size_in_data_bits()).map(Field::from_bits_le).collect::<Result<Vec<_>>>()?;
         // Sign the message.
         Self::sign(private_key, &fields, rng)
     }
+
+    /// Ensures a message of the given number of bits packs into at most `N::MAX_DATA_SIZE_IN_FIELDS` field elements.
+    fn check_message_size_in_bits(num_bits: usize) -> Result<()> {
+        // Compute the number of field elements the bits pack into.
+        let num_fields = num_bits.div_ceil(Field::<N>::size_in_data_bits());
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if num_fields > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_sign_with_matches_sign() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a private key and a message.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let message: Vec<Field<CurrentNetwork>> = (0..i).map(|_| Uniform::rand(&mut rng)).collect();
+            // Pre-derive the compute key.
+            let compute_key = ComputeKey::try_from(&private_key)?;
+
+            // Sign the message on both paths, with the same nonce.
+            let seed = rng.gen();
+            let expected = Signature::sign(&private_key, &message, &mut TestRng::fixed(seed))?;
+            let candidate =
+                Signature::sign_with(&compute_key, &private_key.sk_sig(), &message, &mut TestRng::fixed(seed))?;
+            assert_eq!(expected, candidate);
+            assert!(candidate.verify(&compute_key.to_address(), &message));
+        }
+        Ok(())
+    }
+
+    /// Ensures the constant-time response matches `nonce - (challenge * sk_sig)`, for the nonce of the signature.
+    #[test]
+    fn test_sign_response_is_nonce_minus_challenge_times_sk_sig() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a private key and a message.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let message: Vec<Field<CurrentNetwork>> = (0..i).map(|_| Uniform::rand(&mut rng)).collect();
+
+            // Sign the message, and recover the nonce, by drawing it from an identically-seeded source.
+            let seed = rng.gen();
+            let signature = Signature::sign(&private_key, &message, &mut TestRng::fixed(seed))?;
+            let nonce = NonceSource::<CurrentNetwork>::next_nonce(&mut TestRng::fixed(seed))?;
+
+            // Ensure the response is computed as documented, and the signature verifies.
+            assert_eq!(signature.response(), nonce - (signature.challenge() * private_key.sk_sig()));
+            assert!(signature.verify(&Address::try_from(&private_key)?, &message));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_bytes_rejects_oversized_message() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Compute the largest message size, in bytes, that packs into the maximum number of field elements.
+        let max_bytes =
+            (CurrentNetwork::MAX_DATA_SIZE_IN_FIELDS as usize * Field::<CurrentNetwork>::size_in_data_bits()) / 8;
+
+        // Ensure a message at the limit is signed.
+        let message = vec![1u8; max_bytes];
+        let signature = Signature::sign_bytes(&private_key, &message, &mut rng)?;
+        assert!(signature.verify_bytes(&address, &message));
+
+        // Ensure an over-length message is rejected, with the same error as `Signature::sign`.
+        let message = vec![1u8; max_bytes + 1];
+        let error = Signature::sign_bytes(&private_key, &message, &mut rng).unwrap_err();
+        assert_eq!(error.to_string(), "Cannot sign the message: the message exceeds maximum allowed size");
+        let error = Signature::sign_bits(&private_key, &message.to_bits_le(), &mut rng).unwrap_err();
+        assert_eq!(error.to_string(), "Cannot sign the message: the message exceeds maximum allowed size");
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_with_mismatched_compute_key() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample two private keys and a message.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let other_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let message: Vec<Field<CurrentNetwork>> = (0..4).map(|_| Uniform::rand(&mut rng)).collect();
+
+        // Ensure a signature with a compute key from another private key does not verify.
+        let compute_key = ComputeKey::try_from(&other_key)?;
+        let signature = Signature::sign_with(&compute_key, &private_key.sk_sig(), &message, &mut rng)?;
+        assert!(!signature.verify(&compute_key.to_address(), &message));
+        assert!(!signature.verify(&Address::try_from(&private_key)?, &message));
+        Ok(())
+    }
 }
@@ -0,0 +1,96 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The domain separator for the nonce of a deterministic signature.
+const SIGNATURE_NONCE_DOMAIN: &str = "AleoSignatureNonce0";
+
+impl<N: Network> Signature<N> {
+    /// Returns a signature for the given message using the private key, as in `Signature::sign`,
+    /// with the nonce derived from the private key and the message, rather than sampled from an RNG:
+    ///     nonce := HashToScalar(domain, sk_sig, message)
+    ///
+    /// The same private key and message always produce the same signature, which verifies under `Signature::verify`.
+    /// Distinct messages derive distinct nonces, so a nonce is never reused across messages.
+    pub fn sign_deterministic(private_key: &PrivateKey<N>, message: &[Field<N>]) -> Result<Self> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+
+        // Construct the nonce preimage as (domain, sk_sig, message).
+        let mut preimage = Vec::with_capacity(2 + message.len());
+        preimage.push(Field::new_domain_separator(SIGNATURE_NONCE_DOMAIN));
+        preimage.push(private_key.sk_sig().to_field()?);
+        preimage.extend_from_slice(message);
+        // Derive the nonce.
+        let nonce = N::hash_to_scalar_psd8(&preimage)?;
+
+        // Sign the message with the derived nonce.
+        Self::sign_with_nonce_source(private_key, message, &mut DerivedNonce(Some(nonce)))
+    }
+}
+
+/// A nonce source that yields a single derived nonce.
+struct DerivedNonce<N: Network>(Option<Scalar<N>>);
+
+impl<N: Network> NonceSource<N> for DerivedNonce<N> {
+    /// Returns the derived nonce, which may only be used once.
+    fn next_nonce(&mut self) -> Result<Scalar<N>> {
+        self.0.take().ok_or_else(|| anyhow!("The derived nonce was already used"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_sign_deterministic() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample an address, a private key, and a message.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(&private_key)?;
+            let message: Vec<Field<CurrentNetwork>> = (0..i).map(|_| Uniform::rand(&mut rng)).collect();
+
+            // Ensure two signatures on identical input are byte-identical, and verify.
+            let signature = Signature::sign_deterministic(&private_key, &message)?;
+            let candidate = Signature::sign_deterministic(&private_key, &message)?;
+            assert_eq!(signature.to_bytes_le()?, candidate.to_bytes_le()?);
+            assert!(signature.verify(&address, &message));
+
+            // Ensure a different message derives a different nonce.
+            let mut other_message = message.clone();
+            other_message.push(Uniform::rand(&mut rng));
+            let other = Signature::sign_deterministic(&private_key, &other_message)?;
+            assert!(other.verify(&address, &other_message));
+            assert_ne!(signature.challenge(), other.challenge());
+            assert_ne!(signature.response(), other.response());
+
+            // Ensure a different private key derives a different signature.
+            let other_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            assert_ne!(signature, Signature::sign_deterministic(&other_key, &message)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Signature<N> {
+    /// Returns the size of the signature sort key in bytes, i.e. the signer address followed by the signature.
+    pub fn sort_key_size_in_bytes() -> usize {
+        Address::<N>::size_in_bytes() + Self::size_in_bytes()
+    }
+
+    /// Returns a byte key for ordering signatures deterministically, e.g. in a multi-signature bundle.
+    ///
+    /// The key is the little-endian encoding of the signer address followed by the signature itself,
+    /// so signatures are grouped by signer, and two keys are equal if and only if the signatures are equal.
+    pub fn sort_key(&self) -> Result<Vec<u8>> {
+        let mut key = Vec::with_capacity(Self::sort_key_size_in_bytes());
+        // Write the signer address, followed by the signature.
+        self.to_address().write_le(&mut key)?;
+        self.write_le(&mut key)?;
+        // Ensure the key is the correct size.
+        ensure!(
+            key.len() == Self::sort_key_size_in_bytes(),
+            "Invalid sort key: expected {} bytes, found {} bytes",
+            Self::sort_key_size_in_bytes(),
+            key.len()
+        );
+        Ok(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 25;
+
+    #[test]
+    fn test_sort_key() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample signatures from a few signers.
+        let mut signatures: Vec<Signature<CurrentNetwork>> =
+            (0..ITERATIONS).map(|i| test_helpers::sample_signature(i % 4, &mut rng)).collect();
+        for signature in &signatures {
+            // Ensure the key begins with the signer address and ends with the signature.
+            let key = signature.sort_key()?;
+            let address_size = Address::<CurrentNetwork>::size_in_bytes();
+            assert_eq!(key.len(), Signature::<CurrentNetwork>::sort_key_size_in_bytes());
+            assert_eq!(key[..address_size], signature.to_address().to_bytes_le()?);
+            assert_eq!(key[address_size..], signature.to_bytes_le()?);
+        }
+
+        // Sort the signatures, and ensure the order is independent of the starting order.
+        let mut reversed = signatures.iter().rev().copied().collect::<Vec<_>>();
+        signatures.sort_by_cached_key(|signature| signature.sort_key().unwrap());
+        reversed.sort_by_cached_key(|signature| signature.sort_key().unwrap());
+        assert_eq!(signatures, reversed);
+
+        // Ensure the keys are strictly increasing, as the signatures are distinct.
+        for pair in signatures.windows(2) {
+            assert!(pair[0].sort_key()? < pair[1].sort_key()?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Signature<N> {
+    /// Returns a symmetric key derived from the signature, as `HashPSD2(challenge, response)`.
+    ///
+    /// Note: The derived key is only as secret as the signature itself.
+    /// Any party that observes the signature is able to derive the same key.
+    pub fn derive_symmetric_key(&self) -> Result<Field<N>> {
+        N::hash_psd2(&[self.challenge.to_field()?, self.response.to_field()?])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_derive_symmetric_key() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let mut previous_key = None;
+        for i in 0..ITERATIONS {
+            // Sample a new signature.
+            let signature = test_helpers::sample_signature(i, &mut rng);
+
+            // Check that the key is deterministic for the signature.
+            let key = signature.derive_symmetric_key()?;
+            assert_eq!(key, signature.derive_symmetric_key()?);
+
+            // Check that the key differs from the key of the previous signature.
+            if let Some(previous_key) = previous_key {
+                assert_ne!(key, previous_key);
+            }
+            previous_key = Some(key);
+        }
+        Ok(())
+    }
+}
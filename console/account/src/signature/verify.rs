@@ -14,48 +14,137 @@
 // limitations under the License.
 
 use super::*;
+use crate::Group;
 
 impl<N: Network> Signature<N> {
     /// Verifies (challenge == challenge') && (address == address') where:
     ///     challenge' := HashToScalar(G^response pk_sig^challenge, pk_sig, pr_sig, address, message)
     pub fn verify(&self, address: &Address<N>, message: &[Field<N>]) -> bool {
+        self.report_with(&self.compute_key, address, message).is_ok()
+    }
+
+    /// Verifies the signature as in `Signature::verify`, returning an error describing the first failed check.
+    ///
+    /// On a challenge mismatch, the error includes both the stored and the recomputed challenge,
+    /// to help debug signatures produced by other implementations.
+    #[cfg(any(test, feature = "debug"))]
+    pub fn verify_detailed(&self, address: &Address<N>, message: &[Field<N>]) -> Result<()> {
+        self.verify_detailed_with(&self.compute_key, address, message)
+    }
+
+    /// Verifies the signature as in `Signature::verify`, reporting which component mismatched on failure.
+    ///
+    /// On a challenge mismatch, the report carries the stored and the recomputed challenge,
+    /// along with the nonce commitment `g_r` that the challenge was recomputed from.
+    pub fn verify_and_report(&self, address: &Address<N>, message: &[Field<N>]) -> Result<(), SignatureMismatch<N>> {
+        self.report_with(&self.compute_key, address, message)
+    }
+
+    /// Verifies the signature as in `Signature::verify_and_report`, for a message with a fixed layout of `expected_len` fields.
+    ///
+    /// A message with a different number of fields is rejected with a descriptive error before verifying,
+    /// so that a layout mismatch is not mistaken for an invalid signature.
+    pub fn verify_structured(&self, address: &Address<N>, fields: &[Field<N>], expected_len: usize) -> Result<()> {
+        // Ensure the message has the expected layout.
+        ensure!(
+            fields.len() == expected_len,
+            "Cannot verify the signature: expected a message of {expected_len} fields, found {} fields",
+            fields.len()
+        );
+        // Verify the signature.
+        Ok(self.verify_and_report(address, fields)?)
+    }
+
+    /// Verifies the signature for the given message, using the supplied compute key in place of the embedded one.
+    ///
+    /// This allows a signature to be stored or transmitted without its compute key, which is then supplied
+    /// separately by the verifier. The embedded compute key is ignored, and the signer address is derived
+    /// from the supplied compute key.
+    pub fn verify_with_compute_key(&self, compute_key: &ComputeKey<N>, message: &[Field<N>]) -> bool {
+        match Address::try_from(compute_key) {
+            Ok(address) => self.report_with(compute_key, &address, message).is_ok(),
+            Err(error) => {
+                eprintln!("Failed to verify signature: {error}");
+                false
+            }
+        }
+    }
+
+    /// Returns the nonce commitment `g_r` := (response * G) + (challenge * pk_sig), as reconstructed by the verifier.
+    ///
+    /// This is the verification intermediate that the challenge is recomputed from, which is useful for
+    /// interactive protocols (e.g. MuSig aggregation). The embedded compute key must correspond to the given address.
+    pub fn reconstruct_nonce_commitment(&self, address: &Address<N>) -> Result<Group<N>> {
+        // Ensure the embedded compute key corresponds to the address.
+        let candidate_address = Address::try_from(&self.compute_key)?;
+        ensure!(
+            *address == candidate_address,
+            "Signature address mismatch: expected '{address}', derived '{candidate_address}'"
+        );
+        Ok(self.nonce_commitment_with(self.compute_key.pk_sig()))
+    }
+
+    /// Returns the nonce commitment `g_r` := (response * G) + (challenge * pk_sig), for the given `pk_sig`.
+    fn nonce_commitment_with(&self, pk_sig: Group<N>) -> Group<N> {
+        N::g_scalar_multiply(&self.response) + (pk_sig * self.challenge)
+    }
+
+    /// Verifies the signature as in `Signature::verify_detailed`, using the given compute key.
+    #[cfg(any(test, feature = "debug"))]
+    fn verify_detailed_with(
+        &self,
+        compute_key: &ComputeKey<N>,
+        address: &Address<N>,
+        message: &[Field<N>],
+    ) -> Result<()> {
+        self.report_with(compute_key, address, message).map_err(|mismatch| anyhow!("{mismatch}"))
+    }
+
+    /// Verifies the signature with the given compute key, reporting which component mismatched on failure.
+    fn report_with(
+        &self,
+        compute_key: &ComputeKey<N>,
+        address: &Address<N>,
+        message: &[Field<N>],
+    ) -> Result<(), SignatureMismatch<N>> {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
-            eprintln!("Cannot sign the signature: the signed message exceeds maximum allowed size");
-            return false;
+            return Err(SignatureMismatch::Invalid(
+                "Cannot verify the signature: the signed message exceeds maximum allowed size".to_string(),
+            ));
         }
 
         // Retrieve pk_sig.
-        let pk_sig = self.compute_key.pk_sig();
+        let pk_sig = compute_key.pk_sig();
         // Retrieve pr_sig.
-        let pr_sig = self.compute_key.pr_sig();
+        let pr_sig = compute_key.pr_sig();
 
         // Compute `g_r` := (response * G) + (challenge * pk_sig).
-        let g_r = N::g_scalar_multiply(&self.response) + (pk_sig * self.challenge);
+        let g_r = self.nonce_commitment_with(pk_sig);
 
         // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
-        let mut preimage = Vec::with_capacity(4 + message.len());
-        preimage.extend([g_r, pk_sig, pr_sig, **address].map(|point| point.to_x_coordinate()));
-        preimage.extend(message);
-
-        // Hash to derive the verifier challenge, and return `false` if this operation fails.
-        let candidate_challenge = match N::hash_to_scalar_psd8(&preimage) {
-            // Output the computed candidate challenge.
-            Ok(candidate_challenge) => candidate_challenge,
-            // Return `false` if the challenge errored.
-            Err(_) => return false,
-        };
-
-        // Derive the address from the compute key, and return `false` if this operation fails.
-        let candidate_address = match Address::try_from(self.compute_key) {
-            // Output the computed candidate address.
-            Ok(candidate_address) => candidate_address,
-            // Return `false` if the address errored.
-            Err(_) => return false,
-        };
-
-        // Return `true` if the candidate challenge and address are correct.
-        self.challenge == candidate_challenge && *address == candidate_address
+        let preimage = Self::build_challenge_preimage(&[g_r, pk_sig, pr_sig, **address], message);
+
+        // Hash to derive the verifier challenge.
+        let candidate_challenge =
+            N::hash_to_scalar_psd8(&preimage).map_err(|error| SignatureMismatch::Invalid(error.to_string()))?;
+        // Derive the address from the compute key.
+        let candidate_address =
+            Address::try_from(compute_key).map_err(|error| SignatureMismatch::Invalid(error.to_string()))?;
+
+        // Ensure the candidate challenge is correct.
+        if self.challenge != candidate_challenge {
+            return Err(SignatureMismatch::Challenge {
+                expected: self.challenge,
+                recomputed: candidate_challenge,
+                g_r,
+            });
+        }
+        // Ensure the candidate address is correct.
+        if *address != candidate_address {
+            return Err(SignatureMismatch::Address { expected: *address, derived: candidate_address });
+        }
+        Ok(())
     }
 
     /// Verifies a signature for the given address and message (as bytes).
@@ -110,6 +199,160 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_verify_detailed_challenge_mismatch() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample an address, a private key, and a signature.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+        let message: Vec<_> = (0..5).map(|_| Uniform::rand(rng)).collect();
+        let signature = Signature::sign(&private_key, &message, rng)?;
+        assert!(signature.verify_detailed(&address, &message).is_ok());
+
+        // Corrupt the challenge.
+        let corrupted_challenge = signature.challenge() + Scalar::one();
+        let corrupted = Signature::from((corrupted_challenge, signature.response(), signature.compute_key()));
+
+        // Ensure the error reports both the stored and the recomputed challenge.
+        let error = corrupted.verify_detailed(&address, &message).unwrap_err().to_string();
+        assert!(error.contains(&corrupted_challenge.to_string()), "The stored challenge is missing: {error}");
+        // Recompute the challenge, as in `verify_detailed`.
+        let g_r = CurrentNetwork::g_scalar_multiply(&corrupted.response())
+            + (corrupted.compute_key().pk_sig() * corrupted_challenge);
+        let mut preimage = [g_r, signature.compute_key().pk_sig(), signature.compute_key().pr_sig(), *address]
+            .map(|point| point.to_x_coordinate())
+            .to_vec();
+        preimage.extend(&message);
+        let recomputed_challenge = CurrentNetwork::hash_to_scalar_psd8(&preimage)?;
+        assert!(error.contains(&recomputed_challenge.to_string()), "The recomputed challenge is missing: {error}");
+        assert!(!corrupted.verify(&address, &message));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_and_report() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample an address, a private key, and a signature.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+        let message: Vec<_> = (0..5).map(|_| Uniform::rand(rng)).collect();
+        let signature = Signature::sign(&private_key, &message, rng)?;
+        assert_eq!(signature.verify_and_report(&address, &message), Ok(()));
+
+        // Tamper with the response.
+        let corrupted =
+            Signature::from((signature.challenge(), signature.response() + Scalar::one(), signature.compute_key()));
+        assert!(!corrupted.verify(&address, &message));
+
+        // Ensure the report pinpoints the challenge difference.
+        match corrupted.verify_and_report(&address, &message) {
+            Err(SignatureMismatch::Challenge { expected, recomputed, g_r }) => {
+                assert_eq!(expected, signature.challenge());
+                assert_ne!(recomputed, expected);
+                // Ensure `g_r` is reconstructed from the tampered response, and shifted by exactly one generator.
+                assert_eq!(g_r, corrupted.reconstruct_nonce_commitment(&address)?);
+                assert_eq!(
+                    g_r,
+                    signature.reconstruct_nonce_commitment(&address)?
+                        + CurrentNetwork::g_scalar_multiply(&Scalar::one())
+                );
+                // Ensure the recomputed challenge is the hash of the challenge preimage.
+                let compute_key = signature.compute_key();
+                let preimage = Signature::<CurrentNetwork>::build_challenge_preimage(
+                    &[g_r, compute_key.pk_sig(), compute_key.pr_sig(), *address],
+                    &message,
+                );
+                assert_eq!(recomputed, CurrentNetwork::hash_to_scalar_psd8(&preimage)?);
+            }
+            report => panic!("Expected a challenge mismatch, found {report:?}"),
+        }
+
+        // Ensure the report matches the detailed error.
+        let report = corrupted.verify_and_report(&address, &message).unwrap_err();
+        assert_eq!(report.to_string(), corrupted.verify_detailed(&address, &message).unwrap_err().to_string());
+
+        // Ensure a mismatched address is reported.
+        let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        match signature.verify_and_report(&other_address, &message) {
+            // Note that the address is part of the challenge preimage, so the challenge mismatches first.
+            Err(SignatureMismatch::Challenge { expected, .. }) => assert_eq!(expected, signature.challenge()),
+            report => panic!("Expected a challenge mismatch, found {report:?}"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_structured() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample an address, a private key, and a signature over a message of the form `[amount, recipient, nonce]`.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+        let message: Vec<_> = (0..3).map(|_| Uniform::rand(rng)).collect();
+        let signature = Signature::sign(&private_key, &message, rng)?;
+        assert!(signature.verify_structured(&address, &message, 3).is_ok());
+
+        // Ensure a message of the wrong length is rejected with a descriptive error.
+        let error = signature.verify_structured(&address, &message[..2], 3).unwrap_err().to_string();
+        assert!(error.contains("expected a message of 3 fields, found 2 fields"), "Unexpected error: {error}");
+        // Ensure the expected length is checked, even if the signature is valid.
+        let error = signature.verify_structured(&address, &message, 4).unwrap_err().to_string();
+        assert!(error.contains("expected a message of 4 fields, found 3 fields"), "Unexpected error: {error}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_with_compute_key() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a private key, its compute key, and a signature.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let compute_key = ComputeKey::try_from(&private_key)?;
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signature = Signature::sign(&private_key, &message, rng)?;
+
+            // Replace the embedded compute key, as a format that omits it would.
+            let other_compute_key = ComputeKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            let stripped = Signature::from((signature.challenge(), signature.response(), other_compute_key));
+
+            // Check that the correct external compute key verifies.
+            assert!(signature.verify_with_compute_key(&compute_key, &message));
+            assert!(stripped.verify_with_compute_key(&compute_key, &message));
+            // Check that a wrong external compute key fails.
+            assert!(!signature.verify_with_compute_key(&other_compute_key, &message));
+            assert!(!stripped.verify_with_compute_key(&other_compute_key, &message));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_reconstruct_nonce_commitment() -> Result<()> {
+        for i in 0..ITERATIONS {
+            let rng = &mut TestRng::default();
+
+            // Sample an address, a private key, and a message.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+
+            // Sign the message, and recover the nonce by replaying the RNG.
+            let signature = Signature::sign(&private_key, &message, &mut TestRng::fixed(i))?;
+            let nonce = Scalar::<CurrentNetwork>::rand(&mut TestRng::fixed(i));
+
+            // Ensure the reconstructed nonce commitment equals `g_r`.
+            let g_r = CurrentNetwork::g_scalar_multiply(&nonce);
+            assert_eq!(signature.reconstruct_nonce_commitment(&address)?, g_r);
+
+            // Ensure a mismatched address is rejected.
+            let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(signature.reconstruct_nonce_commitment(&other_address).is_err());
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_sign_and_verify_bytes() -> Result<()> {
         let rng = &mut TestRng::default();
@@ -0,0 +1,99 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Pedersen, Poseidon, BHP};
+use snarkvm_console_types::prelude::*;
+
+/// A hash function over bits, projected to a field element.
+/// This trait is object-safe, so a hash algorithm may be selected at runtime as a `dyn HashAlgorithm<E>`.
+pub trait HashAlgorithm<E: Environment> {
+    /// Returns the hash of the given input bits as a field element.
+    fn hash_to_field(&self, input: &[bool]) -> Result<Field<E>>;
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashAlgorithm<E>
+    for BHP<E, NUM_WINDOWS, WINDOW_SIZE>
+{
+    /// Returns the BHP hash of the given input bits as a field element.
+    fn hash_to_field(&self, input: &[bool]) -> Result<Field<E>> {
+        self.hash(input)
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> HashAlgorithm<E> for Pedersen<E, NUM_BITS> {
+    /// Returns the Pedersen hash of the given input bits as a field element.
+    fn hash_to_field(&self, input: &[bool]) -> Result<Field<E>> {
+        self.hash(input)
+    }
+}
+
+impl<E: Environment, const RATE: usize> HashAlgorithm<E> for Poseidon<E, RATE> {
+    /// Returns the Poseidon hash of the given input bits as a field element.
+    /// The input bits are packed into field elements, in chunks of `Field::<E>::size_in_data_bits()` bits.
+    fn hash_to_field(&self, input: &[bool]) -> Result<Field<E>> {
+        // Pack the bits into field elements.
+        let fields =
+            input.chunks(Field::<E>::size_in_data_bits()).map(Field::from_bits_le).collect::<Result<Vec<_>>>()?;
+        // Hash the field elements.
+        self.hash(&fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Pedersen64, Poseidon2, Poseidon8, BHP256, BHP512};
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_hash_to_field_dispatch() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Initialize the hash algorithms.
+        let bhp256 = BHP256::<CurrentEnvironment>::setup("HashAlgorithmTest")?;
+        let bhp512 = BHP512::<CurrentEnvironment>::setup("HashAlgorithmTest")?;
+        let pedersen64 = Pedersen64::<CurrentEnvironment>::setup("HashAlgorithmTest");
+        let poseidon2 = Poseidon2::<CurrentEnvironment>::setup("HashAlgorithmTest")?;
+        let poseidon8 = Poseidon8::<CurrentEnvironment>::setup("HashAlgorithmTest")?;
+
+        for _ in 0..ITERATIONS {
+            // Sample a random input.
+            let input: Vec<bool> = (0..64).map(|_| Uniform::rand(&mut rng)).collect();
+            // Pack the input into field elements, for Poseidon.
+            let fields = vec![Field::from_bits_le(&input)?];
+
+            // Compute the expected hashes from the direct calls.
+            let expected = [
+                bhp256.hash(&input)?,
+                bhp512.hash(&input)?,
+                pedersen64.hash(&input)?,
+                poseidon2.hash(&fields)?,
+                poseidon8.hash(&fields)?,
+            ];
+
+            // Select each hash algorithm at runtime.
+            let algorithms: [&dyn HashAlgorithm<CurrentEnvironment>; 5] =
+                [&bhp256, &bhp512, &pedersen64, &poseidon2, &poseidon8];
+            for (algorithm, expected) in algorithms.iter().zip_eq(expected) {
+                assert_eq!(expected, algorithm.hash_to_field(&input)?);
+            }
+        }
+        Ok(())
+    }
+}
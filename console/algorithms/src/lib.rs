@@ -29,6 +29,9 @@ pub use blake2xs::Blake2Xs;
 mod elligator2;
 pub use elligator2::Elligator2;
 
+mod hash_algorithm;
+pub use hash_algorithm::HashAlgorithm;
+
 mod keccak;
 pub use keccak::*;
 
@@ -36,4 +39,4 @@ mod pedersen;
 pub use pedersen::{Pedersen, Pedersen128, Pedersen64};
 
 mod poseidon;
-pub use poseidon::{Poseidon, Poseidon2, Poseidon4, Poseidon8};
+pub use poseidon::{AlgebraicSponge, Poseidon, Poseidon16, Poseidon2, Poseidon4, Poseidon8, PoseidonSponge};
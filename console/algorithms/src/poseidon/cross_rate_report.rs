@@ -0,0 +1,64 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> Poseidon<E, RATE, CAPACITY> {
+    /// Returns the hash of each input under this hasher and under the `other` hasher, as `(self, other)` pairs.
+    ///
+    /// This is intended for migrating between Poseidon rates (e.g. from `Poseidon4` to `Poseidon8`),
+    /// so that the digests of an existing dataset can be diffed across the two rates.
+    pub fn cross_rate_report<const OTHER_RATE: usize>(
+        &self,
+        other: &Poseidon<E, OTHER_RATE>,
+        inputs: &[Vec<Field<E>>],
+    ) -> Result<Vec<(Field<E>, Field<E>)>> {
+        inputs.iter().map(|input| Ok((self.hash(input)?, other.hash(input)?))).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_cross_rate_report() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let poseidon4 = Poseidon4::<CurrentEnvironment>::setup("PoseidonMigration")?;
+        let poseidon8 = Poseidon8::<CurrentEnvironment>::setup("PoseidonMigration")?;
+
+        // Sample inputs of varying lengths.
+        let inputs = (0..ITERATIONS)
+            .map(|num_inputs| (0..num_inputs).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let report = poseidon4.cross_rate_report(&poseidon8, &inputs)?;
+        assert_eq!(report.len(), inputs.len());
+        for ((rate_4, rate_8), input) in report.iter().zip_eq(&inputs) {
+            // Ensure each pair matches the individual hashers.
+            assert_eq!(*rate_4, poseidon4.hash(input)?);
+            assert_eq!(*rate_8, poseidon8.hash(input)?);
+            // Ensure the digests differ across the rates.
+            assert_ne!(rate_4, rate_8);
+        }
+        Ok(())
+    }
+}
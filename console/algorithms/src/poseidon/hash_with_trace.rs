@@ -0,0 +1,83 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> Poseidon<E, RATE, CAPACITY> {
+    /// Returns the cryptographic hash for a list of field elements as input, as in `Hash::hash`,
+    /// along with the sponge state after each round of each permutation.
+    ///
+    /// Each state is ordered as the capacity elements followed by the rate elements. This allows a
+    /// developer to diff the native hash round by round against a reference, to localize parameter bugs.
+    pub fn hash_with_trace(&self, input: &[Field<E>]) -> (Field<E>, Vec<Vec<Field<E>>>) {
+        // Construct the preimage: [ DOMAIN || LENGTH(INPUT) || [0; RATE-2] || INPUT ].
+        let mut preimage = Vec::with_capacity(RATE + input.len());
+        preimage.push(self.domain);
+        preimage.push(Field::<E>::from_u128(input.len() as u128));
+        preimage.resize(RATE, Field::<E>::zero()); // Pad up to RATE.
+        preimage.extend_from_slice(input);
+
+        let mut sponge = PoseidonSponge::<E, RATE, CAPACITY>::new(&self.parameters);
+        sponge.enable_round_trace();
+        sponge.absorb(&preimage);
+        let output = sponge.squeeze(1)[0];
+        (output, sponge.take_round_trace())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const CAPACITY: usize = 1;
+    const ITERATIONS: usize = 10;
+
+    fn check_hash_with_trace<const RATE: usize>(rng: &mut TestRng) -> Result<()> {
+        let poseidon = Poseidon::<CurrentEnvironment, RATE>::setup("PoseidonTrace")?;
+        let num_rounds = poseidon.parameters().full_rounds + poseidon.parameters().partial_rounds;
+
+        // An empty input is absorbed in a single permutation.
+        let (output, trace) = poseidon.hash_with_trace(&[]);
+        assert_eq!(output, poseidon.hash(&[])?);
+        assert_eq!(trace.len(), num_rounds);
+        // Ensure the output is squeezed from the first rate element of the final state.
+        assert_eq!(output, trace[num_rounds - 1][CAPACITY]);
+
+        for num_inputs in 1..=2 * RATE {
+            let input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+            let (output, trace) = poseidon.hash_with_trace(&input);
+            // Ensure the output matches the hash.
+            assert_eq!(output, poseidon.hash(&input)?);
+            // Ensure one permutation is traced for the padded prefix and for each started chunk of the input.
+            assert_eq!(trace.len(), (1 + num_inputs.div_ceil(RATE)) * num_rounds);
+            assert!(trace.iter().all(|state| state.len() == RATE + CAPACITY));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_with_trace() -> Result<()> {
+        let mut rng = TestRng::default();
+        for _ in 0..ITERATIONS {
+            check_hash_with_trace::<2>(&mut rng)?;
+            check_hash_with_trace::<4>(&mut rng)?;
+            check_hash_with_trace::<8>(&mut rng)?;
+        }
+        Ok(())
+    }
+}
@@ -37,6 +37,9 @@ pub struct PoseidonSponge<E: Environment, const RATE: usize, const CAPACITY: usi
     state: State<E, RATE, CAPACITY>,
     /// Current mode (whether its absorbing or squeezing)
     pub(in crate::poseidon) mode: DuplexSpongeMode,
+    /// The state after each round of each permutation, if round tracing is enabled.
+    #[cfg(feature = "debug")]
+    round_states: Option<Vec<Vec<Field<E>>>>,
 }
 
 impl<E: Environment, const RATE: usize, const CAPACITY: usize> AlgebraicSponge<E, RATE, CAPACITY>
@@ -49,6 +52,8 @@ impl<E: Environment, const RATE: usize, const CAPACITY: usize> AlgebraicSponge<E
             parameters: parameters.clone(),
             state: State::default(),
             mode: DuplexSpongeMode::Absorbing { next_absorb_index: 0 },
+            #[cfg(feature = "debug")]
+            round_states: None,
         }
     }
 
@@ -146,6 +151,12 @@ impl<E: Environment, const RATE: usize, const CAPACITY: usize> PoseidonSponge<E,
             self.apply_ark(i);
             self.apply_s_box(is_full_round);
             self.apply_mds();
+
+            // If round tracing is enabled, record the state after this round.
+            #[cfg(feature = "debug")]
+            if let Some(round_states) = &mut self.round_states {
+                round_states.push(self.state.iter().copied().collect());
+            }
         }
     }
 
@@ -227,3 +238,26 @@ impl<E: Environment, const RATE: usize, const CAPACITY: usize> PoseidonSponge<E,
         }
     }
 }
+
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> PoseidonSponge<E, RATE, CAPACITY> {
+    /// Applies the permutation once to the given state, as `[ CAPACITY || RATE ]` field elements,
+    /// and returns the permuted state.
+    pub(in crate::poseidon) fn permute_state(&mut self, state: &[Field<E>]) -> Vec<Field<E>> {
+        self.state.iter_mut().zip_eq(state).for_each(|(element, input)| *element = *input);
+        self.permute();
+        self.state.iter().copied().collect()
+    }
+}
+
+#[cfg(feature = "debug")]
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> PoseidonSponge<E, RATE, CAPACITY> {
+    /// Enables recording the state after each round of each permutation.
+    pub(in crate::poseidon) fn enable_round_trace(&mut self) {
+        self.round_states = Some(Vec::new());
+    }
+
+    /// Returns the recorded state after each round of each permutation, in order.
+    pub(in crate::poseidon) fn take_round_trace(&mut self) -> Vec<Vec<Field<E>>> {
+        self.round_states.take().unwrap_or_default()
+    }
+}
@@ -15,47 +15,88 @@
 
 mod helpers;
 
+mod cross_rate_report;
 mod hash;
 mod hash_many;
 mod hash_to_group;
 mod hash_to_scalar;
+#[cfg(feature = "debug")]
+mod hash_with_trace;
+mod parameters_hash;
+mod permute;
 mod prf;
 
+pub use helpers::{AlgebraicSponge, PoseidonSponge};
+
 use crate::{poseidon::helpers::*, Elligator2};
 use snarkvm_console_types::prelude::*;
-use snarkvm_fields::{PoseidonDefaultField, PoseidonParameters};
+use snarkvm_fields::{PoseidonDefaultField, PoseidonParameters, PrimeField};
 
 use std::sync::Arc;
 
-const CAPACITY: usize = 1;
-
 /// Poseidon2 is a cryptographic hash function of input rate 2.
 pub type Poseidon2<E> = Poseidon<E, 2>;
 /// Poseidon4 is a cryptographic hash function of input rate 4.
 pub type Poseidon4<E> = Poseidon<E, 4>;
 /// Poseidon8 is a cryptographic hash function of input rate 8.
 pub type Poseidon8<E> = Poseidon<E, 8>;
-
+/// Poseidon16 is a cryptographic hash function of input rate 16.
+///
+/// Note: The digest differs from that of `Poseidon8`, including when the input is split into two rate-8 chunks,
+/// as the permutation width and round constants depend on the rate.
+pub type Poseidon16<E> = Poseidon<E, 16>;
+
+/// The Poseidon hash function, of input rate `RATE` and capacity `CAPACITY` (which defaults to 1).
+///
+/// The permutation operates on a state of `RATE + CAPACITY` field elements.
 #[derive(Clone, Debug, PartialEq)]
-pub struct Poseidon<E: Environment, const RATE: usize> {
+pub struct Poseidon<E: Environment, const RATE: usize, const CAPACITY: usize = 1> {
     /// The domain separator for the Poseidon hash function.
     domain: Field<E>,
     /// The Poseidon parameters for hashing.
     parameters: Arc<PoseidonParameters<E::Field, RATE, CAPACITY>>,
 }
 
-impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> Poseidon<E, RATE, CAPACITY> {
     /// Initializes a new instance of Poseidon.
     pub fn setup(domain: &str) -> Result<Self> {
+        Self::with_parameters(
+            domain,
+            Arc::new(E::Field::default_poseidon_parameters_with_capacity::<RATE, CAPACITY>()?),
+        )
+    }
+
+    /// Initializes a new instance of Poseidon with the given parameters.
+    pub fn with_parameters(
+        domain: &str,
+        parameters: Arc<PoseidonParameters<E::Field, RATE, CAPACITY>>,
+    ) -> Result<Self> {
         // Ensure the given domain is within the allowed size in bits.
         let num_bits = domain.len().saturating_mul(8);
         let max_bits = Field::<E>::size_in_data_bits();
         ensure!(num_bits <= max_bits, "Domain cannot exceed {max_bits} bits, found {num_bits} bits");
 
-        Ok(Self {
-            domain: Field::<E>::new_domain_separator(domain),
-            parameters: Arc::new(E::Field::default_poseidon_parameters::<RATE>()?),
-        })
+        // Ensure there are `RATE + CAPACITY` additive round keys for each round.
+        let num_rounds = parameters.full_rounds + parameters.partial_rounds;
+        ensure!(
+            parameters.ark.len() == num_rounds && parameters.ark.iter().all(|round| round.len() == RATE + CAPACITY),
+            "Poseidon{RATE} expects {num_rounds} rounds of {} additive round keys, found {} rounds of sizes {:?}",
+            RATE + CAPACITY,
+            parameters.ark.len(),
+            parameters.ark.iter().map(|round| round.len()).collect::<Vec<_>>()
+        );
+        // Ensure the MDS matrix is a square matrix of size `RATE + CAPACITY`.
+        ensure!(
+            parameters.mds.len() == RATE + CAPACITY && parameters.mds.iter().all(|row| row.len() == RATE + CAPACITY),
+            "Poseidon{RATE} expects a {0}x{0} MDS matrix, found {1} rows of sizes {2:?}",
+            RATE + CAPACITY,
+            parameters.mds.len(),
+            parameters.mds.iter().map(|row| row.len()).collect::<Vec<_>>()
+        );
+        // Ensure the MDS matrix is invertible.
+        ensure!(is_invertible(&parameters.mds), "Poseidon{RATE} expects an invertible MDS matrix");
+
+        Ok(Self { domain: Field::<E>::new_domain_separator(domain), parameters })
     }
 
     /// Returns the domain separator for the hash function.
@@ -67,6 +108,37 @@ impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
     pub fn parameters(&self) -> &Arc<PoseidonParameters<E::Field, RATE, CAPACITY>> {
         &self.parameters
     }
+
+    /// Initializes a new sponge, for interleaving the absorption of inputs with the squeezing of outputs.
+    ///
+    /// Note: Unlike `hash`, the sponge does not absorb the domain separator or the input length.
+    pub fn start_sponge(&self) -> PoseidonSponge<E, RATE, CAPACITY> {
+        PoseidonSponge::new(&self.parameters)
+    }
+}
+
+/// Returns `true` if the given square matrix is invertible, by reducing it to row echelon form.
+fn is_invertible<F: PrimeField>(matrix: &[Vec<F>]) -> bool {
+    let mut matrix = matrix.to_vec();
+    for column in 0..matrix.len() {
+        // Find a row with a nonzero entry in this column, and move it into the pivot position.
+        match (column..matrix.len()).find(|row| !matrix[*row][column].is_zero()) {
+            Some(pivot) => matrix.swap(column, pivot),
+            None => return false,
+        }
+        let inverse = match matrix[column][column].inverse() {
+            Some(inverse) => inverse,
+            None => return false,
+        };
+        // Eliminate this column from the rows below the pivot.
+        let (upper, lower) = matrix.split_at_mut(column + 1);
+        let pivot_row = &upper[column];
+        for row in lower {
+            let factor = row[column] * inverse;
+            row.iter_mut().zip_eq(pivot_row).skip(column).for_each(|(entry, pivot)| *entry -= factor * *pivot);
+        }
+    }
+    true
 }
 
 #[cfg(test)]
@@ -78,6 +150,8 @@ mod tests {
 
     type CurrentEnvironment = Console;
 
+    const CAPACITY: usize = 1;
+
     use std::{path::PathBuf, sync::Arc};
 
     /// Returns the path to the `resources` folder for this module.
@@ -155,6 +229,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_start_sponge() -> Result<()> {
+        const RATE: usize = 4;
+        let poseidon = Poseidon::<CurrentEnvironment, RATE>::setup("PoseidonSponge")?;
+        let input = [Field::<CurrentEnvironment>::from_u64(1), Field::from_u64(2), Field::from_u64(3)];
+
+        // Absorb the preimage of `hash_many` in pieces: [ DOMAIN || LENGTH(INPUT) || [0; RATE-2] || INPUT ].
+        let mut sponge = poseidon.start_sponge();
+        sponge.absorb(&[poseidon.domain(), Field::from_u64(input.len() as u64)]);
+        sponge.absorb(&[Field::zero(); RATE - 2]);
+        sponge.absorb(&input);
+
+        // Ensure the sponge agrees with `hash_many`.
+        assert_eq!(sponge.squeeze(3).to_vec(), poseidon.hash_many(&input, 3));
+        Ok(())
+    }
+
     #[test]
     fn test_parameters() {
         fn single_rate_test<const RATE: usize>() {
@@ -172,6 +263,95 @@ mod tests {
         single_rate_test::<8>();
     }
 
+    #[test]
+    fn test_parameters_rate_16() -> Result<()> {
+        const RATE: usize = 16;
+        let parameters = Fq::default_poseidon_parameters::<RATE>()?;
+
+        // Ensure there is one additive round key per state element, for each round.
+        assert_eq!(parameters.ark.len(), parameters.full_rounds + parameters.partial_rounds);
+        assert!(parameters.ark.iter().all(|round| round.len() == RATE + CAPACITY));
+        // Ensure the MDS matrix is square over the state, with no zero entries.
+        assert_eq!(parameters.mds.len(), RATE + CAPACITY);
+        assert!(parameters.mds.iter().all(|row| row.len() == RATE + CAPACITY));
+        assert!(parameters.mds.iter().flatten().all(|entry| !entry.is_zero()));
+
+        // Ensure the rate-16 hash is distinct from the rate-8 hash over the same input.
+        let input = (0..2 * RATE).map(|i| Field::<Console>::from_u64(i as u64)).collect::<Vec<_>>();
+        let poseidon8 = Poseidon8::<Console>::setup("Poseidon")?;
+        let poseidon16 = Poseidon16::<Console>::setup("Poseidon")?;
+        assert_eq!(poseidon16.hash(&input)?, poseidon16.hash(&input)?);
+        assert_ne!(poseidon16.hash(&input)?, poseidon8.hash(&input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_capacity_2() -> Result<()> {
+        const RATE: usize = 4;
+
+        // Ensure the parameters are sampled over a state of `RATE + 2` elements.
+        let parameters = Fq::default_poseidon_parameters_with_capacity::<RATE, 2>()?;
+        assert_eq!(parameters.ark.len(), parameters.full_rounds + parameters.partial_rounds);
+        assert!(parameters.ark.iter().all(|round| round.len() == RATE + 2));
+        assert_eq!(parameters.mds.len(), RATE + 2);
+        assert!(parameters.mds.iter().all(|row| row.len() == RATE + 2));
+
+        // Ensure the round numbers are those tabulated for a state of `RATE + 2` elements.
+        let wider = Fq::default_poseidon_parameters::<{ RATE + 1 }>()?;
+        assert_eq!((parameters.full_rounds, parameters.partial_rounds), (wider.full_rounds, wider.partial_rounds));
+        assert_eq!(parameters.alpha, wider.alpha);
+
+        // Ensure a capacity whose state width is not tabulated is rejected.
+        assert!(Fq::default_poseidon_parameters_with_capacity::<8, 2>().is_err());
+        assert!(Fq::default_poseidon_parameters_with_capacity::<RATE, 0>().is_err());
+
+        // Ensure a capacity of 1 yields the default parameters.
+        assert_eq!(
+            Fq::default_poseidon_parameters_with_capacity::<RATE, 1>()?,
+            Fq::default_poseidon_parameters::<RATE>()?
+        );
+
+        // Ensure the capacity-2 hash is distinct from the capacity-1 hash over the same input.
+        let poseidon = Poseidon::<Console, RATE, 2>::setup("Poseidon")?;
+        let default = Poseidon::<Console, RATE>::setup("Poseidon")?;
+        for num_inputs in 0..=2 * RATE {
+            let input = (0..num_inputs).map(|i| Field::<Console>::from_u64(i as u64)).collect::<Vec<_>>();
+            assert_eq!(poseidon.hash(&input)?, poseidon.hash(&input)?);
+            assert_ne!(poseidon.hash(&input)?, default.hash(&input)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_parameters_rejects_malformed_parameters() -> Result<()> {
+        const RATE: usize = 4;
+        let default = Poseidon::<Console, RATE>::setup("Poseidon")?.parameters().as_ref().clone();
+        assert!(is_invertible(&default.mds));
+
+        // Returns the error message of `with_parameters`, on the default parameters modified by `malform`.
+        let check = |malform: &dyn Fn(&mut PoseidonParameters<Fq, RATE, CAPACITY>), expected: &str| {
+            let mut parameters = default.clone();
+            malform(&mut parameters);
+            let error = Poseidon::<Console, RATE>::with_parameters("Poseidon", Arc::new(parameters)).unwrap_err();
+            assert!(error.to_string().contains(expected), "Unexpected error: {error}");
+        };
+        check(&|parameters| drop(parameters.ark.pop()), "rounds of 5 additive round keys, found");
+        check(&|parameters| drop(parameters.ark[0].pop()), "rounds of 5 additive round keys, found");
+        check(
+            &|parameters| drop(parameters.mds.pop()),
+            "Poseidon4 expects a 5x5 MDS matrix, found 4 rows of sizes [5, 5, 5, 5]",
+        );
+        check(
+            &|parameters| drop(parameters.mds[RATE].pop()),
+            "Poseidon4 expects a 5x5 MDS matrix, found 5 rows of sizes [5, 5, 5, 5, 4]",
+        );
+        check(
+            &|parameters| parameters.mds[1] = parameters.mds[0].clone(),
+            "Poseidon4 expects an invertible MDS matrix",
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_suite_hash2() {
         fn test_case_hash2(index: u8, input: Vec<Field<CurrentEnvironment>>) {
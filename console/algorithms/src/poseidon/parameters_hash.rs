@@ -0,0 +1,101 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Sha3_256;
+
+/// The domain separator for the Poseidon parameters fingerprint.
+const PARAMETERS_DOMAIN: &str = "PoseidonParameters";
+
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> Poseidon<E, RATE, CAPACITY> {
+    /// Returns a fingerprint of the Poseidon parameters, which nodes may compare to detect parameter drift.
+    ///
+    /// The fingerprint is the SHA3-256 hash of the rate, capacity, number of rounds, `alpha`, and the dimensions
+    /// and entries of the `ark` and `mds` matrices, truncated into a field element. It is computed independently
+    /// of the parameters being fingerprinted, and of the domain separator of this instance.
+    pub fn parameters_hash(&self) -> Result<Field<E>> {
+        let parameters = &self.parameters;
+
+        // Construct the preimage: [ DOMAIN || RATE || CAPACITY || FULL_ROUNDS || PARTIAL_ROUNDS || ALPHA || ARK || MDS ].
+        let mut preimage = Field::<E>::new_domain_separator(PARAMETERS_DOMAIN).to_bits_le();
+        for value in [RATE, CAPACITY, parameters.full_rounds, parameters.partial_rounds] {
+            preimage.extend((value as u64).to_bits_le());
+        }
+        preimage.extend(parameters.alpha.to_bits_le());
+        for matrix in [&parameters.ark, &parameters.mds] {
+            // Absorb the number of rows, then each row prefixed by its length.
+            preimage.extend((matrix.len() as u64).to_bits_le());
+            for row in matrix {
+                preimage.extend((row.len() as u64).to_bits_le());
+                row.iter().for_each(|element| preimage.extend(Field::<E>::new(*element).to_bits_le()));
+            }
+        }
+
+        // Hash the preimage, and truncate the digest into a field element.
+        let digest = Sha3_256::default().hash(&preimage)?;
+        Field::from_bits_le(&digest[..Field::<E>::size_in_data_bits()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_parameters_hash_is_stable() -> Result<()> {
+        fn check_rate<const RATE: usize>() -> Result<Field<CurrentEnvironment>> {
+            // Ensure the fingerprint is independent of the domain and the instance.
+            let first = Poseidon::<CurrentEnvironment, RATE>::setup("PoseidonFirst")?;
+            let second = Poseidon::<CurrentEnvironment, RATE>::setup("PoseidonSecond")?;
+            assert_eq!(first.parameters_hash()?, first.parameters_hash()?);
+            assert_eq!(first.parameters_hash()?, second.parameters_hash()?);
+            Ok(first.parameters_hash()?)
+        }
+
+        // Ensure the fingerprints differ across rates.
+        let fingerprints = [check_rate::<2>()?, check_rate::<4>()?, check_rate::<8>()?];
+        assert_ne!(fingerprints[0], fingerprints[1]);
+        assert_ne!(fingerprints[0], fingerprints[2]);
+        assert_ne!(fingerprints[1], fingerprints[2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parameters_hash_detects_changes() -> Result<()> {
+        let poseidon = Poseidon2::<CurrentEnvironment>::setup("Poseidon2")?;
+        let expected = poseidon.parameters_hash()?;
+
+        // Ensure the unaltered parameters yield the same fingerprint.
+        let parameters = (**poseidon.parameters()).clone();
+        let candidate = Poseidon2::<CurrentEnvironment>::with_parameters("Poseidon2", Arc::new(parameters.clone()))?;
+        assert_eq!(expected, candidate.parameters_hash()?);
+
+        // Alter each parameter in turn, and ensure the fingerprint changes.
+        let mut alterations = vec![parameters.clone(); 5];
+        alterations[0].full_rounds += 1;
+        alterations[1].partial_rounds += 1;
+        alterations[2].alpha += 2;
+        alterations[3].ark[0][0] += <CurrentEnvironment as Environment>::Field::one();
+        alterations[4].mds[1][1] += <CurrentEnvironment as Environment>::Field::one();
+        for parameters in alterations {
+            let candidate = Poseidon2::<CurrentEnvironment>::with_parameters("Poseidon2", Arc::new(parameters))?;
+            assert_ne!(expected, candidate.parameters_hash()?);
+        }
+        Ok(())
+    }
+}
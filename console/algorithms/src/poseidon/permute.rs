@@ -0,0 +1,75 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> Poseidon<E, RATE, CAPACITY> {
+    /// Applies the Poseidon permutation once to the given state of `RATE + CAPACITY` field elements,
+    /// and returns the permuted state.
+    ///
+    /// The state is ordered as the capacity elements followed by the rate elements, as in the sponge.
+    /// This allows building custom constructions (e.g. a Merkle tree of a specific arity) on the permutation.
+    pub fn permute(&self, state: &[Field<E>]) -> Result<Vec<Field<E>>> {
+        // Ensure the state is of the correct size.
+        ensure!(
+            state.len() == RATE + CAPACITY,
+            "Poseidon{RATE} expects a state of {} elements, found {}",
+            RATE + CAPACITY,
+            state.len()
+        );
+        Ok(PoseidonSponge::<E, RATE, CAPACITY>::new(&self.parameters).permute_state(state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_permute<const RATE: usize>(rng: &mut TestRng) -> Result<()> {
+        let poseidon = Poseidon::<CurrentEnvironment, RATE>::setup("PoseidonPermute")?;
+
+        // Ensure the hash of an empty input is squeezed from a single permutation of the padded prefix,
+        // whose state is [ 0 || DOMAIN || LENGTH(INPUT) || [0; RATE-2] ].
+        let mut state = vec![Field::zero(); RATE + 1];
+        state[1] = poseidon.domain();
+        assert_eq!(poseidon.permute(&state)?[1], poseidon.hash(&[])?);
+
+        for _ in 0..ITERATIONS {
+            // Ensure the permutation is deterministic, and is not the identity.
+            let state = (0..RATE + 1).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+            let permuted = poseidon.permute(&state)?;
+            assert_eq!(permuted, poseidon.permute(&state)?);
+            assert_ne!(permuted, state);
+        }
+
+        // Ensure a state of the wrong size is rejected.
+        assert!(poseidon.permute(&vec![Field::zero(); RATE]).is_err());
+        assert!(poseidon.permute(&vec![Field::zero(); RATE + 2]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_permute() -> Result<()> {
+        let mut rng = TestRng::default();
+        check_permute::<2>(&mut rng)?;
+        check_permute::<4>(&mut rng)?;
+        check_permute::<8>(&mut rng)
+    }
+}
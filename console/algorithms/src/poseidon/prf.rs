@@ -15,7 +15,7 @@
 
 use super::*;
 
-impl<E: Environment, const RATE: usize> PRF for Poseidon<E, RATE> {
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> PRF for Poseidon<E, RATE, CAPACITY> {
     type Input = Field<E>;
     type Output = Field<E>;
     type Seed = Field<E>;
@@ -31,3 +31,64 @@ impl<E: Environment, const RATE: usize> PRF for Poseidon<E, RATE> {
         self.hash(&preimage)
     }
 }
+
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> Poseidon<E, RATE, CAPACITY> {
+    /// Returns `num_outputs` PRF outputs for the given seed and input, e.g. to derive a keystream.
+    ///
+    /// The preimage `seed || input` is absorbed as in `prf`, and the outputs are squeezed from the sponge,
+    /// so the first output is equal to `prf(seed, input)`.
+    pub fn prf_many(&self, seed: &Field<E>, input: &[Field<E>], num_outputs: usize) -> Result<Vec<Field<E>>> {
+        // Ensure the number of outputs is within the bounds of the sponge.
+        let num_outputs = match u16::try_from(num_outputs) {
+            Ok(num_outputs) => num_outputs,
+            Err(_) => bail!("Cannot squeeze {num_outputs} PRF outputs, the maximum is {}", u16::MAX),
+        };
+
+        // Construct the preimage: seed || input.
+        let mut preimage = Vec::with_capacity(1 + input.len());
+        preimage.push(*seed);
+        preimage.extend_from_slice(input);
+
+        // Hash the preimage to derive the PRF outputs.
+        Ok(self.hash_many(&preimage, num_outputs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 100;
+
+    fn check_prf_many<const RATE: usize>(rng: &mut TestRng) -> Result<()> {
+        let poseidon = Poseidon::<CurrentEnvironment, RATE>::setup("PoseidonPRFMany")?;
+        for i in 0..ITERATIONS {
+            let seed = Uniform::rand(rng);
+            let input = (0..i % 10).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+
+            // Ensure the first output is the single-output PRF.
+            let outputs = poseidon.prf_many(&seed, &input, 8)?;
+            assert_eq!(outputs.len(), 8);
+            assert_eq!(outputs[0], poseidon.prf(&seed, &input)?);
+            // Ensure the outputs are deterministic, and a prefix of a longer keystream.
+            assert_eq!(outputs, poseidon.prf_many(&seed, &input, 8)?);
+            assert_eq!(outputs[..4], poseidon.prf_many(&seed, &input, 4)?);
+            // Ensure a different seed yields different outputs.
+            assert_ne!(outputs, poseidon.prf_many(&Uniform::rand(rng), &input, 8)?);
+        }
+        // Ensure too many outputs are rejected.
+        assert!(poseidon.prf_many(&Uniform::rand(rng), &[], u16::MAX as usize + 1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_prf_many() -> Result<()> {
+        let mut rng = TestRng::default();
+        check_prf_many::<2>(&mut rng)?;
+        check_prf_many::<4>(&mut rng)?;
+        check_prf_many::<8>(&mut rng)
+    }
+}
@@ -15,6 +15,13 @@
 
 use anyhow::Result;
 
+/// Binary operator for computing the absolute difference of two values, enforcing an overflow never occurs.
+pub trait AbsDiff<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn abs_diff(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for adding two values, enforcing an overflow never occurs.
 pub trait AddChecked<Rhs: ?Sized = Self> {
     type Output;
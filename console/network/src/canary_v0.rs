@@ -395,6 +395,11 @@ impl Network for CanaryV0 {
         CANARY_POSEIDON_8.hash_many(input, num_outputs)
     }
 
+    /// Returns the Poseidon PRF with an input rate of 2, on the given seed and input.
+    fn prf_psd2(seed: &Field<Self>, input: &[Field<Self>]) -> Result<Field<Self>> {
+        CANARY_POSEIDON_2.prf(seed, input)
+    }
+
     /// Returns the BHP hash with an input hasher of 256-bits.
     fn hash_to_group_bhp256(input: &[bool]) -> Result<Group<Self>> {
         CANARY_BHP_256.hash_uncompressed(input)
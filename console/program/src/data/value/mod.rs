@@ -18,10 +18,12 @@ mod equal;
 mod find;
 mod parse;
 mod serialize;
+mod sign;
 mod to_bits;
 mod to_fields;
 
 use crate::{Access, Argument, Entry, Future, Literal, Plaintext, Record};
+use snarkvm_console_account::{Address, PrivateKey, Signature};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
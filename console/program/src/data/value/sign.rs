@@ -0,0 +1,98 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns a signature for the value, under the given private key.
+    ///
+    /// The value is signed as its field elements, in the same canonical encoding as `Value::to_fields`,
+    /// so a literal, struct, record, or future is signed exactly as the VM represents it.
+    pub fn sign<R: Rng + CryptoRng>(&self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<Signature<N>> {
+        Signature::sign(private_key, &self.to_fields()?, rng)
+    }
+
+    /// Returns `true` if the signature is valid for the value, under the given address.
+    pub fn verify(&self, address: &Address<N>, signature: &Signature<N>) -> bool {
+        match self.to_fields() {
+            Ok(fields) => signature.verify(address, &fields),
+            Err(error) => {
+                eprintln!("Failed to convert the value into field elements: {error}");
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: usize = 25;
+
+    #[test]
+    fn test_sign_and_verify_nested_struct() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Initialize a nested struct.
+        let value = Value::<CurrentNetwork>::from_str("{ a: 1u8, inner: { b: 2field, c: true }, d: [3u32, 4u32] }")?;
+        // Initialize a value with a different nested member.
+        let other = Value::<CurrentNetwork>::from_str("{ a: 1u8, inner: { b: 2field, c: false }, d: [3u32, 4u32] }")?;
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::new(&mut rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Ensure the signature is valid for the value.
+            let signature = value.sign(&private_key, &mut rng)?;
+            assert!(value.verify(&address, &signature));
+            // Ensure the signature matches signing the fields directly.
+            assert!(signature.verify(&address, &value.to_fields()?));
+
+            // Ensure the signature is invalid for a different value.
+            assert!(!other.verify(&address, &signature));
+            // Ensure the signature is invalid for a different address.
+            assert!(!value.verify(&Address::try_from(&PrivateKey::new(&mut rng)?)?, &signature));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_and_verify_reordered_struct() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Initialize a struct, and the same struct with its members reordered.
+        let value = Value::<CurrentNetwork>::from_str("{ a: 1u8, inner: { b: 2field, c: true } }")?;
+        let reordered = Value::<CurrentNetwork>::from_str("{ inner: { c: true, b: 2field }, a: 1u8 }")?;
+
+        // Ensure the member order is part of the canonical encoding.
+        assert_ne!(value.to_fields()?, reordered.to_fields()?);
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::new(&mut rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Ensure a signature over one member order is invalid for the other.
+            let signature = value.sign(&private_key, &mut rng)?;
+            assert!(!reordered.verify(&address, &signature));
+            let signature = reordered.sign(&private_key, &mut rng)?;
+            assert!(!value.verify(&address, &signature));
+        }
+        Ok(())
+    }
+}
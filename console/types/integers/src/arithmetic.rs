@@ -60,6 +60,24 @@ impl<E: Environment, I: IntegerType> AbsWrapped for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> AbsDiff<Integer<E, I>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the `absolute difference` of `self` and `other`.
+    #[inline]
+    fn abs_diff(&self, other: &Integer<E, I>) -> Self::Output {
+        // Subtract the smaller value from the larger value, which only overflows for signed integers.
+        let difference = match self.integer >= other.integer {
+            true => self.integer.checked_sub(&other.integer),
+            false => other.integer.checked_sub(&self.integer),
+        };
+        match difference {
+            Some(integer) => Integer::new(integer),
+            None => E::halt(format!("Integer absolute difference failed on: {self} and {other}")),
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> Add<Integer<E, I>> for Integer<E, I> {
     type Output = Integer<E, I>;
 
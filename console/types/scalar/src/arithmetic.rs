@@ -15,6 +15,17 @@
 
 use super::*;
 
+impl<E: Environment> Scalar<E> {
+    /// Returns `self - (a * b)`.
+    ///
+    /// Unlike the `Mul` and `Sub` implementations, which may branch on their operands in the final reductions,
+    /// this is computed in constant time, so it is safe to use with a secret operand (e.g. a signing key).
+    #[inline]
+    pub fn ct_mul_sub(&self, a: &Scalar<E>, b: &Scalar<E>) -> Self {
+        Scalar::new(self.scalar.ct_mul_sub(&a.scalar, &b.scalar))
+    }
+}
+
 impl<E: Environment> Neg for Scalar<E> {
     type Output = Scalar<E>;
 
@@ -273,6 +284,29 @@ mod tests {
 
     type CurrentEnvironment = Console;
 
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_ct_mul_sub() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let a = Scalar::<CurrentEnvironment>::rand(&mut rng);
+            let b = Scalar::<CurrentEnvironment>::rand(&mut rng);
+            let c = Scalar::<CurrentEnvironment>::rand(&mut rng);
+            // Ensure the constant-time path matches the variable-time arithmetic.
+            assert_eq!(a.ct_mul_sub(&b, &c), a - (b * c));
+        }
+
+        // Ensure the result is correct when the subtraction wraps around the modulus.
+        let one = Scalar::<CurrentEnvironment>::one();
+        let zero = Scalar::<CurrentEnvironment>::zero();
+        assert_eq!(zero.ct_mul_sub(&one, &one), -one);
+        assert_eq!(one.ct_mul_sub(&one, &one), zero);
+        assert_eq!(zero.ct_mul_sub(&-one, &-one), -one);
+        assert_eq!(one.ct_mul_sub(&zero, &one), one);
+    }
+
     #[test]
     fn test_div_by_zero_fails() {
         let one = Scalar::<CurrentEnvironment>::one();
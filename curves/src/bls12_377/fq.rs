@@ -186,6 +186,8 @@ impl PoseidonDefaultParameters for FqParameters {
         PoseidonDefaultParametersEntry::new(7, 5, 8, 57, 0),
         PoseidonDefaultParametersEntry::new(8, 5, 8, 57, 0),
     ];
+    const PARAMS_OPT_FOR_CONSTRAINTS_EXTENDED: &'static [PoseidonDefaultParametersEntry] =
+        &[PoseidonDefaultParametersEntry::new(16, 5, 8, 57, 0)];
 }
 
 #[cfg(test)]
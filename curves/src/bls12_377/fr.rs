@@ -202,6 +202,8 @@ impl PoseidonDefaultParameters for FrParameters {
         PoseidonDefaultParametersEntry::new(7, 17, 8, 31, 0),
         PoseidonDefaultParametersEntry::new(8, 17, 8, 31, 0),
     ];
+    const PARAMS_OPT_FOR_CONSTRAINTS_EXTENDED: &'static [PoseidonDefaultParametersEntry] =
+        &[PoseidonDefaultParametersEntry::new(16, 17, 8, 31, 0)];
 }
 
 #[cfg(test)]
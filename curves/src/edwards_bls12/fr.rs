@@ -116,6 +116,8 @@ impl PoseidonDefaultParameters for FrParameters {
         PoseidonDefaultParametersEntry::new(7, 3, 8, 84, 0),
         PoseidonDefaultParametersEntry::new(8, 3, 8, 84, 0),
     ];
+    const PARAMS_OPT_FOR_CONSTRAINTS_EXTENDED: &'static [PoseidonDefaultParametersEntry] =
+        &[PoseidonDefaultParametersEntry::new(16, 3, 8, 85, 0)];
 }
 
 #[cfg(test)]
@@ -177,6 +177,27 @@ fn random_expansion_tests<F: Field, R: Rng>(rng: &mut R) {
     }
 }
 
+fn random_mul_sub_tests<F: PrimeField>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        let a = F::rand(rng);
+        let b = F::rand(rng);
+        let c = F::rand(rng);
+
+        // Ensure the constant-time path matches the variable-time arithmetic.
+        assert_eq!(a.ct_mul_sub(&b, &c), a - (b * c));
+    }
+
+    // Ensure the edge cases of the final reduction and subtraction are correct.
+    let minus_one = -F::one();
+    for a in [F::zero(), F::one(), minus_one] {
+        for b in [F::zero(), F::one(), minus_one] {
+            for c in [F::zero(), F::one(), minus_one] {
+                assert_eq!(a.ct_mul_sub(&b, &c), a - (b * c));
+            }
+        }
+    }
+}
+
 fn random_string_tests<F: PrimeField>(rng: &mut TestRng) {
     {
         let a = "84395729384759238745923745892374598234705297301958723458712394587103249587213984572934750213947582345792304758273458972349582734958273495872304598234";
@@ -568,6 +589,7 @@ pub fn primefield_test<F: PrimeField>(rng: &mut TestRng) {
     assert_eq!(F::from_str("2").ok().unwrap(), two);
     assert_eq!(F::from_str(&two.to_string()).ok().unwrap(), two);
 
+    random_mul_sub_tests::<F>(rng);
     random_string_tests::<F>(rng);
     fft_field_test::<F>();
 }
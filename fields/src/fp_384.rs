@@ -64,6 +64,173 @@ impl<P: Fp384Parameters> Fp384<P> {
         }
     }
 
+    /// Reduces `self`, which lies in the range `0..2 * MODULUS`, into the range `0..MODULUS`, as in `reduce`,
+    /// without branching on the value of `self`.
+    #[inline]
+    fn ct_reduce(&mut self) {
+        // Compute `self - MODULUS`, whose final borrow is 1 if `self < MODULUS`.
+        let mut reduced = (self.0).0;
+        let mut borrow = 0;
+        for (limb, modulus) in reduced.iter_mut().zip(P::MODULUS.0) {
+            borrow = fa::sbb(limb, modulus, borrow);
+        }
+        // Select `self` if the subtraction borrowed, and `self - MODULUS` otherwise.
+        let mask = 0u64.wrapping_sub(borrow);
+        for (limb, reduced) in (self.0).0.iter_mut().zip(reduced) {
+            *limb = (*limb & mask) | (reduced & !mask);
+        }
+    }
+
+    /// Returns `self - other`, as in `sub_assign`, without branching on the values of the operands.
+    #[inline]
+    fn ct_sub(&self, other: &Self) -> Self {
+        // Compute `self - other`, whose final borrow is 1 if `self < other`.
+        let mut result = *self;
+        let mut borrow = 0;
+        for (limb, other) in (result.0).0.iter_mut().zip((other.0).0) {
+            borrow = fa::sbb(limb, other, borrow);
+        }
+        // Add the modulus if the subtraction borrowed, and zero otherwise.
+        let mask = 0u64.wrapping_sub(borrow);
+        let mut carry = 0;
+        for (limb, modulus) in (result.0).0.iter_mut().zip(P::MODULUS.0) {
+            carry = fa::adc(limb, modulus & mask, carry);
+        }
+        // Ensure the final carry of the correction cancels the borrow, as the result lies in `0..MODULUS`.
+        debug_assert_eq!(carry, borrow, "The correction of `ct_sub` did not cancel the borrow");
+        result
+    }
+
+    /// Sets `self` to the Montgomery product of `self` and `other`, which lies in the range `0..2 * MODULUS`.
+    #[inline(always)]
+    fn mul_without_reduce(&mut self, other: &Self) {
+        let mut r = [0u64; 6];
+        let mut carry1 = 0u64;
+        let mut carry2 = 0u64;
+
+        // Iteration 0.
+        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[0], &mut carry1);
+        let k = r[0].wrapping_mul(P::INV);
+        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
+        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[0], &mut carry1);
+        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
+
+        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[0], &mut carry1);
+        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
+
+        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[0], &mut carry1);
+        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
+
+        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[0], &mut carry1);
+        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
+
+        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[0], &mut carry1);
+        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
+        r[5] = carry1 + carry2;
+
+        // Iteration 1.
+        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[1], &mut carry1);
+        let k = r[0].wrapping_mul(P::INV);
+        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
+        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[1], &mut carry1);
+        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
+
+        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[1], &mut carry1);
+        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
+
+        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[1], &mut carry1);
+        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
+
+        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[1], &mut carry1);
+        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
+
+        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[1], &mut carry1);
+        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
+        r[5] = carry1 + carry2;
+
+        // Iteration 2.
+        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[2], &mut carry1);
+        let k = r[0].wrapping_mul(P::INV);
+        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
+        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[2], &mut carry1);
+        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
+
+        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[2], &mut carry1);
+        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
+
+        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[2], &mut carry1);
+        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
+
+        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[2], &mut carry1);
+        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
+
+        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[2], &mut carry1);
+        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
+        r[5] = carry1 + carry2;
+
+        // Iteration 3.
+        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[3], &mut carry1);
+        let k = r[0].wrapping_mul(P::INV);
+        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
+        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[3], &mut carry1);
+        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
+
+        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[3], &mut carry1);
+        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
+
+        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[3], &mut carry1);
+        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
+
+        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[3], &mut carry1);
+        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
+
+        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[3], &mut carry1);
+        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
+        r[5] = carry1 + carry2;
+
+        // Iteration 4.
+        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[4], &mut carry1);
+        let k = r[0].wrapping_mul(P::INV);
+        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
+        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[4], &mut carry1);
+        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
+
+        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[4], &mut carry1);
+        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
+
+        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[4], &mut carry1);
+        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
+
+        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[4], &mut carry1);
+        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
+
+        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[4], &mut carry1);
+        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
+        r[5] = carry1 + carry2;
+
+        // Iteration 5.
+        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[5], &mut carry1);
+        let k = r[0].wrapping_mul(P::INV);
+        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
+        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[5], &mut carry1);
+        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
+
+        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[5], &mut carry1);
+        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
+
+        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[5], &mut carry1);
+        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
+
+        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[5], &mut carry1);
+        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
+
+        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[5], &mut carry1);
+        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
+        r[5] = carry1 + carry2;
+
+        (self.0).0 = r;
+    }
+
     #[inline(always)]
     #[allow(clippy::too_many_arguments)]
     fn mont_reduce(
@@ -498,6 +665,16 @@ impl<P: Fp384Parameters> PrimeField for Fp384<P> {
         tmp
     }
 
+    #[inline]
+    fn ct_mul_sub(&self, a: &Self, b: &Self) -> Self {
+        // Compute `a * b`, with a branch-free final reduction.
+        let mut product = *a;
+        product.mul_without_reduce(b);
+        product.ct_reduce();
+        // Compute `self - (a * b)`.
+        self.ct_sub(&product)
+    }
+
     #[inline]
     fn decompose(
         &self,
@@ -769,131 +946,7 @@ impl<'a, P: Fp384Parameters> SubAssign<&'a Self> for Fp384<P> {
 impl<'a, P: Fp384Parameters> MulAssign<&'a Self> for Fp384<P> {
     #[inline]
     fn mul_assign(&mut self, other: &Self) {
-        let mut r = [0u64; 6];
-        let mut carry1 = 0u64;
-        let mut carry2 = 0u64;
-
-        // Iteration 0.
-        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[0], &mut carry1);
-        let k = r[0].wrapping_mul(P::INV);
-        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
-        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[0], &mut carry1);
-        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
-
-        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[0], &mut carry1);
-        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
-
-        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[0], &mut carry1);
-        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
-
-        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[0], &mut carry1);
-        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
-
-        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[0], &mut carry1);
-        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
-        r[5] = carry1 + carry2;
-
-        // Iteration 1.
-        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[1], &mut carry1);
-        let k = r[0].wrapping_mul(P::INV);
-        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
-        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[1], &mut carry1);
-        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
-
-        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[1], &mut carry1);
-        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
-
-        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[1], &mut carry1);
-        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
-
-        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[1], &mut carry1);
-        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
-
-        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[1], &mut carry1);
-        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
-        r[5] = carry1 + carry2;
-
-        // Iteration 2.
-        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[2], &mut carry1);
-        let k = r[0].wrapping_mul(P::INV);
-        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
-        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[2], &mut carry1);
-        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
-
-        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[2], &mut carry1);
-        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
-
-        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[2], &mut carry1);
-        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
-
-        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[2], &mut carry1);
-        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
-
-        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[2], &mut carry1);
-        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
-        r[5] = carry1 + carry2;
-
-        // Iteration 3.
-        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[3], &mut carry1);
-        let k = r[0].wrapping_mul(P::INV);
-        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
-        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[3], &mut carry1);
-        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
-
-        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[3], &mut carry1);
-        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
-
-        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[3], &mut carry1);
-        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
-
-        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[3], &mut carry1);
-        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
-
-        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[3], &mut carry1);
-        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
-        r[5] = carry1 + carry2;
-
-        // Iteration 4.
-        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[4], &mut carry1);
-        let k = r[0].wrapping_mul(P::INV);
-        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
-        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[4], &mut carry1);
-        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
-
-        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[4], &mut carry1);
-        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
-
-        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[4], &mut carry1);
-        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
-
-        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[4], &mut carry1);
-        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
-
-        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[4], &mut carry1);
-        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
-        r[5] = carry1 + carry2;
-
-        // Iteration 5.
-        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[5], &mut carry1);
-        let k = r[0].wrapping_mul(P::INV);
-        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
-        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[5], &mut carry1);
-        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
-
-        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[5], &mut carry1);
-        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
-
-        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[5], &mut carry1);
-        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
-
-        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[5], &mut carry1);
-        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
-
-        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[5], &mut carry1);
-        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
-        r[5] = carry1 + carry2;
-
-        (self.0).0 = r;
+        self.mul_without_reduce(other);
         self.reduce();
     }
 }
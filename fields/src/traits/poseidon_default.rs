@@ -40,31 +40,51 @@ pub trait PoseidonDefaultField {
     /// Obtain the default Poseidon parameters for this rate and for this prime field,
     /// with a specific optimization goal.
     fn default_poseidon_parameters<const RATE: usize>() -> Result<PoseidonParameters<Self, RATE, 1>>
+    where
+        Self: PrimeField,
+    {
+        Self::default_poseidon_parameters_with_capacity::<RATE, 1>()
+    }
+
+    /// Obtain the default Poseidon parameters for this rate and capacity and for this prime field,
+    /// with a specific optimization goal.
+    ///
+    /// The tabulated parameters are computed for a state of `rate + 1` elements, so the round numbers are
+    /// those tabulated for the rate `RATE + CAPACITY - 1`, i.e. for a state of `RATE + CAPACITY` elements,
+    /// and the round keys and MDS matrix are sampled for that state. For a capacity of 1, these are
+    /// `default_poseidon_parameters`. A capacity whose state width is not tabulated is rejected.
+    fn default_poseidon_parameters_with_capacity<const RATE: usize, const CAPACITY: usize>(
+    ) -> Result<PoseidonParameters<Self, RATE, CAPACITY>>
     where
         Self: PrimeField,
     {
         /// Internal function that computes the ark and mds from the Poseidon Grain LFSR.
         #[allow(clippy::type_complexity)]
-        fn find_poseidon_ark_and_mds<F: PrimeField, const RATE: usize>(
+        fn find_poseidon_ark_and_mds<F: PrimeField, const RATE: usize, const CAPACITY: usize>(
             full_rounds: u64,
             partial_rounds: u64,
             skip_matrices: u64,
         ) -> Result<(Vec<Vec<F>>, Vec<Vec<F>>)> {
             let lfsr_time = start_timer!(|| "LFSR Init");
-            let mut lfsr =
-                PoseidonGrainLFSR::new(false, F::size_in_bits() as u64, (RATE + 1) as u64, full_rounds, partial_rounds);
+            let mut lfsr = PoseidonGrainLFSR::new(
+                false,
+                F::size_in_bits() as u64,
+                (RATE + CAPACITY) as u64,
+                full_rounds,
+                partial_rounds,
+            );
             end_timer!(lfsr_time);
 
             let ark_time = start_timer!(|| "Constructing ARK");
             let mut ark = Vec::with_capacity((full_rounds + partial_rounds) as usize);
             for _ in 0..(full_rounds + partial_rounds) {
-                ark.push(lfsr.get_field_elements_rejection_sampling(RATE + 1)?);
+                ark.push(lfsr.get_field_elements_rejection_sampling(RATE + CAPACITY)?);
             }
             end_timer!(ark_time);
 
             let skip_time = start_timer!(|| "Skipping matrices");
             for _ in 0..skip_matrices {
-                let _ = lfsr.get_field_elements_mod_p::<F>(2 * (RATE + 1))?;
+                let _ = lfsr.get_field_elements_mod_p::<F>(2 * (RATE + CAPACITY))?;
             }
             end_timer!(skip_time);
 
@@ -73,26 +93,34 @@ pub trait PoseidonDefaultField {
             // - There is no i and j such that x[i] + y[j] = p.
             // - There resultant MDS passes all three tests.
 
-            let xs = lfsr.get_field_elements_mod_p::<F>(RATE + 1)?;
-            let ys = lfsr.get_field_elements_mod_p::<F>(RATE + 1)?;
+            let xs = lfsr.get_field_elements_mod_p::<F>(RATE + CAPACITY)?;
+            let ys = lfsr.get_field_elements_mod_p::<F>(RATE + CAPACITY)?;
 
             let mds_time = start_timer!(|| "Construct MDS");
-            let mut mds_flattened = vec![F::zero(); (RATE + 1) * (RATE + 1)];
-            for (x, mds_row_i) in xs.iter().take(RATE + 1).zip_eq(mds_flattened.chunks_mut(RATE + 1)) {
-                for (y, e) in ys.iter().take(RATE + 1).zip_eq(mds_row_i) {
+            let mut mds_flattened = vec![F::zero(); (RATE + CAPACITY) * (RATE + CAPACITY)];
+            for (x, mds_row_i) in xs.iter().take(RATE + CAPACITY).zip_eq(mds_flattened.chunks_mut(RATE + CAPACITY)) {
+                for (y, e) in ys.iter().take(RATE + CAPACITY).zip_eq(mds_row_i) {
                     *e = *x + y;
                 }
             }
             serial_batch_inversion_and_mul(&mut mds_flattened, &F::one());
-            let mds = mds_flattened.chunks(RATE + 1).map(|row| row.to_vec()).collect();
+            let mds = mds_flattened.chunks(RATE + CAPACITY).map(|row| row.to_vec()).collect();
             end_timer!(mds_time);
 
             Ok((ark, mds))
         }
 
-        match Self::Parameters::PARAMS_OPT_FOR_CONSTRAINTS.iter().find(|entry| entry.rate == RATE) {
+        // Ensure the capacity is nonzero.
+        if CAPACITY == 0 {
+            bail!("The Poseidon capacity must be nonzero")
+        }
+        // Retrieve the parameters tabulated for a state of `RATE + CAPACITY` elements.
+        let mut entries = Self::Parameters::PARAMS_OPT_FOR_CONSTRAINTS
+            .iter()
+            .chain(Self::Parameters::PARAMS_OPT_FOR_CONSTRAINTS_EXTENDED);
+        match entries.find(|entry| entry.rate + 1 == RATE + CAPACITY) {
             Some(entry) => {
-                let (ark, mds) = find_poseidon_ark_and_mds::<Self, RATE>(
+                let (ark, mds) = find_poseidon_ark_and_mds::<Self, RATE, CAPACITY>(
                     entry.full_rounds as u64,
                     entry.partial_rounds as u64,
                     entry.skip_matrices as u64,
@@ -105,7 +133,7 @@ pub trait PoseidonDefaultField {
                     mds,
                 })
             }
-            None => bail!("No Poseidon parameters were found for this rate"),
+            None => bail!("No Poseidon parameters were found for this rate and capacity"),
         }
     }
 }
@@ -119,6 +147,13 @@ pub trait PoseidonDefaultParameters {
     /// Here, `skip_matrices` denote how many matrices to skip before
     /// finding one that satisfy all the requirements.
     const PARAMS_OPT_FOR_CONSTRAINTS: [PoseidonDefaultParametersEntry; 7];
+
+    /// A list of additional parameters optimized for constraints, for the rates beyond
+    /// `PARAMS_OPT_FOR_CONSTRAINTS` (e.g. rate = 16), in the same format.
+    ///
+    /// This is separate from `PARAMS_OPT_FOR_CONSTRAINTS`, so that the length of that array is unchanged,
+    /// and defaults to an empty list.
+    const PARAMS_OPT_FOR_CONSTRAINTS_EXTENDED: &'static [PoseidonDefaultParametersEntry] = &[];
 }
 
 /// An entry in the default Poseidon parameters
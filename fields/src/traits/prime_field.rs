@@ -45,6 +45,12 @@ pub trait PrimeField:
         half_r: &[u64; 8],
     ) -> (Self, Self, bool, bool);
 
+    /// Returns `self - (a * b)`, in constant time.
+    ///
+    /// Implementations must not branch on (or index by) the values of `self`, `a`, or `b`,
+    /// so that it is safe to use with secret operands.
+    fn ct_mul_sub(&self, a: &Self, b: &Self) -> Self;
+
     /// Returns the field size in bits.
     fn size_in_bits() -> usize {
         Self::Parameters::MODULUS_BITS as usize
@@ -101,6 +101,9 @@ const HASH_BHP_PER_BYTE_COST: u64 = 300;
 const HASH_PSD_BASE_COST: u64 = 40_000;
 const HASH_PSD_PER_BYTE_COST: u64 = 75;
 
+const LOOKUP_BASE_COST: u64 = 500;
+const LOOKUP_PER_ENTRY_COST: u64 = 100;
+
 const MAPPING_BASE_COST: u64 = 10_000;
 const MAPPING_PER_BYTE_COST: u64 = 10;
 
@@ -137,6 +140,28 @@ fn plaintext_size_in_bytes<N: Network>(stack: &Stack<N>, plaintext_type: &Plaint
     }
 }
 
+/// A helper function to determine the plaintext type of an operand in the finalize scope.
+fn plaintext_type_of_operand<N: Network>(
+    stack: &Stack<N>,
+    finalize: &Finalize<N>,
+    operand: &Operand<N>,
+) -> Result<PlaintextType<N>> {
+    match stack.get_finalize_types(finalize.name())?.get_type_from_operand(stack, operand)? {
+        FinalizeType::Plaintext(plaintext_type) => Ok(plaintext_type),
+        FinalizeType::Future(future) => bail!("Future '{future}' is not a valid operand in the finalize scope"),
+    }
+}
+
+/// A helper function to determine the number of elements in an array operand in the finalize scope.
+fn array_length_of_operand<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, operand: &Operand<N>) -> Result<u64> {
+    match plaintext_type_of_operand(stack, finalize, operand)? {
+        PlaintextType::Array(array_type) => Ok(**array_type.length() as u64),
+        plaintext_type => {
+            bail!("Expected an array operand in '{}/{}', found '{plaintext_type}'", stack.program_id(), finalize.name())
+        }
+    }
+}
+
 /// A helper function to compute the following: base_cost + (byte_multiplier * size_of_operands).
 fn cost_in_size<'a, N: Network>(
     stack: &Stack<N>,
@@ -171,13 +196,20 @@ fn cost_in_size<'a, N: Network>(
 pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, command: &Command<N>) -> Result<u64> {
     match command {
         Command::Instruction(Instruction::Abs(_)) => Ok(500),
+        Command::Instruction(Instruction::AbsDiff(_)) => Ok(500),
         Command::Instruction(Instruction::AbsWrapped(_)) => Ok(500),
         Command::Instruction(Instruction::Add(_)) => Ok(500),
         Command::Instruction(Instruction::AddWrapped(_)) => Ok(500),
+        Command::Instruction(Instruction::AddressFingerprint(fingerprint)) => {
+            cost_in_size(stack, finalize, fingerprint.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
         Command::Instruction(Instruction::And(_)) => Ok(500),
         Command::Instruction(Instruction::AssertEq(_)) => Ok(500),
         Command::Instruction(Instruction::AssertNeq(_)) => Ok(500),
+        Command::Instruction(Instruction::AssertBool(_)) => Ok(500),
+        Command::Instruction(Instruction::AssertNonzero(_)) => Ok(500),
         Command::Instruction(Instruction::Async(_)) => bail!("'async' is not supported in finalize"),
+        Command::Instruction(Instruction::BitsToInt(_)) => Ok(500),
         Command::Instruction(Instruction::Call(_)) => bail!("'call' is not supported in finalize"),
         Command::Instruction(Instruction::Cast(cast)) => match cast.cast_type() {
             CastType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
@@ -199,9 +231,17 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
             | CastType::Record(_)
             | CastType::ExternalRecord(_) => Ok(500),
         },
+        Command::Instruction(Instruction::CommitAdd(_)) => Ok(500),
         Command::Instruction(Instruction::CommitBHP256(commit)) => {
             cost_in_size(stack, finalize, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
         }
+        Command::Instruction(Instruction::CommitBHP256Field(commit)) => {
+            cost_in_size(stack, finalize, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+        }
+        Command::Instruction(Instruction::CommitBHP256Hashed(commit)) => {
+            cost_in_size(stack, finalize, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+                .map(|cost| cost.saturating_add(HASH_PSD_BASE_COST))
+        }
         Command::Instruction(Instruction::CommitBHP512(commit)) => {
             cost_in_size(stack, finalize, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
         }
@@ -211,12 +251,19 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         Command::Instruction(Instruction::CommitBHP1024(commit)) => {
             cost_in_size(stack, finalize, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
         }
+        Command::Instruction(Instruction::CommitChainBHP256(commit)) => {
+            cost_in_size(stack, finalize, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+        }
+        Command::Instruction(Instruction::CommitCiphertextBHP256(_)) => {
+            bail!("`commit.ciphertext.bhp256` is not supported in finalize")
+        }
         Command::Instruction(Instruction::CommitPED64(commit)) => {
             cost_in_size(stack, finalize, commit.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
         }
         Command::Instruction(Instruction::CommitPED128(commit)) => {
             cost_in_size(stack, finalize, commit.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
         }
+        Command::Instruction(Instruction::CommitZeroPED64(_)) => Ok(HASH_BASE_COST),
         Command::Instruction(Instruction::Div(div)) => {
             // Ensure `div` has exactly two operands.
             ensure!(div.operands().len() == 2, "'div' must contain exactly 2 operands");
@@ -233,6 +280,8 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         }
         Command::Instruction(Instruction::DivWrapped(_)) => Ok(500),
         Command::Instruction(Instruction::Double(_)) => Ok(500),
+        Command::Instruction(Instruction::Generator(_)) => Ok(500),
+        Command::Instruction(Instruction::GetMember(_)) => Ok(500),
         Command::Instruction(Instruction::GreaterThan(_)) => Ok(500),
         Command::Instruction(Instruction::GreaterThanOrEqual(_)) => Ok(500),
         Command::Instruction(Instruction::HashBHP256(hash)) => {
@@ -289,11 +338,47 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         Command::Instruction(Instruction::HashManyPSD8(_)) => {
             bail!("`hash_many.psd8` is not supported in finalize")
         }
+        Command::Instruction(Instruction::HashMulti(hash)) => {
+            cost_in_size(stack, finalize, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
+        Command::Instruction(Instruction::HashToScalarPSD2(hash)) => {
+            cost_in_size(stack, finalize, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
+        Command::Instruction(Instruction::HashToScalarPSD4(hash)) => {
+            cost_in_size(stack, finalize, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
+        Command::Instruction(Instruction::HashToGroupPSD8(hash)) => {
+            cost_in_size(stack, finalize, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
+        Command::Instruction(Instruction::HashBytesSha256(hash)) => {
+            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
+        }
+        Command::Instruction(Instruction::HashBytesKeccak256(hash)) => {
+            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
+        }
+        Command::Instruction(Instruction::HashConcatPSD8(hash)) => {
+            cost_in_size(stack, finalize, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
         Command::Instruction(Instruction::Inv(_)) => Ok(2_500),
         Command::Instruction(Instruction::IsEq(_)) => Ok(500),
         Command::Instruction(Instruction::IsNeq(_)) => Ok(500),
         Command::Instruction(Instruction::LessThan(_)) => Ok(500),
         Command::Instruction(Instruction::LessThanOrEqual(_)) => Ok(500),
+        Command::Instruction(Instruction::Lookup(lookup)) => {
+            // The circuit selects the output with a multiplexer over every entry of the table, so the cost scales
+            // with the length of the table, which is fixed by its type whether or not its values are constant.
+            let num_entries = array_length_of_operand(stack, finalize, &lookup.operands()[1])?;
+            Ok(num_entries.saturating_mul(LOOKUP_PER_ENTRY_COST).saturating_add(LOOKUP_BASE_COST))
+        }
+        Command::Instruction(Instruction::MerkleNode(merkle)) => {
+            cost_in_size(stack, finalize, merkle.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
+        Command::Instruction(Instruction::MerkleRootPSD2(merkle)) => {
+            cost_in_size(stack, finalize, merkle.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
+        Command::Instruction(Instruction::MerkleVerify(merkle)) => {
+            cost_in_size(stack, finalize, merkle.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
         Command::Instruction(Instruction::Modulo(_)) => Ok(500),
         Command::Instruction(Instruction::Mul(mul)) => {
             // Ensure `mul` has exactly two operands.
@@ -316,6 +401,7 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         Command::Instruction(Instruction::Nor(_)) => Ok(500),
         Command::Instruction(Instruction::Not(_)) => Ok(500),
         Command::Instruction(Instruction::Or(_)) => Ok(500),
+        Command::Instruction(Instruction::Popcount(_)) => Ok(500),
         Command::Instruction(Instruction::Pow(pow)) => {
             // Ensure `pow` has at least one operand.
             ensure!(!pow.operands().is_empty(), "'pow' must contain at least 1 operand");
@@ -330,9 +416,19 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
                 FinalizeType::Future(_) => bail!("'pow' does not support futures"),
             }
         }
+        Command::Instruction(Instruction::PowConst(_)) => Ok(1_500),
         Command::Instruction(Instruction::PowWrapped(_)) => Ok(500),
+        Command::Instruction(Instruction::Randomizer(randomizer)) => {
+            cost_in_size(stack, finalize, randomizer.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
         Command::Instruction(Instruction::Rem(_)) => Ok(500),
         Command::Instruction(Instruction::RemWrapped(_)) => Ok(500),
+        Command::Instruction(Instruction::Reveal(reveal)) => {
+            cost_in_size(stack, finalize, reveal.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+        }
+        Command::Instruction(Instruction::SerialNumber(serial_number)) => {
+            cost_in_size(stack, finalize, serial_number.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
         Command::Instruction(Instruction::SignVerify(sign)) => {
             cost_in_size(stack, finalize, sign.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
         }
@@ -340,12 +436,34 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         Command::Instruction(Instruction::ShlWrapped(_)) => Ok(500),
         Command::Instruction(Instruction::Shr(_)) => Ok(500),
         Command::Instruction(Instruction::ShrWrapped(_)) => Ok(500),
+        Command::Instruction(Instruction::Shuffle(shuffle)) => {
+            // The shuffle invokes `hash.psd2` on a `[seed, index]` pair once per element after the first.
+            let num_hashes = array_length_of_operand(stack, finalize, &shuffle.operands()[0])?.saturating_sub(1);
+            let hash_cost =
+                cost_in_size(stack, finalize, [&shuffle.operands()[1]; 2], HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)?;
+            Ok(num_hashes.saturating_mul(hash_cost).saturating_add(500))
+        }
         Command::Instruction(Instruction::Square(_)) => Ok(500),
         Command::Instruction(Instruction::SquareRoot(_)) => Ok(2_500),
+        Command::Instruction(Instruction::StateHash(hash)) => {
+            cost_in_size(stack, finalize, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
         Command::Instruction(Instruction::Sub(_)) => Ok(500),
         Command::Instruction(Instruction::SubWrapped(_)) => Ok(500),
-        Command::Instruction(Instruction::Ternary(_)) => Ok(500),
+        Command::Instruction(Instruction::Ternary(ternary)) => {
+            // The selected operands share a type, so the cost scales with the size of the first one.
+            match plaintext_type_of_operand(stack, finalize, &ternary.operands()[1])? {
+                PlaintextType::Literal(_) => Ok(500),
+                plaintext_type => Ok(plaintext_size_in_bytes(stack, &plaintext_type)?
+                    .saturating_mul(CAST_PER_BYTE_COST)
+                    .saturating_add(CAST_BASE_COST)),
+            }
+        }
+        Command::Instruction(Instruction::VerifyCommitments(verify)) => {
+            cost_in_size(stack, finalize, verify.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+        }
         Command::Instruction(Instruction::Xor(_)) => Ok(500),
+        Command::Instruction(Instruction::XorField(_)) => Ok(500),
         Command::Await(_) => Ok(500),
         Command::Contains(command) => {
             cost_in_size(stack, finalize, [command.key()], MAPPING_PER_BYTE_COST, MAPPING_BASE_COST)
@@ -482,4 +600,60 @@ function over_five_thousand:
         assert_eq!(storage_cost_under_5000, execution_storage_cost::<MainnetV0>(execution_size_under_5000));
         assert_eq!(storage_cost_over_5000, execution_storage_cost::<MainnetV0>(execution_size_over_5000));
     }
+
+    /// Returns the finalize cost of a program whose finalize scope takes the given inputs and runs the given command.
+    fn finalize_cost(input_types: &[&str], command: &str) -> u64 {
+        // Construct the function and finalize inputs.
+        let inputs: String = input_types
+            .iter()
+            .enumerate()
+            .map(|(i, input_type)| format!("    input r{i} as {input_type}.public;\n"))
+            .collect();
+        let registers = (0..input_types.len()).map(|i| format!("r{i}")).collect::<Vec<_>>().join(" ");
+        let future = input_types.len();
+        // Construct the program.
+        let program = Program::<MainnetV0>::from_str(&format!(
+            "program cost_test.aleo;
+
+function run:
+{inputs}    async run {registers} into r{future};
+    output r{future} as cost_test.aleo/run.future;
+
+finalize run:
+{inputs}    {command};
+"
+        ))
+        .unwrap();
+        // Compute the finalize cost.
+        let stack = Stack::new(&Process::load().unwrap(), &program).unwrap();
+        cost_in_microcredits(&stack, &Identifier::from_str("run").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_ternary_cost_scales_with_operand_size() {
+        // The cost of selecting between literals is unchanged.
+        assert_eq!(finalize_cost(&["boolean", "u64"], "ternary r0 r1 r1 into r2"), 500);
+        // The cost of selecting between aggregates scales with their size, as for `cast`.
+        let cost = finalize_cost(&["boolean", "[u64; 4u32]"], "ternary r0 r1 r1 into r2");
+        assert_eq!(cost, CAST_BASE_COST + 4 * 8 * CAST_PER_BYTE_COST);
+        let cost = finalize_cost(&["boolean", "[u64; 32u32]"], "ternary r0 r1 r1 into r2");
+        assert_eq!(cost, CAST_BASE_COST + 32 * 8 * CAST_PER_BYTE_COST);
+    }
+
+    #[test]
+    fn test_lookup_cost_scales_with_table_length() {
+        let cost = finalize_cost(&["u8", "[u64; 4u32]"], "lookup r0 r1 into r2");
+        assert_eq!(cost, LOOKUP_BASE_COST + 4 * LOOKUP_PER_ENTRY_COST);
+        let cost = finalize_cost(&["u8", "[u64; 32u32]"], "lookup r0 r1 into r2");
+        assert_eq!(cost, LOOKUP_BASE_COST + 32 * LOOKUP_PER_ENTRY_COST);
+    }
+
+    #[test]
+    fn test_shuffle_cost_scales_with_number_of_hashes() {
+        // The shuffle invokes `hash.psd2` on two field elements once per element after the first.
+        let hash_cost = HASH_PSD_BASE_COST + 2 * 32 * HASH_PSD_PER_BYTE_COST;
+        assert_eq!(finalize_cost(&["[u64; 1u32]", "field"], "shuffle r0 r1 into r2"), 500);
+        assert_eq!(finalize_cost(&["[u64; 4u32]", "field"], "shuffle r0 r1 into r2"), 500 + 3 * hash_cost);
+        assert_eq!(finalize_cost(&["[u64; 32u32]", "field"], "shuffle r0 r1 into r2"), 500 + 31 * hash_cost);
+    }
 }
@@ -29,6 +29,10 @@ impl<N: Network> Process<N> {
         let stack = Stack::new(self, program)?;
         lap!(timer, "Compute the stack");
 
+        // Ensure the program satisfies the checks for new deployments.
+        stack.check_new_deployment()?;
+        lap!(timer, "Check the new deployment");
+
         // Return the deployment.
         let deployment = stack.deploy::<A, R>(rng);
         lap!(timer, "Construct the deployment");
@@ -22,6 +22,33 @@ impl<N: Network> Process<N> {
         &self,
         authorization: Authorization<N>,
         rng: &mut R,
+    ) -> Result<(Response<N>, Trace<N>)> {
+        self.execute_with_trace::<A, R>(authorization, Trace::new(), rng)
+    }
+
+    /// Executes the given authorization, with a trace in debug mode.
+    ///
+    /// The trace records each `add` instruction on field operands whose sum wraps the field modulus,
+    /// which is returned by `Trace::field_add_wraps`. This helps program authors catch cases where they
+    /// expected integer semantics, but got field semantics. It also tracks the liveness of the console registers
+    /// of each executed function, which is returned by `Stack::peak_live_registers`.
+    /// The execution and its proofs are unchanged.
+    #[inline]
+    pub fn execute_debug<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        authorization: Authorization<N>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Trace<N>)> {
+        self.execute_with_trace::<A, R>(authorization, Trace::new_debug(), rng)
+    }
+
+    /// Executes the given authorization, recording the execution in the given trace.
+    #[inline]
+    fn execute_with_trace<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        authorization: Authorization<N>,
+        trace: Trace<N>,
+        rng: &mut R,
     ) -> Result<(Response<N>, Trace<N>)> {
         let timer = timer!("Process::execute");
 
@@ -38,7 +65,7 @@ impl<N: Network> Process<N> {
         // This is the root request and we do not have a root_tvk to pass on.
         let root_tvk = None;
         // Initialize the trace.
-        let trace = Arc::new(RwLock::new(Trace::new()));
+        let trace = Arc::new(RwLock::new(trace));
         // Initialize the call stack.
         let call_stack = CallStack::execute(authorization, trace.clone())?;
         lap!(timer, "Initialize call stack");
@@ -210,6 +210,12 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // Initialize the registers.
         let mut registers = Registers::new(call_stack, self.get_register_types(function.name())?.clone());
+        // If the trace is in debug mode, track the liveness of the console registers.
+        if let CallStack::Execute(_, trace) = registers.call_stack() {
+            if trace.read().is_debug() {
+                registers.enable_liveness_tracking();
+            }
+        }
 
         // Set the root tvk, from a parent request or the current request.
         // inject the `root_tvk` as `Mode::Private`.
@@ -283,9 +289,16 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         let mut contains_function_call = false;
 
         // Execute the instructions.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
             // If the circuit is in execute mode, then evaluate the instructions.
-            if let CallStack::Execute(..) = registers.call_stack() {
+            if let CallStack::Execute(_, trace) = registers.call_stack() {
+                // If the trace is in debug mode, record whether a field `add` instruction wraps the field modulus.
+                if let Instruction::Add(add) = instruction {
+                    if trace.read().is_debug() && self.is_field_add_wrap(&registers, add.operands())? {
+                        trace.write().insert_field_add_wrap(Locator::new(*self.program_id(), *function.name()), index);
+                    }
+                }
+
                 // Evaluate the instruction.
                 let result = match instruction {
                     // If the instruction is a `call` instruction, we need to handle it separately.
@@ -321,6 +334,13 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         }
         lap!(timer, "Execute the instructions");
 
+        // If liveness tracking is enabled, record the peak number of live console registers for the function.
+        if let Some(peak) = registers.peak_live_registers() {
+            let mut peak_live_registers = self.peak_live_registers.write();
+            let entry = peak_live_registers.entry(*function.name()).or_default();
+            *entry = (*entry).max(peak);
+        }
+
         // Load the outputs.
         let output_operands = &function.outputs().iter().map(|output| output.operand()).collect::<Vec<_>>();
         let outputs = output_operands
@@ -514,6 +534,17 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 }
 
 impl<N: Network> Stack<N> {
+    /// Returns `true` if the operands of an `add` instruction are field elements whose sum wraps the field modulus.
+    fn is_field_add_wrap(&self, registers: &impl RegistersLoad<N>, operands: &[Operand<N>]) -> Result<bool> {
+        // Ensure the number of operands is correct.
+        ensure!(operands.len() == 2, "Instruction 'add' expects 2 operands, found {} operands", operands.len());
+        match (registers.load_literal(self, &operands[0])?, registers.load_literal(self, &operands[1])?) {
+            // The modular reduction triggered if and only if the sum is less than an operand.
+            (Literal::Field(first), Literal::Field(second)) => Ok(*(first + second).is_less_than(&first)),
+            _ => Ok(false),
+        }
+    }
+
     /// Prints the current state of the circuit.
     #[cfg(debug_assertions)]
     pub(crate) fn log_circuit<A: circuit::Aleo<Network = N>, S: Into<String>>(scope: S) {
@@ -629,6 +629,14 @@ impl<N: Network> FinalizeTypes<N> {
                     matches!(instruction, Instruction::AssertNeq(..)),
                     "Instruction '{instruction}' is not for opcode '{opcode}'."
                 ),
+                "assert" => ensure!(
+                    matches!(instruction, Instruction::AssertBool(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "assert.nonzero" => ensure!(
+                    matches!(instruction, Instruction::AssertNonzero(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
             Opcode::Async => {
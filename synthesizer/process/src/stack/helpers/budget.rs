@@ -0,0 +1,54 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Synthesizes the circuit for the given function name on sampled inputs, and returns its constraint budget.
+    ///
+    /// This synthesizes the full circuit, in the same mode used to check deployments, so it is as expensive as
+    /// checking the deployment of the function. However, it does not compute a circuit key or a proof.
+    /// As the circuit for a function is fixed, the returned counts match those of any execution of the function,
+    /// and may be compared against `N::MAX_DEPLOYMENT_CONSTRAINTS` before deploying the program.
+    ///
+    /// Note: Calls to external functions are not synthesized, as they are proven in their own transitions.
+    #[inline]
+    pub fn constraint_budget<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<CallMetrics<N>> {
+        // Retrieve the program ID.
+        let program_id = self.program_id();
+
+        // Sample a request on burner inputs, with a burner private key.
+        let (burner_private_key, request) = self.sample_burner_request(function_name, rng)?;
+        // Initialize the assignments.
+        let assignments = Assignments::<N>::default();
+        // Initialize the call stack, without a constraint limit or variable limit.
+        let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone(), None, None);
+        // Synthesize the circuit.
+        // Note: The caller and the `root_tvk` are `None` when synthesizing an individual circuit.
+        if let Err(error) = self.execute_function::<A, R>(call_stack, None, None, rng) {
+            bail!("Failed to synthesize the circuit for '{program_id}/{function_name}': {error}")
+        }
+
+        // Retrieve the metrics for the function.
+        match assignments.read().last() {
+            Some((_, metrics)) => Ok(*metrics),
+            None => bail!("The assignment for function '{function_name}' is missing in '{program_id}'"),
+        }
+    }
+}
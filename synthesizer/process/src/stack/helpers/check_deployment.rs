@@ -0,0 +1,76 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use synthesizer_program::Command;
+
+impl<N: Network> Stack<N> {
+    /// Ensures the program satisfies the checks that only apply to new deployments.
+    ///
+    /// These checks postdate programs that are already deployed, so they are not part of `Stack::new`,
+    /// which must still load those programs. They are run by `Process::deploy` and `Process::verify_deployment`.
+    pub fn check_new_deployment(&self) -> Result<()> {
+        // Check the closures.
+        for closure in self.program.closures().values() {
+            let register_types = self.get_register_types(closure.name())?;
+            for instruction in closure.instructions() {
+                let get_type = |operand: &Operand<N>| register_types.get_type_from_operand(self, operand);
+                self.check_deployment_instruction(instruction, get_type)?;
+            }
+        }
+        // Check the functions.
+        for function in self.program.functions().values() {
+            let register_types = self.get_register_types(function.name())?;
+            for instruction in function.instructions() {
+                let get_type = |operand: &Operand<N>| register_types.get_type_from_operand(self, operand);
+                self.check_deployment_instruction(instruction, get_type)?;
+            }
+            // Check the finalize logic, if it exists.
+            if let Some(finalize) = function.finalize_logic() {
+                let finalize_types = self.get_finalize_types(function.name())?;
+                for command in finalize.commands() {
+                    if let Command::Instruction(instruction) = command {
+                        let get_type = |operand: &Operand<N>| {
+                            Ok(RegisterType::from(finalize_types.get_type_from_operand(self, operand)?))
+                        };
+                        self.check_deployment_instruction(instruction, get_type)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures the given instruction satisfies the checks that only apply to new deployments.
+    fn check_deployment_instruction(
+        &self,
+        instruction: &Instruction<N>,
+        get_type: impl Fn(&Operand<N>) -> Result<RegisterType<N>>,
+    ) -> Result<()> {
+        // Retrieve the register types of the operands.
+        let input_types = || instruction.operands().iter().map(&get_type).collect::<Result<Vec<_>>>();
+
+        match instruction {
+            Instruction::CommitBHP256(commit) => commit.check_deployment_types(self, &input_types()?),
+            Instruction::CommitBHP256Hashed(commit) => commit.check_deployment_types(self, &input_types()?),
+            Instruction::CommitBHP512(commit) => commit.check_deployment_types(self, &input_types()?),
+            Instruction::CommitBHP768(commit) => commit.check_deployment_types(self, &input_types()?),
+            Instruction::CommitBHP1024(commit) => commit.check_deployment_types(self, &input_types()?),
+            Instruction::CommitPED64(commit) => commit.check_deployment_types(self, &input_types()?),
+            Instruction::CommitPED128(commit) => commit.check_deployment_types(self, &input_types()?),
+            _ => Ok(()),
+        }
+    }
+}
@@ -30,6 +30,7 @@ impl<N: Network> Stack<N> {
             verifying_keys: Default::default(),
             number_of_calls: Default::default(),
             finalize_costs: Default::default(),
+            peak_live_registers: Default::default(),
             program_depth: 0,
         };
 
@@ -15,6 +15,8 @@
 
 use super::*;
 
+mod budget;
+mod check_deployment;
 mod initialize;
 mod matches;
 mod sample;
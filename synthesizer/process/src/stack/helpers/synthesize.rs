@@ -28,8 +28,51 @@ impl<N: Network> Stack<N> {
             return Ok(());
         }
 
-        // Retrieve the program ID.
-        let program_id = self.program_id();
+        // Sample a request on burner inputs, with a burner private key.
+        let (burner_private_key, request) = self.sample_burner_request(function_name, rng)?;
+        // Initialize the authorization.
+        let authorization = Authorization::new(request.clone());
+        // Initialize the call stack.
+        let call_stack = CallStack::Synthesize(vec![request], burner_private_key, authorization);
+        // Synthesize the circuit.
+        // Note: The caller and the `root_tvk` are `None` when synthesizing an individual circuit.
+        let _response = self.execute_function::<A, R>(call_stack, None, None, rng)?;
+
+        // Ensure the proving key exists.
+        ensure!(self.contains_proving_key(function_name), "Function '{function_name}' is missing a proving key.");
+        // Ensure the verifying key exists.
+        ensure!(self.contains_verifying_key(function_name), "Function '{function_name}' is missing a verifying key.");
+        Ok(())
+    }
+
+    /// Synthesizes and stores the `(proving_key, verifying_key)` for the given function name and assignment.
+    #[inline]
+    pub fn synthesize_from_assignment(
+        &self,
+        function_name: &Identifier<N>,
+        assignment: &circuit::Assignment<N::Field>,
+    ) -> Result<()> {
+        // If the proving and verifying key already exist, skip the synthesis for this function.
+        if self.contains_proving_key(function_name) && self.contains_verifying_key(function_name) {
+            return Ok(());
+        }
+
+        // Synthesize the proving and verifying key.
+        let (proving_key, verifying_key) = self.universal_srs.to_circuit_key(&function_name.to_string(), assignment)?;
+        // Insert the proving key.
+        self.insert_proving_key(function_name, proving_key)?;
+        // Insert the verifying key.
+        self.insert_verifying_key(function_name, verifying_key)
+    }
+
+    /// Returns a burner private key, and a request for the given function name signed by it on sampled inputs.
+    ///
+    /// The request is a root request, and is used to synthesize the circuit for an individual function.
+    pub(crate) fn sample_burner_request<R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<(PrivateKey<N>, Request<N>)> {
         // Retrieve the function input types.
         let input_types = self.get_function(function_name)?.input_types();
 
@@ -53,16 +96,13 @@ impl<N: Network> Stack<N> {
         // Sample 'is_root'.
         let is_root = true;
 
-        // The `root_tvk` is `None` when deploying an individual circuit.
+        // The `root_tvk` is `None` when synthesizing an individual circuit.
         let root_tvk = None;
 
-        // The caller is `None` when deploying an individual circuit.
-        let caller = None;
-
         // Compute the request, with a burner private key.
         let request = Request::sign(
             &burner_private_key,
-            *program_id,
+            *self.program_id(),
             *function_name,
             inputs.into_iter(),
             &input_types,
@@ -70,37 +110,6 @@ impl<N: Network> Stack<N> {
             is_root,
             rng,
         )?;
-        // Initialize the authorization.
-        let authorization = Authorization::new(request.clone());
-        // Initialize the call stack.
-        let call_stack = CallStack::Synthesize(vec![request], burner_private_key, authorization);
-        // Synthesize the circuit.
-        let _response = self.execute_function::<A, R>(call_stack, caller, root_tvk, rng)?;
-
-        // Ensure the proving key exists.
-        ensure!(self.contains_proving_key(function_name), "Function '{function_name}' is missing a proving key.");
-        // Ensure the verifying key exists.
-        ensure!(self.contains_verifying_key(function_name), "Function '{function_name}' is missing a verifying key.");
-        Ok(())
-    }
-
-    /// Synthesizes and stores the `(proving_key, verifying_key)` for the given function name and assignment.
-    #[inline]
-    pub fn synthesize_from_assignment(
-        &self,
-        function_name: &Identifier<N>,
-        assignment: &circuit::Assignment<N::Field>,
-    ) -> Result<()> {
-        // If the proving and verifying key already exist, skip the synthesis for this function.
-        if self.contains_proving_key(function_name) && self.contains_verifying_key(function_name) {
-            return Ok(());
-        }
-
-        // Synthesize the proving and verifying key.
-        let (proving_key, verifying_key) = self.universal_srs.to_circuit_key(&function_name.to_string(), assignment)?;
-        // Insert the proving key.
-        self.insert_proving_key(function_name, proving_key)?;
-        // Insert the verifying key.
-        self.insert_verifying_key(function_name, verifying_key)
+        Ok((burner_private_key, request))
     }
 }
@@ -191,6 +191,8 @@ pub struct Stack<N: Network> {
     number_of_calls: IndexMap<Identifier<N>, usize>,
     /// The mapping of function names to finalize cost.
     finalize_costs: IndexMap<Identifier<N>, u64>,
+    /// The mapping of function names to the peak number of live console registers, observed in debug executions.
+    peak_live_registers: Arc<RwLock<IndexMap<Identifier<N>, usize>>>,
     /// The program depth.
     program_depth: usize,
 }
@@ -428,6 +430,14 @@ impl<N: Network> Stack<N> {
         Ok(())
     }
 
+    /// Returns the peak number of simultaneously-live console registers observed while executing
+    /// the given function in debug mode (see `Process::execute_debug`), across all such executions,
+    /// or `None` if the function has not been executed in debug mode.
+    #[inline]
+    pub fn peak_live_registers(&self, function_name: &Identifier<N>) -> Option<usize> {
+        self.peak_live_registers.read().get(function_name).copied()
+    }
+
     /// Removes the proving key for the given function name.
     #[inline]
     pub fn remove_proving_key(&self, function_name: &Identifier<N>) {
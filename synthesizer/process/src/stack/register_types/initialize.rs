@@ -416,6 +416,14 @@ impl<N: Network> RegisterTypes<N> {
                     matches!(instruction, Instruction::AssertNeq(..)),
                     "Instruction '{instruction}' is not for opcode '{opcode}'."
                 ),
+                "assert" => ensure!(
+                    matches!(instruction, Instruction::AssertBool(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "assert.nonzero" => ensure!(
+                    matches!(instruction, Instruction::AssertNonzero(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
             Opcode::Async => {
@@ -577,7 +585,14 @@ impl<N: Network> RegisterTypes<N> {
                 bail!("Forbidden operation: Instruction '{instruction}' cannot invoke command '{opcode}'.");
             }
             Opcode::Commit(opcode) => Self::check_commit_opcode(opcode, instruction)?,
-            Opcode::Hash(opcode) => Self::check_hash_opcode(opcode, instruction)?,
+            Opcode::Hash(opcode) => {
+                // Ensure the instruction is not a native byte hash, as it is not supported in circuit.
+                ensure!(
+                    !matches!(instruction, Instruction::HashBytesSha256(..) | Instruction::HashBytesKeccak256(..)),
+                    "Instruction '{instruction}' is only supported in 'finalize'."
+                );
+                Self::check_hash_opcode(opcode, instruction)?
+            }
             Opcode::Is(opcode) => match opcode {
                 "is.eq" => ensure!(
                     matches!(instruction, Instruction::IsEq(..)),
@@ -690,10 +705,22 @@ impl<N: Network> RegisterTypes<N> {
     pub(crate) fn check_commit_opcode(opcode: &str, instruction: &Instruction<N>) -> Result<()> {
         // Ensure the instruction is the correct one.
         match opcode {
+            "commit.add" => ensure!(
+                matches!(instruction, Instruction::CommitAdd(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
             "commit.bhp256" => ensure!(
                 matches!(instruction, Instruction::CommitBHP256(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
             ),
+            "commit.bhp256.field" => ensure!(
+                matches!(instruction, Instruction::CommitBHP256Field(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "commit.bhp256.hashed" => ensure!(
+                matches!(instruction, Instruction::CommitBHP256Hashed(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
             "commit.bhp512" => ensure!(
                 matches!(instruction, Instruction::CommitBHP512(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
@@ -706,6 +733,14 @@ impl<N: Network> RegisterTypes<N> {
                 matches!(instruction, Instruction::CommitBHP1024(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
             ),
+            "commit.chain.bhp256" => ensure!(
+                matches!(instruction, Instruction::CommitChainBHP256(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "commit.ciphertext.bhp256" => ensure!(
+                matches!(instruction, Instruction::CommitCiphertextBHP256(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
             "commit.ped64" => ensure!(
                 matches!(instruction, Instruction::CommitPED64(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
@@ -714,6 +749,14 @@ impl<N: Network> RegisterTypes<N> {
                 matches!(instruction, Instruction::CommitPED128(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
             ),
+            "commit.zero.ped64" => ensure!(
+                matches!(instruction, Instruction::CommitZeroPED64(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "reveal" => ensure!(
+                matches!(instruction, Instruction::Reveal(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
             _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
         }
         Ok(())
@@ -796,6 +839,50 @@ impl<N: Network> RegisterTypes<N> {
                 matches!(instruction, Instruction::HashManyPSD8(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
             ),
+            "hash.multi" => ensure!(
+                matches!(instruction, Instruction::HashMulti(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash_to_scalar.psd2" => ensure!(
+                matches!(instruction, Instruction::HashToScalarPSD2(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash_to_scalar.psd4" => ensure!(
+                matches!(instruction, Instruction::HashToScalarPSD4(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash_to_group.psd8" => ensure!(
+                matches!(instruction, Instruction::HashToGroupPSD8(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash_bytes.sha256" => ensure!(
+                matches!(instruction, Instruction::HashBytesSha256(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash_bytes.keccak256" => ensure!(
+                matches!(instruction, Instruction::HashBytesKeccak256(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash.concat.psd8" => ensure!(
+                matches!(instruction, Instruction::HashConcatPSD8(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "merkle.node" => ensure!(
+                matches!(instruction, Instruction::MerkleNode(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "merkle.root.psd2" => ensure!(
+                matches!(instruction, Instruction::MerkleRootPSD2(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "merkle.verify" => ensure!(
+                matches!(instruction, Instruction::MerkleVerify(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "serial_number" => ensure!(
+                matches!(instruction, Instruction::SerialNumber(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
             _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
         }
         Ok(())
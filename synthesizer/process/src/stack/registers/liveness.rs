@@ -0,0 +1,156 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use parking_lot::Mutex;
+
+/// Tracks the live range of each console register, as the span from its `store` to its last `load`.
+#[derive(Default)]
+pub(super) struct RegisterLiveness {
+    /// The number of `store` and `load` events observed so far.
+    num_events: usize,
+    /// The mapping of each stored register to its `(store, last load)` event positions.
+    live_ranges: Mutex<IndexMap<u64, (usize, usize)>>,
+}
+
+impl RegisterLiveness {
+    /// Records a `store` to the given register locator.
+    pub(super) fn record_store(&mut self, locator: u64) {
+        let position = self.num_events;
+        self.num_events += 1;
+        self.live_ranges.get_mut().insert(locator, (position, position));
+    }
+
+    /// Records a `load` from the given register locator.
+    pub(super) fn record_load(&self, locator: u64) {
+        let mut live_ranges = self.live_ranges.lock();
+        // Loads are positioned after every event observed so far, without advancing the store counter,
+        // so that an instruction reading its operands and then writing its destination overlaps both.
+        let position = self.num_events;
+        if let Some((_, last_load)) = live_ranges.get_mut(&locator) {
+            *last_load = position;
+        }
+    }
+
+    /// Returns the peak number of simultaneously-live registers.
+    pub(super) fn peak(&self) -> usize {
+        // Collect the boundaries of each live range, where a range ends just after its last load.
+        let mut boundaries = self
+            .live_ranges
+            .lock()
+            .values()
+            .flat_map(|(start, end)| [(*start, 1isize), (*end + 1, -1isize)])
+            .collect::<Vec<_>>();
+        // Sort the boundaries, with range ends processed before range starts at the same position.
+        boundaries.sort_unstable();
+
+        let mut live = 0isize;
+        let mut peak = 0isize;
+        for (_, delta) in boundaries {
+            live += delta;
+            peak = peak.max(live);
+        }
+        peak as usize
+    }
+}
+
+impl Clone for RegisterLiveness {
+    fn clone(&self) -> Self {
+        Self { num_events: self.num_events, live_ranges: Mutex::new(self.live_ranges.lock().clone()) }
+    }
+}
+
+impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
+    /// Enables liveness tracking for the console registers, which is disabled by default.
+    ///
+    /// Only the stores and loads after this call are tracked.
+    #[inline]
+    pub fn enable_liveness_tracking(&mut self) {
+        self.liveness.get_or_insert_with(Default::default);
+    }
+
+    /// Returns the peak number of simultaneously-live console registers observed so far,
+    /// or `None` if liveness tracking is disabled.
+    ///
+    /// A register is live from the moment it is stored until the last time it is loaded.
+    /// This is a diagnostic for program authors tuning functions near the register limit.
+    #[inline]
+    pub fn peak_live_registers(&self) -> Option<usize> {
+        self.liveness.as_ref().map(RegisterLiveness::peak)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, Process, Stack, StackProgramTypes};
+    use console::{network::MainnetV0, program::Identifier};
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_peak_live_registers() -> Result<()> {
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function run:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    input r2 as u64.private;
+    add r0 r1 into r3;
+    add r3 r3 into r4;
+    add r4 r2 into r5;
+    output r5 as u64.private;",
+        )?;
+        let stack = Stack::new(&Process::load()?, &program)?;
+        let function_name = Identifier::from_str("run")?;
+        let function = program.get_function(&function_name)?;
+
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::try_from((vec![], vec![]))?)?,
+            stack.get_register_types(&function_name)?.clone(),
+        );
+        // Ensure liveness tracking is disabled by default.
+        assert_eq!(registers.peak_live_registers(), None);
+        registers.enable_liveness_tracking();
+        assert_eq!(registers.peak_live_registers(), Some(0));
+
+        // Store the inputs.
+        for (index, input) in ["1u64", "2u64", "3u64"].iter().enumerate() {
+            registers.store(&stack, &Register::Locator(index as u64), Value::from_str(input)?)?;
+        }
+        assert_eq!(registers.peak_live_registers(), Some(3));
+
+        // Evaluate the instructions.
+        for instruction in function.instructions() {
+            instruction.evaluate(&stack, &mut registers)?;
+        }
+        // Load the output.
+        registers.load(&stack, &Operand::Register(Register::Locator(5)))?;
+
+        // By hand, the live registers at each instruction are:
+        //   `add r0 r1 into r3` -> { r0, r1, r2, r3 }
+        //   `add r3 r3 into r4` -> { r2, r3, r4 }
+        //   `add r4 r2 into r5` -> { r2, r4, r5 }
+        assert_eq!(registers.peak_live_registers(), Some(4));
+        Ok(())
+    }
+}
@@ -46,6 +46,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
         // Retrieve the stack value.
         let stack_value =
             self.console_registers.get(&register.locator()).ok_or_else(|| anyhow!("'{register}' does not exist"))?;
+        // Record the load, if liveness tracking is enabled.
+        if let Some(liveness) = &self.liveness {
+            liveness.record_load(register.locator());
+        }
 
         // Return the value for the given register or register access.
         let stack_value = match register {
@@ -15,7 +15,9 @@
 
 mod call;
 mod caller;
+mod liveness;
 mod load;
+mod state;
 mod store;
 
 use crate::{CallStack, RegisterTypes, RegistersCall};
@@ -36,6 +38,8 @@ use synthesizer_program::{
     StackProgram,
 };
 
+use liveness::RegisterLiveness;
+
 use indexmap::IndexMap;
 
 #[derive(Clone)]
@@ -64,6 +68,8 @@ pub struct Registers<N: Network, A: circuit::Aleo<Network = N>> {
     tvk: Option<Field<N>>,
     /// The transition view key, as a circuit.
     tvk_circuit: Option<circuit::Field<A>>,
+    /// The live ranges of the console registers, if liveness tracking is enabled.
+    liveness: Option<RegisterLiveness>,
 }
 
 impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
@@ -83,6 +89,7 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
             caller_circuit: None,
             tvk: None,
             tvk_circuit: None,
+            liveness: None,
         }
     }
 
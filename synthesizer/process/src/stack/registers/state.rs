@@ -0,0 +1,117 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
+    /// Returns the assigned console registers as bytes, so that an evaluation can be checkpointed and resumed.
+    ///
+    /// Note: The circuit registers are not persisted, as they are bound to the circuit environment.
+    pub fn serialize_state(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        // Write the number of assigned registers.
+        u32::try_from(self.console_registers.len())?.write_le(&mut bytes)?;
+        // Write each register locator and value.
+        for (locator, value) in &self.console_registers {
+            locator.write_le(&mut bytes)?;
+            value.write_le(&mut bytes)?;
+        }
+        Ok(bytes)
+    }
+
+    /// Restores the console registers from the given bytes, as produced by `Registers::serialize_state`.
+    ///
+    /// Each value is type-checked against the register types, and the registers must not yet be assigned.
+    pub fn restore_state(&mut self, stack: &(impl StackMatches<N> + StackProgram<N>), bytes: &[u8]) -> Result<()> {
+        // Ensure no registers have been assigned yet.
+        ensure!(self.console_registers.is_empty(), "Cannot restore the state into registers that are already assigned");
+
+        let mut reader = bytes;
+        // Read the number of assigned registers.
+        let num_registers = u32::read_le(&mut reader)?;
+        // Read and store each register.
+        for _ in 0..num_registers {
+            let locator = u64::read_le(&mut reader)?;
+            let value = Value::read_le(&mut reader)?;
+            self.store(stack, &Register::Locator(locator), value)?;
+        }
+        // Ensure all of the bytes were consumed.
+        ensure!(reader.is_empty(), "Found {} trailing bytes in the register state", reader.len());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, Process, Stack, StackProgramTypes};
+    use console::{network::MainnetV0, program::Identifier, types::U64};
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_serialize_and_restore_state() -> Result<()> {
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function run:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    mul r2 r1 into r3;
+    output r3 as u64.private;",
+        )?;
+        let stack = Stack::new(&Process::load()?, &program)?;
+        let function_name = Identifier::from_str("run")?;
+        let function = program.get_function(&function_name)?;
+
+        // Initializes a fresh set of registers for `run`.
+        let sample_registers = || -> Result<Registers<CurrentNetwork, CurrentAleo>> {
+            Ok(Registers::new(
+                CallStack::evaluate(Authorization::try_from((vec![], vec![]))?)?,
+                stack.get_register_types(&function_name)?.clone(),
+            ))
+        };
+
+        // Store the inputs, and evaluate the first instruction.
+        let mut registers = sample_registers()?;
+        registers.store(&stack, &Register::Locator(0), Value::from_str("3u64")?)?;
+        registers.store(&stack, &Register::Locator(1), Value::from_str("5u64")?)?;
+        function.instructions()[0].evaluate(&stack, &mut registers)?;
+
+        // Checkpoint the registers mid-execution, and restore them into a fresh set of registers.
+        let state = registers.serialize_state()?;
+        let mut restored = sample_registers()?;
+        restored.restore_state(&stack, &state)?;
+        assert_eq!(state, restored.serialize_state()?);
+
+        // Resume the evaluation on both sets of registers, and ensure they agree.
+        function.instructions()[1].evaluate(&stack, &mut registers)?;
+        function.instructions()[1].evaluate(&stack, &mut restored)?;
+        let expected = Value::Plaintext(Plaintext::from(Literal::U64(U64::new(40))));
+        assert_eq!(expected, restored.load(&stack, &Operand::Register(Register::Locator(3)))?);
+        assert_eq!(registers.serialize_state()?, restored.serialize_state()?);
+
+        // Ensure the state cannot be restored into assigned registers.
+        assert!(restored.restore_state(&stack, &state).is_err());
+        // Ensure truncated state is rejected.
+        assert!(sample_registers()?.restore_state(&stack, &state[..state.len() - 1]).is_err());
+        Ok(())
+    }
+}
@@ -52,8 +52,13 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersStore<N> for Registers<
                 match self.console_registers.insert(*locator, stack_value) {
                     // Ensure the register has not been previously stored.
                     Some(..) => bail!("Attempted to write to register '{register}' again"),
-                    // Return on success.
-                    None => Ok(()),
+                    // Record the store, if liveness tracking is enabled, and return on success.
+                    None => {
+                        if let Some(liveness) = &mut self.liveness {
+                            liveness.record_store(*locator);
+                        }
+                        Ok(())
+                    }
                 }
             }
             // Ensure the register is not a register access.
@@ -24,8 +24,8 @@ use circuit::{network::AleoV0, Aleo};
 use console::{
     account::{Address, PrivateKey, ViewKey},
     network::{prelude::*, MainnetV0},
-    program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
-    types::{Field, U64},
+    program::{Identifier, Literal, Locator, Plaintext, ProgramID, Record, Value},
+    types::{Field, Scalar, U64},
 };
 use ledger_block::{Fee, Transaction};
 use ledger_query::Query;
@@ -572,6 +572,165 @@ function hello_world:
     process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, &mut TestRng::default()).unwrap();
 }
 
+#[test]
+fn test_process_constraint_budget() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r#"program testing.aleo;
+
+function commit_and_hash:
+    input r0 as field.private;
+    input r1 as scalar.private;
+    input r2 as u64.public;
+    commit.bhp256 r0 r1 into r3 as field;
+    hash.psd2 r3 into r4 as field;
+    mul r2 r2 into r5;
+    output r4 as field.private;
+    output r5 as u64.private;
+"#,
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("commit_and_hash").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Compute the constraint budget, without executing the function.
+    let budget =
+        process.get_stack(program.id()).unwrap().constraint_budget::<CurrentAleo, _>(&function_name, rng).unwrap();
+    assert_eq!(budget.num_instructions, 3);
+    assert!(budget.num_function_constraints > 0);
+
+    // Execute the function.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let inputs = [
+        Value::<CurrentNetwork>::from_str(&Field::<CurrentNetwork>::rand(rng).to_string()).unwrap(),
+        Value::<CurrentNetwork>::from_str(&Scalar::<CurrentNetwork>::rand(rng).to_string()).unwrap(),
+        Value::<CurrentNetwork>::from_str("5u64").unwrap(),
+    ];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    let (_, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let metrics = trace.call_metrics();
+    assert_eq!(metrics.len(), 1);
+
+    // Ensure the budget matches the constraint counts of the execution.
+    // As the circuit for a function is fixed, the counts are expected to match exactly.
+    assert_eq!(metrics[0].num_request_constraints, budget.num_request_constraints);
+    assert_eq!(metrics[0].num_function_constraints, budget.num_function_constraints);
+    assert_eq!(metrics[0].num_response_constraints, budget.num_response_constraints);
+}
+
+#[test]
+fn test_process_execute_debug_records_field_add_wrap() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program testing.aleo;
+
+function add_fields:
+    input r0 as field.private;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;
+",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("add_fields").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorizes `add_fields` on the given inputs.
+    let authorize = |first: &str, second: &str, rng: &mut TestRng| {
+        let inputs = [Value::<CurrentNetwork>::from_str(first).unwrap(), Value::from_str(second).unwrap()];
+        process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap()
+    };
+
+    // Add two field elements whose sum exceeds the modulus, i.e. (p - 1) + 2.
+    let (response, trace) = process.execute_debug::<CurrentAleo, _>(authorize("-1field", "2field", rng), rng).unwrap();
+    assert_eq!(response.outputs()[0], Value::from_str("1field").unwrap());
+    // Ensure the trace flags the wrap-around.
+    let locator = Locator::new(*program.id(), function_name);
+    assert_eq!(trace.field_add_wraps(), &[(locator, 0)]);
+
+    // Add two field elements whose sum does not exceed the modulus.
+    let (response, trace) = process.execute_debug::<CurrentAleo, _>(authorize("1field", "2field", rng), rng).unwrap();
+    assert_eq!(response.outputs()[0], Value::from_str("3field").unwrap());
+    // Ensure the trace does not flag a wrap-around.
+    assert!(trace.field_add_wraps().is_empty());
+
+    // Ensure a (non-debug) execution does not record the wrap-around.
+    let (response, trace) = process.execute::<CurrentAleo, _>(authorize("-1field", "2field", rng), rng).unwrap();
+    assert_eq!(response.outputs()[0], Value::from_str("1field").unwrap());
+    assert!(trace.field_add_wraps().is_empty());
+}
+
+#[test]
+fn test_process_execute_debug_records_peak_live_registers() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program testing.aleo;
+
+function run:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    input r2 as u64.private;
+    add r0 r1 into r3;
+    add r3 r3 into r4;
+    add r4 r2 into r5;
+    output r5 as u64.private;
+",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("run").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorizes `run` on fixed inputs.
+    let authorize = |rng: &mut TestRng| {
+        let inputs = ["1u64", "2u64", "3u64"].map(|input| Value::<CurrentNetwork>::from_str(input).unwrap());
+        process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap()
+    };
+
+    // Ensure a (non-debug) execution does not record the peak number of live registers.
+    let (response, _) = process.execute::<CurrentAleo, _>(authorize(rng), rng).unwrap();
+    assert_eq!(response.outputs()[0], Value::from_str("9u64").unwrap());
+    assert_eq!(process.get_stack(program.id()).unwrap().peak_live_registers(&function_name), None);
+
+    // Ensure a debug execution records the peak number of live registers.
+    let (response, _) = process.execute_debug::<CurrentAleo, _>(authorize(rng), rng).unwrap();
+    assert_eq!(response.outputs()[0], Value::from_str("9u64").unwrap());
+    // By hand, the live registers at each instruction are:
+    //   `add r0 r1 into r3` -> { r0, r1, r2, r3 }
+    //   `add r3 r3 into r4` -> { r2, r3, r4 }
+    //   `add r4 r2 into r5` -> { r2, r4, r5 }
+    assert_eq!(process.get_stack(program.id()).unwrap().peak_live_registers(&function_name), Some(4));
+}
+
 #[test]
 fn test_process_multirecords() {
     // Initialize a new program.
@@ -41,6 +41,9 @@ pub struct Trace<N: Network> {
     inclusion_tasks: Inclusion<N>,
     /// A list of call metrics.
     call_metrics: Vec<CallMetrics<N>>,
+    /// A list of field `add` instructions (by locator and instruction index) whose sum wrapped the field modulus,
+    /// if the trace is in debug mode.
+    field_add_wraps: Option<Vec<(Locator<N>, usize)>>,
 
     /// A tracker for the inclusion assignments.
     inclusion_assignments: OnceCell<Vec<InclusionAssignment<N>>>,
@@ -58,9 +61,17 @@ impl<N: Network> Trace<N> {
             inclusion_assignments: OnceCell::new(),
             global_state_root: OnceCell::new(),
             call_metrics: Vec::new(),
+            field_add_wraps: None,
         }
     }
 
+    /// Initializes a new trace in debug mode, which records the field `add` instructions whose sum wraps the modulus.
+    ///
+    /// Debug mode is a tooling aid for program authors, and does not affect the execution or its proofs.
+    pub fn new_debug() -> Self {
+        Self { field_add_wraps: Some(Vec::new()), ..Self::new() }
+    }
+
     /// Returns the list of transitions.
     pub fn transitions(&self) -> &[Transition<N>] {
         &self.transitions
@@ -70,6 +81,17 @@ impl<N: Network> Trace<N> {
     pub fn call_metrics(&self) -> &[CallMetrics<N>] {
         &self.call_metrics
     }
+
+    /// Returns `true` if the trace is in debug mode.
+    pub fn is_debug(&self) -> bool {
+        self.field_add_wraps.is_some()
+    }
+
+    /// Returns the field `add` instructions (by locator and instruction index) whose sum wrapped the field modulus.
+    /// This is always empty if the trace is not in debug mode.
+    pub fn field_add_wraps(&self) -> &[(Locator<N>, usize)] {
+        self.field_add_wraps.as_deref().unwrap_or_default()
+    }
 }
 
 impl<N: Network> Trace<N> {
@@ -99,6 +121,14 @@ impl<N: Network> Trace<N> {
 
         Ok(())
     }
+
+    /// Records that the field `add` instruction at the given index of the given function wrapped the field modulus.
+    /// This is a no-op if the trace is not in debug mode.
+    pub fn insert_field_add_wrap(&mut self, locator: Locator<N>, index: usize) {
+        if let Some(field_add_wraps) = &mut self.field_add_wraps {
+            field_add_wraps.push((locator, index));
+        }
+    }
 }
 
 impl<N: Network> Trace<N> {
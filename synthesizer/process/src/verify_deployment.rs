@@ -34,6 +34,10 @@ impl<N: Network> Process<N> {
         let stack = Stack::new(self, deployment.program())?;
         lap!(timer, "Compute the stack");
 
+        // Ensure the program satisfies the checks for new deployments.
+        stack.check_new_deployment()?;
+        lap!(timer, "Check the new deployment");
+
         // Ensure the verifying keys are well-formed and the certificates are valid.
         let verification = stack.verify_deployment::<A, R>(deployment, rng);
         lap!(timer, "Verify the deployment");
@@ -47,8 +51,63 @@ impl<N: Network> Process<N> {
 mod tests {
     use super::*;
 
+    type CurrentNetwork = console::network::MainnetV0;
     type CurrentAleo = circuit::network::AleoV0;
 
+    /// Ensures the given program is rejected as a new deployment with the expected error, and that it still loads.
+    ///
+    /// Note: These programs fail when evaluated, so they cannot be synthesized into a deployment, and are
+    /// loaded with `Process::add_program`, which builds the stack as `Process::load_deployment` does.
+    fn check_deployed_program_loads(
+        program: &Program<CurrentNetwork>,
+        expected: &str,
+        rng: &mut TestRng,
+    ) -> Result<()> {
+        let mut process = Process::load()?;
+
+        // Ensure the program is rejected as a new deployment.
+        let error = process.deploy::<CurrentAleo, _>(program, rng).unwrap_err();
+        assert!(error.to_string().contains(expected), "Unexpected error: {error}");
+
+        // Ensure the deployed program still loads.
+        process.add_program(program)?;
+        assert!(process.contains_program(program.id()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_deployment_checks_allow_deployed_programs() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program that commits to an input wider than the Pedersen window,
+        // as could have been deployed before the check for new deployments.
+        let program = Program::from_str(
+            r"
+program legacy_commit.aleo;
+
+function run:
+    input r0 as u64.private;
+    input r1 as scalar.private;
+    commit.ped64 r0 r1 into r2 as group;
+    output r2 as group.private;",
+        )?;
+        check_deployed_program_loads(&program, "expects an input of at most 64 bits", rng)?;
+
+        // Initialize a program that commits with a field randomizer,
+        // as could have been deployed before the check for new deployments.
+        let program = Program::from_str(
+            r"
+program legacy_randomizer.aleo;
+
+function run:
+    input r0 as u64.private;
+    input r1 as field.private;
+    commit.bhp256 r0 r1 into r2 as group;
+    output r2 as group.private;",
+        )?;
+        check_deployed_program_loads(&program, "expects a scalar randomizer, found 'field'", rng)
+    }
+
     /// Use `cargo test profiler --features timer` to run this test.
     #[ignore]
     #[test]
@@ -46,6 +46,7 @@ pub use traits::*;
 mod bytes;
 mod parse;
 mod serialize;
+mod validate_outputs;
 
 use console::{
     network::prelude::{
@@ -68,44 +68,70 @@ use console::{
 pub enum Instruction<N: Network> {
     /// Compute the absolute value of `first`, checking for overflow, and storing the outcome in `destination`.
     Abs(Abs<N>),
+    /// Computes the absolute difference of `first` and `second`, storing the outcome in `destination`.
+    AbsDiff(AbsDiff<N>),
     /// Compute the absolute value of `first`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
     AbsWrapped(AbsWrapped<N>),
     /// Adds `first` with `second`, storing the outcome in `destination`.
     Add(Add<N>),
     /// Adds `first` with `second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
     AddWrapped(AddWrapped<N>),
+    /// Computes a short `u64` fingerprint of an address, storing the outcome in `destination`.
+    AddressFingerprint(AddressFingerprint<N>),
     /// Performs a bitwise `and` operation on `first` and `second`, storing the outcome in `destination`.
     And(And<N>),
     /// Asserts `first` and `second` are equal.
     AssertEq(AssertEq<N>),
     /// Asserts `first` and `second` are **not** equal.
     AssertNeq(AssertNeq<N>),
+    /// Asserts `first` is true, halting otherwise.
+    AssertBool(AssertBool<N>),
+    /// Asserts the field or integer operand is non-zero, halting otherwise.
+    AssertNonzero(AssertNonzero<N>),
     /// Calls a finalize asynchronously on the operands.
     Async(Async<N>),
+    /// Packs the boolean array `first` into an integer, storing the outcome in `destination`.
+    BitsToInt(BitsToInt<N>),
     /// Calls a closure or function on the operands.
     Call(Call<N>),
     /// Casts the operands into the declared type.
     Cast(Cast<N>),
     /// Casts the operands into the declared type, with lossy truncation if applicable.
     CastLossy(CastLossy<N>),
+    /// Adds two Pedersen commitments, storing the group sum in `destination`.
+    CommitAdd(CommitAdd<N>),
     /// Performs a BHP commitment on inputs of 256-bit chunks.
     CommitBHP256(CommitBHP256<N>),
+    /// Performs a BHP commitment on inputs of 256-bit chunks, using a field randomizer.
+    CommitBHP256Field(CommitBHP256Field<N>),
+    /// Performs a BHP commitment on inputs of 256-bit chunks, and hashes the commitment with Poseidon2.
+    CommitBHP256Hashed(CommitBHP256Hashed<N>),
     /// Performs a BHP commitment on inputs of 512-bit chunks.
     CommitBHP512(CommitBHP512<N>),
     /// Performs a BHP commitment on inputs of 768-bit chunks.
     CommitBHP768(CommitBHP768<N>),
     /// Performs a BHP commitment on inputs of 1024-bit chunks.
     CommitBHP1024(CommitBHP1024<N>),
+    /// Extends a BHP256 commitment chain with a value, storing the new commitment in `destination`.
+    CommitChainBHP256(CommitChainBHP256<N>),
+    /// Performs a BHP commitment on the ciphertext of a record, in 256-bit chunks.
+    CommitCiphertextBHP256(CommitCiphertextBHP256<N>),
     /// Performs a Pedersen commitment on up to a 64-bit input.
     CommitPED64(CommitPED64<N>),
     /// Performs a Pedersen commitment on up to a 128-bit input.
     CommitPED128(CommitPED128<N>),
+    /// Computes the Pedersen commitment to zero under a randomizer, storing the group element in `destination`.
+    CommitZeroPED64(CommitZeroPED64<N>),
     /// Divides `first` by `second`, storing the outcome in `destination`.
     Div(Div<N>),
     /// Divides `first` by `second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
     DivWrapped(DivWrapped<N>),
     /// Doubles `first`, storing the outcome in `destination`.
     Double(Double<N>),
+    /// Stores the group generator `G` in `destination`.
+    Generator(Generator<N>),
+    /// Reads a named member of a struct, storing the member in `destination`.
+    GetMember(GetMember<N>),
     /// Computes whether `first` is greater than `second` as a boolean, storing the outcome in `destination`.
     GreaterThan(GreaterThan<N>),
     /// Computes whether `first` is greater than or equal to `second` as a boolean, storing the outcome in `destination`.
@@ -146,6 +172,20 @@ pub enum Instruction<N: Network> {
     HashManyPSD4(HashManyPSD4<N>),
     /// Performs a Poseidon hash with an input rate of 8.
     HashManyPSD8(HashManyPSD8<N>),
+    /// Computes one Poseidon hash of `first` per domain in the `second` field array, storing the outcome in `destination`.
+    HashMulti(HashMulti<N>),
+    /// Performs a Poseidon hash to a scalar with an input rate of 2.
+    HashToScalarPSD2(HashToScalarPSD2<N>),
+    /// Performs a Poseidon hash to a scalar with an input rate of 4.
+    HashToScalarPSD4(HashToScalarPSD4<N>),
+    /// Performs a Poseidon hash onto the curve with an input rate of 8, outputting a group element.
+    HashToGroupPSD8(HashToGroupPSD8<N>),
+    /// Performs a native SHA-256 hash, outputting 32 bytes.
+    HashBytesSha256(HashBytesSha256<N>),
+    /// Performs a native Keccak hash, outputting 32 bytes.
+    HashBytesKeccak256(HashBytesKeccak256<N>),
+    /// Performs a Poseidon hash with an input rate of 8, over the concatenated fields of the operands.
+    HashConcatPSD8(HashConcatPSD8<N>),
     /// Computes the multiplicative inverse of `first`, storing the outcome in `destination`.
     Inv(Inv<N>),
     /// Computes whether `first` equals `second` as a boolean, storing the outcome in `destination`.
@@ -156,6 +196,14 @@ pub enum Instruction<N: Network> {
     LessThan(LessThan<N>),
     /// Computes whether `first` is less than or equal to `second` as a boolean, storing the outcome in `destination`.
     LessThanOrEqual(LessThanOrEqual<N>),
+    /// Looks up the element at index `first` in the table `second`, storing the outcome in `destination`.
+    Lookup(Lookup<N>),
+    /// Computes the domain-separated Poseidon hash of two Merkle node children, storing the outcome in `destination`.
+    MerkleNode(MerkleNode<N>),
+    /// Computes the root of the Poseidon Merkle tree over the field array `first`, storing the outcome in `destination`.
+    MerkleRootPSD2(MerkleRootPSD2<N>),
+    /// Verifies a Merkle inclusion proof against a root.
+    MerkleVerify(MerkleVerify<N>),
     /// Computes `first` mod `second`, storing the outcome in `destination`.
     Modulo(Modulo<N>),
     /// Multiplies `first` with `second`, storing the outcome in `destination`.
@@ -172,14 +220,24 @@ pub enum Instruction<N: Network> {
     Not(Not<N>),
     /// Performs a bitwise `or` on `first` and `second`, storing the outcome in `destination`.
     Or(Or<N>),
+    /// Counts the number of set bits in `first`, storing the outcome as a `u32` in `destination`.
+    Popcount(Popcount<N>),
     /// Raises `first` to the power of `second`, storing the outcome in `destination`.
     Pow(Pow<N>),
+    /// Raises the field `first` to a small constant exponent, storing the outcome in `destination`.
+    PowConst(PowConst<N>),
     /// Raises `first` to the power of `second`, wrapping around at the boundary of the type, storing the outcome in `destination`.
     PowWrapped(PowWrapped<N>),
+    /// Derives a scalar commitment randomizer from a seed and index.
+    Randomizer(Randomizer<N>),
     /// Divides `first` by `second`, storing the remainder in `destination`.
     Rem(Rem<N>),
     /// Divides `first` by `second`, wrapping around at the boundary of the type, storing the remainder in `destination`.
     RemWrapped(RemWrapped<N>),
+    /// Computes whether `value` and `randomizer` are a valid BHP256 opening of `commitment`, storing the outcome in `destination`.
+    Reveal(Reveal<N>),
+    /// Derives the serial number of a record as `PRF(secret, commitment)`, storing the outcome in `destination`.
+    SerialNumber(SerialNumber<N>),
     /// Shifts `first` left by `second` bits, storing the outcome in `destination`.
     Shl(Shl<N>),
     /// Shifts `first` left by `second` bits, wrapping around at the boundary of the type, storing the outcome in `destination`.
@@ -188,20 +246,28 @@ pub enum Instruction<N: Network> {
     Shr(Shr<N>),
     /// Shifts `first` right by `second` bits, wrapping around at the boundary of the type, storing the outcome in `destination`.
     ShrWrapped(ShrWrapped<N>),
+    /// Shuffles the array `first` with the seed `second`, storing the outcome in `destination`.
+    Shuffle(Shuffle<N>),
     /// Computes whether `signature` is valid for the given `address` and `message`.
     SignVerify(SignVerify<N>),
     /// Squares 'first', storing the outcome in `destination`.
     Square(Square<N>),
     /// Compute the square root of 'first', storing the outcome in `destination`.
     SquareRoot(SquareRoot<N>),
+    /// Hashes the given registers into a single field attestation, storing the outcome in `destination`.
+    StateHash(StateHash<N>),
     /// Computes `first - second`, storing the outcome in `destination`.
     Sub(Sub<N>),
     /// Computes `first - second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
     SubWrapped(SubWrapped<N>),
     /// Selects `first`, if `condition` is true, otherwise selects `second`, storing the result in `destination`.
     Ternary(Ternary<N>),
+    /// Verifies a batch of `commit.bhp256` openings, storing the outcome in `destination`.
+    VerifyCommitments(VerifyCommitments<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
+    /// Computes the bitwise XOR of the fields `first` and `second`, storing the outcome in `destination`.
+    XorField(XorField<N>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -301,6 +367,40 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            // Note: The opcode of an instruction is its index in this list, so new opcodes must be appended.
+            Reveal,
+            Popcount,
+            MerkleNode,
+            CommitBHP256Field,
+            HashMulti,
+            Randomizer,
+            MerkleVerify,
+            HashToScalarPSD2,
+            HashToScalarPSD4,
+            AssertBool,
+            HashBytesSha256,
+            HashBytesKeccak256,
+            CommitBHP256Hashed,
+            Generator,
+            HashConcatPSD8,
+            PowConst,
+            BitsToInt,
+            CommitChainBHP256,
+            AddressFingerprint,
+            CommitAdd,
+            GetMember,
+            StateHash,
+            HashToGroupPSD8,
+            AssertNonzero,
+            CommitCiphertextBHP256,
+            Shuffle,
+            VerifyCommitments,
+            AbsDiff,
+            Lookup,
+            CommitZeroPED64,
+            MerkleRootPSD2,
+            XorField,
+            SerialNumber,
         }}
     };
     // A variant **without** curly braces:
@@ -470,7 +570,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            101,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
@@ -0,0 +1,278 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, PlaintextType, Register, RegisterType},
+};
+
+/// Asserts the field or integer operand is non-zero, halting otherwise.
+///
+/// This documents the precondition of a division explicitly. In the circuit, this is enforced as `x * x^(-1) == 1`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct AssertNonzero<N: Network> {
+    /// The operand.
+    operands: Vec<Operand<N>>,
+}
+
+impl<N: Network> AssertNonzero<N> {
+    /// Initializes a new `assert.nonzero` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>) -> Result<Self> {
+        // Sanity check that the operands is exactly one input.
+        ensure!(operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Assert("assert.nonzero")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly one input.
+        debug_assert!(self.operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![]
+    }
+}
+
+impl<N: Network> AssertNonzero<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut impl RegistersLoad<N>,
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the input.
+        let is_zero = match registers.load_literal(stack, &self.operands[0])? {
+            Literal::Field(input) => input.is_zero(),
+            Literal::I8(input) => input.is_zero(),
+            Literal::I16(input) => input.is_zero(),
+            Literal::I32(input) => input.is_zero(),
+            Literal::I64(input) => input.is_zero(),
+            Literal::I128(input) => input.is_zero(),
+            Literal::U8(input) => input.is_zero(),
+            Literal::U16(input) => input.is_zero(),
+            Literal::U32(input) => input.is_zero(),
+            Literal::U64(input) => input.is_zero(),
+            Literal::U128(input) => input.is_zero(),
+            literal => {
+                bail!("Instruction '{}' expects a field or integer, found '{}'", Self::opcode(), literal.to_type())
+            }
+        };
+        match is_zero {
+            true => bail!("'{}' failed: '{}' is zero", Self::opcode(), self.operands[0]),
+            false => Ok(()),
+        }
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut impl RegistersLoadCircuit<N, A>,
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        use circuit::traits::{Inverse, ToField};
+
+        // Retrieve the input as a field element.
+        let input = match registers.load_literal_circuit(stack, &self.operands[0])? {
+            circuit::Literal::Field(input) => input,
+            circuit::Literal::I8(input) => input.to_field(),
+            circuit::Literal::I16(input) => input.to_field(),
+            circuit::Literal::I32(input) => input.to_field(),
+            circuit::Literal::I64(input) => input.to_field(),
+            circuit::Literal::I128(input) => input.to_field(),
+            circuit::Literal::U8(input) => input.to_field(),
+            circuit::Literal::U16(input) => input.to_field(),
+            circuit::Literal::U32(input) => input.to_field(),
+            circuit::Literal::U64(input) => input.to_field(),
+            circuit::Literal::U128(input) => input.to_field(),
+            literal => {
+                bail!("Instruction '{}' expects a field or integer, found '{}'", Self::opcode(), literal.to_type())
+            }
+        };
+        // Enforce `input * input^(-1) == 1`, leaving the circuit unsatisfied if the input is zero.
+        input.inverse();
+        Ok(())
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut impl RegistersLoad<N>,
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 1 {
+            bail!("Instruction '{}' expects 1 input, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the operand is a field or an integer.
+        match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Literal(
+                LiteralType::Field
+                | LiteralType::I8
+                | LiteralType::I16
+                | LiteralType::I32
+                | LiteralType::I64
+                | LiteralType::I128
+                | LiteralType::U8
+                | LiteralType::U16
+                | LiteralType::U32
+                | LiteralType::U64
+                | LiteralType::U128,
+            )) => (),
+            input_type => bail!("Instruction '{}' expects a field or integer, found '{input_type}'", Self::opcode()),
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        Ok(vec![])
+    }
+}
+
+impl<N: Network> Parser for AssertNonzero<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the operand from the string.
+        let (string, operand) = Operand::parse(string)?;
+
+        Ok((string, Self { operands: vec![operand] }))
+    }
+}
+
+impl<N: Network> FromStr for AssertNonzero<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for AssertNonzero<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for AssertNonzero<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {}", Self::opcode(), self.operands[0])
+    }
+}
+
+impl<N: Network> FromBytes for AssertNonzero<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operand.
+        let operand = Operand::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands: vec![operand] })
+    }
+}
+
+impl<N: Network> ToBytes for AssertNonzero<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(error(format!("The number of operands must be 1, found {}", self.operands.len())));
+        }
+        // Write the operand.
+        self.operands[0].write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, assert) = AssertNonzero::<CurrentNetwork>::parse("assert.nonzero r0").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(assert.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(assert.operands[0], Operand::Register(Register::Locator(0)), "The operand is incorrect");
+
+        // Ensure the boolean assertion is not parsed as `assert.nonzero`.
+        assert!(AssertNonzero::<CurrentNetwork>::from_str("assert r0").is_err());
+    }
+}
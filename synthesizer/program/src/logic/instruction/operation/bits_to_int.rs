@@ -0,0 +1,344 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+};
+
+/// Returns `true` if the destination type is valid.
+fn is_valid_destination_type(destination_type: LiteralType) -> bool {
+    matches!(
+        destination_type,
+        LiteralType::I8
+            | LiteralType::I16
+            | LiteralType::I32
+            | LiteralType::I64
+            | LiteralType::I128
+            | LiteralType::U8
+            | LiteralType::U16
+            | LiteralType::U32
+            | LiteralType::U64
+            | LiteralType::U128
+    )
+}
+
+/// Packs the boolean array `first` into an integer of the declared type, storing the outcome in `destination`.
+///
+/// The array elements are the little-endian bits of the integer, so `first[i]` is bit `i` of the output,
+/// and any bits beyond the length of the array are zero.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BitsToInt<N: Network> {
+    /// The operand.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+    /// The destination register type.
+    destination_type: LiteralType,
+}
+
+impl<N: Network> BitsToInt<N> {
+    /// Initializes a new `bits_to_int` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>, destination_type: LiteralType) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Sanity check the destination type.
+        ensure!(is_valid_destination_type(destination_type), "Invalid destination type for '{}'", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination, destination_type })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Literal("bits_to_int")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there is exactly one operand.
+        debug_assert!(self.operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the destination register type.
+    #[inline]
+    pub const fn destination_type(&self) -> LiteralType {
+        self.destination_type
+    }
+}
+
+impl<N: Network> BitsToInt<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+        // Ensure the destination type is valid.
+        ensure!(is_valid_destination_type(self.destination_type), "Invalid destination type for '{}'", Self::opcode());
+
+        // Retrieve the bits.
+        let mut bits_le = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(Plaintext::Array(elements, _)) => elements
+                .iter()
+                .map(|element| match element {
+                    Plaintext::Literal(Literal::Boolean(bit), _) => Ok(**bit),
+                    _ => bail!("Instruction '{}' expects a boolean array", Self::opcode()),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("Instruction '{}' expects a boolean array", Self::opcode()),
+        };
+
+        // Ensure the bits fit in the destination type, and pad them to its size.
+        let num_bits = self.destination_type.size_in_bits::<N>() as usize;
+        ensure!(bits_le.len() <= num_bits, "Instruction '{}' expects at most {num_bits} bits", Self::opcode());
+        bits_le.resize(num_bits, false);
+
+        // Pack the bits into the integer.
+        let output = Literal::from_bits_le(self.destination_type.type_id(), &bits_le)?;
+        // Store the output.
+        registers.store_literal(stack, &self.destination, output)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::Inject;
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+        // Ensure the destination type is valid.
+        ensure!(is_valid_destination_type(self.destination_type), "Invalid destination type for '{}'", Self::opcode());
+
+        // Retrieve the bits.
+        let mut bits_le = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Array(elements, _)) => elements
+                .iter()
+                .map(|element| match element {
+                    circuit::Plaintext::Literal(circuit::Literal::Boolean(bit), _) => Ok(bit.clone()),
+                    _ => bail!("Instruction '{}' expects a boolean array", Self::opcode()),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("Instruction '{}' expects a boolean array", Self::opcode()),
+        };
+
+        // Ensure the bits fit in the destination type, and pad them to its size.
+        let num_bits = self.destination_type.size_in_bits::<N>() as usize;
+        ensure!(bits_le.len() <= num_bits, "Instruction '{}' expects at most {num_bits} bits", Self::opcode());
+        bits_le.resize(num_bits, circuit::Boolean::constant(false));
+
+        // Pack the bits into the integer.
+        let variant = circuit::U8::constant(console::types::U8::new(self.destination_type.type_id()));
+        let output = circuit::Literal::from_bits_le(&variant, &bits_le);
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, output)
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 1 {
+            bail!("Instruction '{}' expects 1 input, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the destination type is valid.
+        ensure!(is_valid_destination_type(self.destination_type), "Invalid destination type for '{}'", Self::opcode());
+
+        // Ensure the input is a boolean array that fits in the destination type.
+        match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Array(array_type))
+                if array_type.next_element_type() == &PlaintextType::Literal(LiteralType::Boolean) =>
+            {
+                let num_bits = self.destination_type.size_in_bits::<N>();
+                if **array_type.length() > u32::from(num_bits) {
+                    bail!(
+                        "Instruction '{}' expects at most {num_bits} bits for '{}', found '{}'",
+                        Self::opcode(),
+                        self.destination_type,
+                        input_types[0]
+                    )
+                }
+            }
+            input_type => bail!("Instruction '{}' expects a boolean array, found '{input_type}'", Self::opcode()),
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(self.destination_type))])
+    }
+}
+
+impl<N: Network> Parser for BitsToInt<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register type from the string.
+        let (string, destination_type) = LiteralType::parse(string)?;
+        // Ensure the destination type is allowed.
+        match is_valid_destination_type(destination_type) {
+            true => Ok((string, Self { operands: vec![first], destination, destination_type })),
+            false => map_res(fail, |_: ParserResult<Self>| {
+                Err(error(format!("Failed to parse '{}': '{destination_type}' is invalid", Self::opcode())))
+            })(string),
+        }
+    }
+}
+
+impl<N: Network> FromStr for BitsToInt<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for BitsToInt<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for BitsToInt<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {} into {} as {}", Self::opcode(), self.operands[0], self.destination, self.destination_type)
+    }
+}
+
+impl<N: Network> FromBytes for BitsToInt<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operand.
+        let operand = Operand::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Read the destination register type.
+        let destination_type = LiteralType::read_le(&mut reader)?;
+
+        // Return the operation.
+        Self::new(vec![operand], destination, destination_type).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for BitsToInt<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(error(format!("The number of operands must be 1, found {}", self.operands.len())));
+        }
+        // Write the operand.
+        self.operands[0].write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)?;
+        // Write the destination register type.
+        self.destination_type.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, instruction) = BitsToInt::<CurrentNetwork>::parse("bits_to_int r0 into r1 as u32").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(instruction.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(instruction.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(instruction.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(instruction.destination_type, LiteralType::U32, "The destination type is incorrect");
+
+        // Ensure non-integer destination types are rejected.
+        assert!(BitsToInt::<CurrentNetwork>::parse("bits_to_int r0 into r1 as field").is_err());
+    }
+}
@@ -21,6 +21,7 @@ use crate::{
 use console::{
     network::prelude::*,
     program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{Field, Scalar},
 };
 
 /// BHP256 is a collision-resistant function that processes inputs in 256-bit chunks.
@@ -37,6 +38,14 @@ pub type CommitPED64<N> = CommitInstruction<N, { Committer::CommitPED64 as u8 }>
 /// Pedersen128 is a collision-resistant function that processes inputs in 128-bit chunks.
 pub type CommitPED128<N> = CommitInstruction<N, { Committer::CommitPED128 as u8 }>;
 
+/// BHP256 commitment with a field randomizer, which is cast to a scalar by reducing it modulo the scalar field,
+/// i.e. the randomizer is the canonical integer of the field element, modulo the scalar field modulus.
+pub type CommitBHP256Field<N> = CommitInstruction<N, { Committer::CommitBHP256Field as u8 }>;
+
+/// BHP256 commitment whose coordinates are hashed with Poseidon2, as `HashPSD2([c.x, c.y])`,
+/// where `c := commit.bhp256(input, randomizer)`. Both coordinates are hashed, as `c` and `-c` share an x-coordinate.
+pub type CommitBHP256Hashed<N> = CommitInstruction<N, { Committer::CommitBHP256Hashed as u8 }>;
+
 enum Committer {
     CommitBHP256,
     CommitBHP512,
@@ -44,6 +53,56 @@ enum Committer {
     CommitBHP1024,
     CommitPED64,
     CommitPED128,
+    CommitBHP256Field,
+    CommitBHP256Hashed,
+}
+
+/// The errors raised while evaluating, executing, or type-checking a `commit` instruction.
+///
+/// The instruction methods return `anyhow::Result`, so match on a variant with `error.downcast_ref::<CommitError>()`.
+#[derive(Debug, thiserror::Error)]
+pub enum CommitError {
+    /// The instruction does not have exactly two operands.
+    #[error("Instruction '{opcode}' expects 2 operands, found {found} operands")]
+    InvalidNumberOfOperands { opcode: Opcode, found: usize },
+    /// The instruction was type-checked with other than two input types.
+    #[error("Instruction '{opcode}' expects 2 inputs, found {found} inputs")]
+    InvalidNumberOfInputs { opcode: Opcode, found: usize },
+    /// The destination type is not an address, field, or group.
+    #[error("Invalid destination type in 'commit' instruction")]
+    InvalidDestinationType,
+    /// The randomizer loaded at runtime is not of the expected type.
+    #[error("Invalid randomizer type for the commit {phase}, expected a {expected}")]
+    InvalidRandomizerType { phase: &'static str, expected: LiteralType },
+    /// The input of a Pedersen commitment exceeds the number of bits the committer accepts.
+    #[error("Instruction '{opcode}' expects an input of at most {max_num_bits} bits, found '{input_type}' ({num_bits} bits)")]
+    InputTooLarge { opcode: Opcode, max_num_bits: usize, input_type: String, num_bits: usize },
+    /// The input of a Pedersen commitment is not a plaintext.
+    #[error("Instruction '{opcode}' expects a plaintext input, found '{input_type}'")]
+    NonPlaintextInput { opcode: Opcode, input_type: String },
+    /// The randomizer of `commit.bhp256.field` is not declared as a field.
+    #[error("Instruction '{opcode}' expects a field randomizer, found '{randomizer_type}'")]
+    ExpectedFieldRandomizer { opcode: Opcode, randomizer_type: String },
+    /// The randomizer of a scalar committer is not declared as a scalar.
+    #[error("Instruction '{opcode}' expects a scalar randomizer, found '{randomizer_type}'")]
+    ExpectedScalarRandomizer { opcode: Opcode, randomizer_type: String },
+    /// The committer variant is not supported.
+    #[error("Invalid 'commit' variant: {0}")]
+    InvalidVariant(u8),
+}
+
+/// The format tag of the two-operand `commit` byte encoding, which is written before the operands.
+///
+/// The tag is required for the tagged variants, and rejected for the others, so that each instruction
+/// has exactly one encoding.
+const COMMIT_FORMAT_V1: u8 = 0x80;
+
+/// Returns 'true' if the committer variant is written with a format tag.
+///
+/// The committers that predate the format tag are written untagged, so their encodings
+/// (and the checksums and IDs of deployed programs) are unchanged.
+const fn is_tagged_variant(variant: u8) -> bool {
+    variant >= Committer::CommitBHP256Field as u8
 }
 
 /// Returns 'true' if the destination type is valid.
@@ -51,7 +110,37 @@ fn is_valid_destination_type(destination_type: LiteralType) -> bool {
     matches!(destination_type, LiteralType::Address | LiteralType::Field | LiteralType::Group)
 }
 
+/// Returns the (maximum) number of bits in `Plaintext::to_bits_le` for a plaintext of the given type.
+fn plaintext_size_in_bits<N: Network>(
+    stack: &impl StackProgram<N>,
+    plaintext_type: &PlaintextType<N>,
+) -> Result<usize> {
+    match plaintext_type {
+        // A literal is written as its variant bits, the literal type, the literal size, and the literal.
+        PlaintextType::Literal(literal_type) => Ok(2 + 8 + 16 + literal_type.size_in_bits::<N>() as usize),
+        // A struct is written as its variant bits, the number of members, and each (sized) member.
+        PlaintextType::Struct(struct_name) => {
+            let mut num_bits = 2 + 8;
+            for (identifier, member_type) in stack.program().get_struct(struct_name)?.members() {
+                num_bits += 8 + identifier.size_in_bits() as usize + 16 + plaintext_size_in_bits(stack, member_type)?;
+            }
+            Ok(num_bits)
+        }
+        // An array is written as its variant bits, the number of elements, and each (sized) element.
+        PlaintextType::Array(array_type) => {
+            let element_size = 16 + plaintext_size_in_bits(stack, array_type.next_element_type())?;
+            Ok(2 + 32 + **array_type.length() as usize * element_size)
+        }
+    }
+}
+
 /// Commits the operand into the declared type.
+///
+/// A commitment declared `as address` is the address of the commitment group element, which can serve
+/// as a deterministic pseudo-address (e.g. `commit.bhp256 r0 r1 into r2 as address`, for an owner and a salt).
+///
+/// The `VARIANT` must be one of the supported committers, which is checked at compile time
+/// (see `tests/ui/commit_invalid_variant.rs`).
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CommitInstruction<N: Network, const VARIANT: u8> {
     /// The operand as `input`.
@@ -63,6 +152,11 @@ pub struct CommitInstruction<N: Network, const VARIANT: u8> {
 }
 
 impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
+    /// Ensures the `VARIANT` is a supported committer. This is evaluated when the instruction is instantiated,
+    /// so an unsupported `VARIANT` fails to compile, instead of panicking on the first opcode access.
+    const CHECK_VARIANT: () =
+        assert!(VARIANT <= Committer::CommitBHP256Hashed as u8, "Invalid 'commit' instruction variant");
+
     /// Initializes a new `commit` instruction.
     #[inline]
     pub fn new(operands: Vec<Operand<N>>, destination: Register<N>, destination_type: LiteralType) -> Result<Self> {
@@ -77,6 +171,9 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
     /// Returns the opcode.
     #[inline]
     pub const fn opcode() -> Opcode {
+        // Ensure the variant is supported at compile time.
+        let () = Self::CHECK_VARIANT;
+
         match VARIANT {
             0 => Opcode::Commit("commit.bhp256"),
             1 => Opcode::Commit("commit.bhp512"),
@@ -84,7 +181,9 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
             3 => Opcode::Commit("commit.bhp1024"),
             4 => Opcode::Commit("commit.ped64"),
             5 => Opcode::Commit("commit.ped128"),
-            6.. => panic!("Invalid 'commit' instruction opcode"),
+            6 => Opcode::Commit("commit.bhp256.field"),
+            7 => Opcode::Commit("commit.bhp256.hashed"),
+            8.. => panic!("Invalid 'commit' instruction opcode"),
         }
     }
 
@@ -110,6 +209,73 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
     }
 }
 
+impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
+    /// Returns the scalar randomizer from the given value, loaded from a register or a literal.
+    ///
+    /// The randomizer of `commit.bhp256.field` is a field, which is reduced modulo the scalar field.
+    fn extract_scalar_randomizer(randomizer: Value<N>) -> Result<Scalar<N>> {
+        match (VARIANT, randomizer) {
+            (0..=5 | 7, Value::Plaintext(Plaintext::Literal(Literal::Scalar(randomizer), ..))) => Ok(randomizer),
+            // Note: The field randomizer is reduced into a scalar, consistent with the circuit extraction.
+            (6, Value::Plaintext(Plaintext::Literal(Literal::Field(randomizer), ..))) => {
+                Ok(Scalar::new(N::Scalar::from_bytes_le_mod_order(&randomizer.to_bytes_le()?)))
+            }
+            (6, _) => bail!(CommitError::InvalidRandomizerType { phase: "evaluation", expected: LiteralType::Field }),
+            _ => bail!(CommitError::InvalidRandomizerType { phase: "evaluation", expected: LiteralType::Scalar }),
+        }
+    }
+
+    /// Returns the scalar randomizer from the given circuit value, loaded from a register or a literal.
+    ///
+    /// The randomizer of `commit.bhp256.field` is a field, which is reduced modulo the scalar field.
+    fn extract_scalar_randomizer_circuit<A: circuit::Aleo<Network = N>>(
+        randomizer: circuit::Value<A>,
+    ) -> Result<circuit::Scalar<A>> {
+        match (VARIANT, randomizer) {
+            (
+                0..=5 | 7,
+                circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Scalar(randomizer), ..)),
+            ) => Ok(randomizer),
+            // Note: The field randomizer is reduced into a scalar, consistent with the console extraction.
+            (6, circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(randomizer), ..))) => {
+                Self::reduce_field_randomizer_circuit::<A>(randomizer)
+            }
+            (6, _) => bail!(CommitError::InvalidRandomizerType { phase: "execution", expected: LiteralType::Field }),
+            _ => bail!(CommitError::InvalidRandomizerType { phase: "execution", expected: LiteralType::Scalar }),
+        }
+    }
+
+    /// Returns the given circuit field, reduced modulo the scalar field.
+    ///
+    /// The scalar field modulus is subtracted from the field once for each multiple of the modulus
+    /// that fits in the base field, whenever the (remaining) field is at least the modulus.
+    fn reduce_field_randomizer_circuit<A: circuit::Aleo<Network = N>>(
+        randomizer: circuit::Field<A>,
+    ) -> Result<circuit::Scalar<A>> {
+        use circuit::traits::{Compare, FromField, Inject, Ternary};
+
+        // Compute the scalar field modulus, as a base field element.
+        let modulus = (-Scalar::<N>::one()).to_field()? + Field::<N>::one();
+        // Compute the number of multiples of the modulus in the largest base field element.
+        let mut num_subtractions = 0;
+        let mut remainder = -Field::<N>::one();
+        while remainder >= modulus {
+            remainder -= modulus;
+            num_subtractions += 1;
+        }
+
+        // Subtract the modulus while the field is at least the modulus.
+        let modulus = circuit::Field::constant(modulus);
+        let mut reduced = randomizer;
+        for _ in 0..num_subtractions {
+            let is_at_least_modulus = reduced.is_greater_than_or_equal(&modulus);
+            reduced = circuit::Field::ternary(&is_at_least_modulus, &(&reduced - &modulus), &reduced);
+        }
+        // Cast the reduced field into a scalar, which is guaranteed to be less than the modulus.
+        Ok(circuit::Scalar::from_field(reduced))
+    }
+}
+
 impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
     /// Evaluates the instruction.
     #[inline]
@@ -120,29 +286,34 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
     ) -> Result<()> {
         // Ensure the number of operands is correct.
         if self.operands.len() != 2 {
-            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+            bail!(CommitError::InvalidNumberOfOperands { opcode: Self::opcode(), found: self.operands.len() })
         }
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(self.destination_type), "Invalid destination type in 'commit' instruction");
+        ensure!(is_valid_destination_type(self.destination_type), CommitError::InvalidDestinationType);
 
         // Retrieve the input and randomizer.
         let input = registers.load(stack, &self.operands[0])?;
         let randomizer = registers.load(stack, &self.operands[1])?;
         // Retrieve the randomizer.
-        let randomizer = match randomizer {
-            Value::Plaintext(Plaintext::Literal(Literal::Scalar(randomizer), ..)) => randomizer,
-            _ => bail!("Invalid randomizer type for the commit evaluation, expected a scalar"),
-        };
+        let randomizer = Self::extract_scalar_randomizer(randomizer)?;
+        // Retrieve the preimage, as the little-endian bits of the input.
+        // Note: Evaluation and execution both derive the preimage from `to_bits_le`, so they commit to the same bits.
+        let preimage = input.to_bits_le();
 
         // Commit the input.
         let output = match VARIANT {
-            0 => Literal::Group(N::commit_to_group_bhp256(&input.to_bits_le(), &randomizer)?),
-            1 => Literal::Group(N::commit_to_group_bhp512(&input.to_bits_le(), &randomizer)?),
-            2 => Literal::Group(N::commit_to_group_bhp768(&input.to_bits_le(), &randomizer)?),
-            3 => Literal::Group(N::commit_to_group_bhp1024(&input.to_bits_le(), &randomizer)?),
-            4 => Literal::Group(N::commit_to_group_ped64(&input.to_bits_le(), &randomizer)?),
-            5 => Literal::Group(N::commit_to_group_ped128(&input.to_bits_le(), &randomizer)?),
-            6.. => bail!("Invalid 'commit' variant: {VARIANT}"),
+            0 => Literal::Group(N::commit_to_group_bhp256(&preimage, &randomizer)?),
+            1 => Literal::Group(N::commit_to_group_bhp512(&preimage, &randomizer)?),
+            2 => Literal::Group(N::commit_to_group_bhp768(&preimage, &randomizer)?),
+            3 => Literal::Group(N::commit_to_group_bhp1024(&preimage, &randomizer)?),
+            4 => Literal::Group(N::commit_to_group_ped64(&preimage, &randomizer)?),
+            5 => Literal::Group(N::commit_to_group_ped128(&preimage, &randomizer)?),
+            6 => Literal::Group(N::commit_to_group_bhp256(&preimage, &randomizer)?),
+            7 => {
+                let commitment = N::commit_to_group_bhp256(&preimage, &randomizer)?;
+                Literal::Field(N::hash_psd2(&[commitment.to_x_coordinate(), commitment.to_y_coordinate()])?)
+            }
+            8.. => bail!(CommitError::InvalidVariant(VARIANT)),
         };
         // Cast the output to the destination type.
         let output = output.cast_lossy(self.destination_type)?;
@@ -161,31 +332,34 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
 
         // Ensure the number of operands is correct.
         if self.operands.len() != 2 {
-            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+            bail!(CommitError::InvalidNumberOfOperands { opcode: Self::opcode(), found: self.operands.len() })
         }
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(self.destination_type), "Invalid destination type in 'commit' instruction");
+        ensure!(is_valid_destination_type(self.destination_type), CommitError::InvalidDestinationType);
 
         // Retrieve the input and randomizer.
         let input = registers.load_circuit(stack, &self.operands[0])?;
         let randomizer = registers.load_circuit(stack, &self.operands[1])?;
         // Retrieve the randomizer.
-        let randomizer = match randomizer {
-            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Scalar(randomizer), ..)) => {
-                randomizer
-            }
-            _ => bail!("Invalid randomizer type for the commit execution, expected a scalar"),
-        };
+        let randomizer = Self::extract_scalar_randomizer_circuit::<A>(randomizer)?;
+        // Retrieve the preimage, as the little-endian bits of the input.
+        // Note: Evaluation and execution both derive the preimage from `to_bits_le`, so they commit to the same bits.
+        let preimage = input.to_bits_le();
 
         // Commits the input.
         let output = match VARIANT {
-            0 => circuit::Literal::Group(A::commit_to_group_bhp256(&input.to_bits_le(), &randomizer)),
-            1 => circuit::Literal::Group(A::commit_to_group_bhp512(&input.to_bits_le(), &randomizer)),
-            2 => circuit::Literal::Group(A::commit_to_group_bhp768(&input.to_bits_le(), &randomizer)),
-            3 => circuit::Literal::Group(A::commit_to_group_bhp1024(&input.to_bits_le(), &randomizer)),
-            4 => circuit::Literal::Group(A::commit_to_group_ped64(&input.to_bits_le(), &randomizer)),
-            5 => circuit::Literal::Group(A::commit_to_group_ped128(&input.to_bits_le(), &randomizer)),
-            6.. => bail!("Invalid 'commit' variant: {VARIANT}"),
+            0 => circuit::Literal::Group(A::commit_to_group_bhp256(&preimage, &randomizer)),
+            1 => circuit::Literal::Group(A::commit_to_group_bhp512(&preimage, &randomizer)),
+            2 => circuit::Literal::Group(A::commit_to_group_bhp768(&preimage, &randomizer)),
+            3 => circuit::Literal::Group(A::commit_to_group_bhp1024(&preimage, &randomizer)),
+            4 => circuit::Literal::Group(A::commit_to_group_ped64(&preimage, &randomizer)),
+            5 => circuit::Literal::Group(A::commit_to_group_ped128(&preimage, &randomizer)),
+            6 => circuit::Literal::Group(A::commit_to_group_bhp256(&preimage, &randomizer)),
+            7 => {
+                let commitment = A::commit_to_group_bhp256(&preimage, &randomizer);
+                circuit::Literal::Field(A::hash_psd2(&[commitment.to_x_coordinate(), commitment.to_y_coordinate()]))
+            }
+            8.. => bail!(CommitError::InvalidVariant(VARIANT)),
         };
         let output = output.cast_lossy(self.destination_type)?;
         // Convert the output to a stack value.
@@ -213,22 +387,71 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
     ) -> Result<Vec<RegisterType<N>>> {
         // Ensure the number of input types is correct.
         if input_types.len() != 2 {
-            bail!("Instruction '{}' expects 2 inputs, found {} inputs", Self::opcode(), input_types.len())
+            bail!(CommitError::InvalidNumberOfInputs { opcode: Self::opcode(), found: input_types.len() })
         }
         // Ensure the number of operands is correct.
         if self.operands.len() != 2 {
-            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+            bail!(CommitError::InvalidNumberOfOperands { opcode: Self::opcode(), found: self.operands.len() })
         }
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(self.destination_type), "Invalid destination type in 'commit' instruction");
-
-        // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
+        ensure!(is_valid_destination_type(self.destination_type), CommitError::InvalidDestinationType);
+
+        // Ensure the randomizer of `commit.bhp256.field` is a field.
+        if VARIANT == 6 && input_types[1] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field)) {
+            bail!(CommitError::ExpectedFieldRandomizer {
+                opcode: Self::opcode(),
+                randomizer_type: input_types[1].to_string()
+            })
+        }
 
         match VARIANT {
-            0..=5 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(self.destination_type))]),
-            6.. => bail!("Invalid 'commit' variant: {VARIANT}"),
+            0..=7 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(self.destination_type))]),
+            8.. => bail!(CommitError::InvalidVariant(VARIANT)),
         }
     }
+
+    /// Ensures the given input types satisfy the checks that only apply to new deployments.
+    ///
+    /// These checks postdate programs that are already deployed, so they are not part of `output_types`,
+    /// which must still accept those programs when they are reloaded.
+    pub fn check_deployment_types(&self, stack: &impl StackProgram<N>, input_types: &[RegisterType<N>]) -> Result<()> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 2 {
+            bail!(CommitError::InvalidNumberOfInputs { opcode: Self::opcode(), found: input_types.len() })
+        }
+
+        // If the operation is Pedersen, ensure the input is within the number of bits.
+        let max_num_bits = match VARIANT {
+            4 => Some(64),
+            5 => Some(128),
+            _ => None,
+        };
+        if let Some(max_num_bits) = max_num_bits {
+            match &input_types[0] {
+                RegisterType::Plaintext(plaintext_type) => {
+                    let num_bits = plaintext_size_in_bits(stack, plaintext_type)?;
+                    ensure!(num_bits <= max_num_bits, CommitError::InputTooLarge {
+                        opcode: Self::opcode(),
+                        max_num_bits,
+                        input_type: plaintext_type.to_string(),
+                        num_bits,
+                    });
+                }
+                input_type => {
+                    bail!(CommitError::NonPlaintextInput { opcode: Self::opcode(), input_type: input_type.to_string() })
+                }
+            }
+        }
+
+        // Ensure the randomizer of the other committers is a scalar.
+        if VARIANT != 6 && input_types[1] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Scalar)) {
+            bail!(CommitError::ExpectedScalarRandomizer {
+                opcode: Self::opcode(),
+                randomizer_type: input_types[1].to_string()
+            })
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network, const VARIANT: u8> Parser for CommitInstruction<N, VARIANT> {
@@ -253,14 +476,14 @@ impl<N: Network, const VARIANT: u8> Parser for CommitInstruction<N, VARIANT> {
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the destination register from the string.
         let (string, destination) = Register::parse(string)?;
-        // Parse the whitespace from the string.
-        let (string, _) = Sanitizer::parse_whitespaces(string)?;
-        // Parse the "as" from the string.
-        let (string, _) = tag("as")(string)?;
-        // Parse the whitespace from the string.
-        let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the destination register type from the string.
-        let (string, destination_type) = LiteralType::parse(string)?;
+        let (string, destination_type) = match VARIANT {
+            // Note: The destination type of `commit.bhp256.hashed` is optional, and defaults to a field.
+            7 => map(opt(Self::parse_destination_type), |destination_type| {
+                destination_type.unwrap_or(LiteralType::Field)
+            })(string)?,
+            _ => Self::parse_destination_type(string)?,
+        };
         // Ensure the destination type is allowed.
         match destination_type {
             LiteralType::Address | LiteralType::Field | LiteralType::Group => {
@@ -273,6 +496,20 @@ impl<N: Network, const VARIANT: u8> Parser for CommitInstruction<N, VARIANT> {
     }
 }
 
+impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
+    /// Parses the destination register type, as `as {destination_type}`, from the string.
+    fn parse_destination_type(string: &str) -> ParserResult<LiteralType> {
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register type from the string.
+        LiteralType::parse(string)
+    }
+}
+
 impl<N: Network, const VARIANT: u8> FromStr for CommitInstruction<N, VARIANT> {
     type Err = Error;
 
@@ -315,6 +552,13 @@ impl<N: Network, const VARIANT: u8> Display for CommitInstruction<N, VARIANT> {
 impl<N: Network, const VARIANT: u8> FromBytes for CommitInstruction<N, VARIANT> {
     /// Reads the operation from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the format tag, if the variant is tagged.
+        if is_tagged_variant(VARIANT) {
+            let tag = u8::read_le(&mut reader)?;
+            if tag != COMMIT_FORMAT_V1 {
+                return Err(error(format!("Invalid 'commit' format tag: {tag}")));
+            }
+        }
         // Initialize the vector for the operands.
         let mut operands = Vec::with_capacity(2);
         // Read the operands.
@@ -338,6 +582,10 @@ impl<N: Network, const VARIANT: u8> ToBytes for CommitInstruction<N, VARIANT> {
         if self.operands.len() != 2 {
             return Err(error(format!("The number of operands must be 2, found {}", self.operands.len())));
         }
+        // Write the format tag, if the variant is tagged.
+        if is_tagged_variant(VARIANT) {
+            COMMIT_FORMAT_V1.write_le(&mut writer)?;
+        }
         // Write the operands.
         self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
         // Write the destination register.
@@ -372,4 +620,103 @@ mod tests {
             assert_eq!(commit.destination_type, *destination_type, "The destination type is incorrect");
         }
     }
+
+    #[test]
+    fn test_parse_hashed() {
+        // Ensure the destination type defaults to a field.
+        let (string, commit) =
+            CommitBHP256Hashed::<CurrentNetwork>::parse("commit.bhp256.hashed r0 r1 into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(commit.destination, Register::Locator(2), "The destination register is incorrect");
+        assert_eq!(commit.destination_type, LiteralType::Field, "The destination type is incorrect");
+        assert_eq!(commit.to_string(), "commit.bhp256.hashed r0 r1 into r2 as field");
+
+        // Ensure an explicit destination type is still supported.
+        let (string, commit) =
+            CommitBHP256Hashed::<CurrentNetwork>::parse("commit.bhp256.hashed r0 r1 into r2 as group").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(commit.destination_type, LiteralType::Group, "The destination type is incorrect");
+
+        // Ensure the destination type is required for the other variants.
+        assert!(CommitBHP256::<CurrentNetwork>::from_str("commit.bhp256 r0 r1 into r2").is_err());
+    }
+
+    #[test]
+    fn test_parse_literal_randomizer() {
+        let (string, commit) =
+            CommitBHP256::<CurrentNetwork>::parse("commit.bhp256 r0 5scalar into r1 as group").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(commit.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(
+            commit.operands[1],
+            Operand::Literal(Literal::Scalar(Scalar::from_str("5scalar").unwrap())),
+            "The second operand is incorrect"
+        );
+        assert_eq!(commit.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(commit.to_string(), "commit.bhp256 r0 5scalar into r1 as group");
+
+        // Ensure the literal randomizer round-trips through bytes.
+        let bytes = commit.to_bytes_le().unwrap();
+        assert_eq!(commit, CommitBHP256::<CurrentNetwork>::from_bytes_le(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_bytes_format_tag() {
+        for string in ["commit.bhp256.field r0 r1 into r2 as group", "commit.bhp256.field r0 5field into r1 as field"] {
+            let commit = CommitBHP256Field::<CurrentNetwork>::from_str(string).unwrap();
+
+            // Ensure the encoding of a new variant begins with the format tag, and round-trips.
+            let bytes = commit.to_bytes_le().unwrap();
+            assert_eq!(bytes[0], COMMIT_FORMAT_V1);
+            assert_eq!(commit, CommitBHP256Field::<CurrentNetwork>::from_bytes_le(&bytes).unwrap());
+
+            // Ensure an untagged encoding is rejected.
+            assert!(CommitBHP256Field::<CurrentNetwork>::from_bytes_le(&bytes[1..]).is_err());
+        }
+
+        // Ensure an unknown format tag is rejected.
+        let commit =
+            CommitBHP256Field::<CurrentNetwork>::from_str("commit.bhp256.field r0 r1 into r2 as group").unwrap();
+        let mut bytes = commit.to_bytes_le().unwrap();
+        bytes[0] = COMMIT_FORMAT_V1 + 1;
+        assert!(CommitBHP256Field::<CurrentNetwork>::from_bytes_le(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_bytes_legacy_round_trip() {
+        for string in ["commit.bhp256 r0 r1 into r2 as group", "commit.bhp256 r0 5scalar into r1 as field"] {
+            let commit = CommitBHP256::<CurrentNetwork>::from_str(string).unwrap();
+
+            // Construct the legacy (untagged) encoding by hand.
+            let mut legacy_bytes = Vec::new();
+            commit.operands.iter().for_each(|operand| operand.write_le(&mut legacy_bytes).unwrap());
+            commit.destination.write_le(&mut legacy_bytes).unwrap();
+            commit.destination_type.write_le(&mut legacy_bytes).unwrap();
+
+            // Ensure the legacy encoding round-trips byte-for-byte.
+            let candidate = CommitBHP256::<CurrentNetwork>::from_bytes_le(&legacy_bytes).unwrap();
+            assert_eq!(commit, candidate);
+            assert_eq!(legacy_bytes, candidate.to_bytes_le().unwrap());
+
+            // Ensure a tagged encoding of a legacy variant is rejected.
+            let tagged_bytes = [&[COMMIT_FORMAT_V1], legacy_bytes.as_slice()].concat();
+            assert!(CommitBHP256::<CurrentNetwork>::from_bytes_le(&tagged_bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let expected = "commit.bhp256 r0 r1 into r2 as group";
+        for string in [
+            "commit.bhp256 r0 r1 into r2 as group",
+            "commit.bhp256  r0   r1  into  r2  as  group",
+            "commit.bhp256\tr0\nr1 \t into\nr2 as\tgroup",
+        ] {
+            let commit = CommitBHP256::<CurrentNetwork>::from_str(string).unwrap();
+            // Ensure the display is canonical, regardless of the whitespace in the parsed string.
+            assert_eq!(commit.to_string(), expected);
+            // Ensure the display reparses to an equal instruction.
+            assert_eq!(commit, CommitBHP256::<CurrentNetwork>::from_str(&commit.to_string()).unwrap());
+        }
+    }
 }
@@ -20,7 +20,7 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    program::{ArrayType, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
 };
 
 /// BHP256 is a collision-resistant hash function that processes inputs in 256-bit chunks.
@@ -65,6 +65,20 @@ pub type HashManyPSD4<N> = HashInstruction<N, { Hasher::HashManyPSD4 as u8 }>;
 /// Poseidon8 is a cryptographic hash function that processes inputs in 8-field chunks.
 pub type HashManyPSD8<N> = HashInstruction<N, { Hasher::HashManyPSD8 as u8 }>;
 
+/// Poseidon2 hashes the input into a scalar, processing it in 2-field chunks.
+///
+/// Note: Account signatures derive their challenge with the Poseidon8 variant, and are unaffected by this opcode.
+pub type HashToScalarPSD2<N> = HashInstruction<N, { Hasher::HashToScalarPSD2 as u8 }>;
+/// Poseidon4 hashes the input into a scalar, processing it in 4-field chunks.
+///
+/// Note: Account signatures derive their challenge with the Poseidon8 variant, and are unaffected by this opcode.
+pub type HashToScalarPSD4<N> = HashInstruction<N, { Hasher::HashToScalarPSD4 as u8 }>;
+
+/// Poseidon8 hashes the input onto the curve, outputting a group element with no known discrete logarithm.
+///
+/// This is equivalent to `hash.psd8 r0 into r1 as group`, and always outputs a `group`.
+pub type HashToGroupPSD8<N> = HashInstruction<N, { Hasher::HashToGroupPSD8 as u8 }>;
+
 enum Hasher {
     HashBHP256,
     HashBHP512,
@@ -84,6 +98,9 @@ enum Hasher {
     HashManyPSD2,
     HashManyPSD4,
     HashManyPSD8,
+    HashToScalarPSD2,
+    HashToScalarPSD4,
+    HashToGroupPSD8,
 }
 
 /// Returns the expected number of operands given the variant.
@@ -104,8 +121,40 @@ fn check_number_of_operands(variant: u8, opcode: Opcode, num_operands: usize) ->
     Ok(())
 }
 
+/// Returns 'true' if the variant is `hash_many`, which hashes into an array of fields.
+const fn is_hash_many(variant: u8) -> bool {
+    variant >= Hasher::HashManyPSD2 as u8 && variant <= Hasher::HashManyPSD8 as u8
+}
+
+/// Returns 'true' if the destination type is declared in the instruction, as opposed to implied by the variant.
+const fn has_declared_destination_type(variant: u8) -> bool {
+    variant != Hasher::HashToGroupPSD8 as u8 && !is_hash_many(variant)
+}
+
+/// Returns the destination type of a `hash_many` instruction, given its number of outputs.
+/// The destination is an array of fields, whose length is the number of outputs, declared as a constant `u32`.
+fn hash_many_destination_type<N: Network>(opcode: Opcode, num_outputs: &Operand<N>) -> Result<PlaintextType<N>> {
+    match num_outputs {
+        Operand::Literal(Literal::U32(num_outputs)) => {
+            Ok(PlaintextType::Array(ArrayType::new(PlaintextType::Literal(LiteralType::Field), vec![*num_outputs])?))
+        }
+        operand => bail!("Instruction '{opcode}' expects a constant 'u32' number of outputs, found '{operand}'"),
+    }
+}
+
 /// Returns 'true' if the destination type is valid.
-fn is_valid_destination_type<N: Network>(destination_type: &PlaintextType<N>) -> bool {
+fn is_valid_destination_type<N: Network>(variant: u8, destination_type: &PlaintextType<N>) -> bool {
+    if is_hash_many(variant) {
+        return match destination_type {
+            PlaintextType::Array(array_type) => {
+                array_type.next_element_type() == &PlaintextType::Literal(LiteralType::Field)
+            }
+            _ => false,
+        };
+    }
+    if !has_declared_destination_type(variant) {
+        return destination_type == &PlaintextType::Literal(LiteralType::Group);
+    }
     !matches!(
         destination_type,
         PlaintextType::Literal(LiteralType::Boolean)
@@ -137,7 +186,7 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Sanity check the number of operands.
         check_number_of_operands(VARIANT, Self::opcode(), operands.len())?;
         // Sanity check the destination type.
-        if !is_valid_destination_type(&destination_type) {
+        if !is_valid_destination_type(VARIANT, &destination_type) {
             bail!("Invalid destination type for 'hash' instruction")
         }
         // Return the instruction.
@@ -166,7 +215,10 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             15 => Opcode::Hash("hash_many.psd2"),
             16 => Opcode::Hash("hash_many.psd4"),
             17 => Opcode::Hash("hash_many.psd8"),
-            18.. => panic!("Invalid 'hash' instruction opcode"),
+            18 => Opcode::Hash("hash_to_scalar.psd2"),
+            19 => Opcode::Hash("hash_to_scalar.psd4"),
+            20 => Opcode::Hash("hash_to_group.psd8"),
+            21.. => panic!("Invalid 'hash' instruction opcode"),
         }
     }
 
@@ -194,6 +246,14 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
     pub const fn destination_type(&self) -> &PlaintextType<N> {
         &self.destination_type
     }
+
+    /// Returns the number of outputs of a `hash_many` instruction, which is declared as a constant `u32` operand.
+    fn num_outputs(&self) -> Result<u16> {
+        match self.operands.get(1) {
+            Some(Operand::Literal(Literal::U32(num_outputs))) => Ok(u16::try_from(**num_outputs)?),
+            _ => bail!("Instruction '{}' expects a constant 'u32' number of outputs", Self::opcode()),
+        }
+    }
 }
 
 impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
@@ -207,10 +267,28 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Ensure the number of operands is correct.
         check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
+        ensure!(
+            is_valid_destination_type(VARIANT, &self.destination_type),
+            "Invalid destination type in 'hash' instruction"
+        );
 
         // Load the operand.
         let input = registers.load(stack, &self.operands[0])?;
+
+        // If the variant is `hash_many`, hash the input into an array of fields.
+        if is_hash_many(VARIANT) {
+            let num_outputs = self.num_outputs()?;
+            let outputs = match VARIANT {
+                15 => N::hash_many_psd2(&input.to_fields()?, num_outputs),
+                16 => N::hash_many_psd4(&input.to_fields()?, num_outputs),
+                _ => N::hash_many_psd8(&input.to_fields()?, num_outputs),
+            };
+            let elements = outputs.into_iter().map(|output| Plaintext::from(Literal::Field(output))).collect();
+            let output = Value::Plaintext(Plaintext::Array(elements, Default::default()));
+            // Store the output.
+            return registers.store(stack, &self.destination, output);
+        }
+
         // Hash the input.
         let output = match (VARIANT, &self.destination_type) {
             (0, PlaintextType::Literal(..)) => Literal::Group(N::hash_to_group_bhp256(&input.to_bits_le())?),
@@ -249,10 +327,11 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (14, PlaintextType::Literal(..)) => {
                 Literal::Group(N::hash_to_group_bhp512(&N::hash_sha3_512(&input.to_bits_le())?)?)
             }
-            (15, _) => bail!("'hash_many.psd2' is not yet implemented"),
-            (16, _) => bail!("'hash_many.psd4' is not yet implemented"),
-            (17, _) => bail!("'hash_many.psd8' is not yet implemented"),
-            (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
+            (15..=17, _) => bail!("Instruction '{}' hashes into an array of fields", Self::opcode()),
+            (18, PlaintextType::Literal(..)) => Literal::Scalar(N::hash_to_scalar_psd2(&input.to_fields()?)?),
+            (19, PlaintextType::Literal(..)) => Literal::Scalar(N::hash_to_scalar_psd4(&input.to_fields()?)?),
+            (20, PlaintextType::Literal(..)) => Literal::Group(N::hash_to_group_psd8(&input.to_fields()?)?),
+            (21.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
         };
@@ -278,10 +357,31 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Ensure the number of operands is correct.
         check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
+        ensure!(
+            is_valid_destination_type(VARIANT, &self.destination_type),
+            "Invalid destination type in 'hash' instruction"
+        );
 
         // Load the operand.
         let input = registers.load_circuit(stack, &self.operands[0])?;
+
+        // If the variant is `hash_many`, hash the input into an array of fields.
+        if is_hash_many(VARIANT) {
+            let num_outputs = self.num_outputs()?;
+            let outputs = match VARIANT {
+                15 => A::hash_many_psd2(&input.to_fields(), num_outputs),
+                16 => A::hash_many_psd4(&input.to_fields(), num_outputs),
+                _ => A::hash_many_psd8(&input.to_fields(), num_outputs),
+            };
+            let elements = outputs
+                .into_iter()
+                .map(|output| circuit::Plaintext::Literal(circuit::Literal::Field(output), Default::default()))
+                .collect();
+            let output = circuit::Value::Plaintext(circuit::Plaintext::Array(elements, Default::default()));
+            // Store the output.
+            return registers.store_circuit(stack, &self.destination, output);
+        }
+
         // Hash the input.
         let output = match (VARIANT, &self.destination_type) {
             (0, PlaintextType::Literal(..)) => circuit::Literal::Group(A::hash_to_group_bhp256(&input.to_bits_le())),
@@ -320,10 +420,11 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (14, PlaintextType::Literal(..)) => {
                 circuit::Literal::Group(A::hash_to_group_bhp512(&A::hash_sha3_512(&input.to_bits_le())))
             }
-            (15, _) => bail!("'hash_many.psd2' is not yet implemented"),
-            (16, _) => bail!("'hash_many.psd4' is not yet implemented"),
-            (17, _) => bail!("'hash_many.psd8' is not yet implemented"),
-            (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
+            (15..=17, _) => bail!("Instruction '{}' hashes into an array of fields", Self::opcode()),
+            (18, PlaintextType::Literal(..)) => circuit::Literal::Scalar(A::hash_to_scalar_psd2(&input.to_fields())),
+            (19, PlaintextType::Literal(..)) => circuit::Literal::Scalar(A::hash_to_scalar_psd4(&input.to_fields())),
+            (20, PlaintextType::Literal(..)) => circuit::Literal::Group(A::hash_to_group_psd8(&input.to_fields())),
+            (21.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
         };
@@ -361,14 +462,36 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Ensure the number of operands is correct.
         check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
+        ensure!(
+            is_valid_destination_type(VARIANT, &self.destination_type),
+            "Invalid destination type in 'hash' instruction"
+        );
 
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
         match VARIANT {
-            0..=14 => Ok(vec![RegisterType::Plaintext(self.destination_type.clone())]),
-            15..=17 => bail!("'hash_many' is not yet implemented"),
-            18.. => bail!("Invalid 'hash' variant: {VARIANT}"),
+            0..=14 | 18..=20 => Ok(vec![RegisterType::Plaintext(self.destination_type.clone())]),
+            15..=17 => {
+                // Ensure the number of outputs is a `u32`.
+                let num_outputs_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32));
+                if input_types[1] != num_outputs_type {
+                    bail!(
+                        "Instruction '{}' expects a 'u32' number of outputs, found '{}'",
+                        Self::opcode(),
+                        input_types[1]
+                    )
+                }
+                // Ensure the destination type matches the constant number of outputs.
+                let destination_type = hash_many_destination_type(Self::opcode(), &self.operands[1])?;
+                ensure!(
+                    destination_type == self.destination_type,
+                    "Instruction '{}' expects a destination of type '{destination_type}', found '{}'",
+                    Self::opcode(),
+                    self.destination_type
+                );
+                Ok(vec![RegisterType::Plaintext(destination_type)])
+            }
+            21.. => bail!("Invalid 'hash' variant: {VARIANT}"),
         }
     }
 }
@@ -408,6 +531,19 @@ impl<N: Network, const VARIANT: u8> Parser for HashInstruction<N, VARIANT> {
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the destination register from the string.
         let (string, destination) = Register::parse(string)?;
+        // If the variant is `hash_many`, derive the destination type from the number of outputs.
+        if is_hash_many(VARIANT) {
+            let destination_type = hash_many_destination_type(Self::opcode(), &operands[1]);
+            return match destination_type {
+                Ok(destination_type) => Ok((string, Self { operands, destination, destination_type })),
+                Err(e) => map_res(fail, |_: ParserResult<Self>| Err(error(format!("Failed to parse: {e}"))))(string),
+            };
+        }
+        // If the destination type is implied by the variant, return the instruction.
+        if !has_declared_destination_type(VARIANT) {
+            let destination_type = PlaintextType::Literal(LiteralType::Group);
+            return Ok((string, Self { operands, destination, destination_type }));
+        }
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the "as" from the string.
@@ -461,7 +597,10 @@ impl<N: Network, const VARIANT: u8> Display for HashInstruction<N, VARIANT> {
         // Print the operation.
         write!(f, "{} ", Self::opcode())?;
         self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
-        write!(f, "into {} as {}", self.destination, self.destination_type)
+        match has_declared_destination_type(VARIANT) {
+            true => write!(f, "into {} as {}", self.destination, self.destination_type),
+            false => write!(f, "into {}", self.destination),
+        }
     }
 }
 
@@ -471,11 +610,17 @@ impl<N: Network, const VARIANT: u8> FromBytes for HashInstruction<N, VARIANT> {
         // Prepare the number of operands.
         let num_operands = expected_num_operands(VARIANT);
         // Read the operands.
-        let operands = (0..num_operands).map(|_| Operand::read_le(&mut reader)).collect::<Result<_, _>>()?;
+        let operands = (0..num_operands).map(|_| Operand::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
         // Read the destination register.
         let destination = Register::read_le(&mut reader)?;
         // Read the destination register type.
-        let destination_type = PlaintextType::read_le(&mut reader)?;
+        let destination_type = match has_declared_destination_type(VARIANT) {
+            true => PlaintextType::read_le(&mut reader)?,
+            false if is_hash_many(VARIANT) => {
+                hash_many_destination_type(Self::opcode(), &operands[1]).map_err(|e| error(format!("{e}")))?
+            }
+            false => PlaintextType::Literal(LiteralType::Group),
+        };
         // Return the operation.
         Ok(Self { operands, destination, destination_type })
     }
@@ -491,7 +636,10 @@ impl<N: Network, const VARIANT: u8> ToBytes for HashInstruction<N, VARIANT> {
         // Write the destination register.
         self.destination.write_le(&mut writer)?;
         // Write the destination register type.
-        self.destination_type.write_le(&mut writer)
+        match has_declared_destination_type(VARIANT) {
+            true => self.destination_type.write_le(&mut writer),
+            false => Ok(()),
+        }
     }
 }
 
@@ -534,4 +682,65 @@ mod tests {
             assert_eq!(&hash.destination_type, destination_type, "The destination type is incorrect");
         }
     }
+
+    #[test]
+    fn test_parse_psd() {
+        let (string, hash) = HashPSD2::<CurrentNetwork>::parse("hash.psd2 r0 into r1 as field").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.operands, vec![Operand::Register(Register::Locator(0))], "The operands are incorrect");
+        assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(
+            hash.destination_type,
+            PlaintextType::Literal(LiteralType::Field),
+            "The destination type is incorrect"
+        );
+
+        // Ensure each Poseidon variant only parses its own opcode.
+        assert!(HashPSD4::<CurrentNetwork>::from_str("hash.psd4 r0 into r1 as field").is_ok());
+        assert!(HashPSD8::<CurrentNetwork>::from_str("hash.psd8 r0 into r1 as field").is_ok());
+        assert!(HashPSD2::<CurrentNetwork>::from_str("hash.psd4 r0 into r1 as field").is_err());
+        assert!(HashPSD4::<CurrentNetwork>::from_str("hash.psd8 r0 into r1 as field").is_err());
+    }
+
+    #[test]
+    fn test_parse_hash_to_group() {
+        let (string, hash) = HashToGroupPSD8::<CurrentNetwork>::parse("hash_to_group.psd8 r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.operands, vec![Operand::Register(Register::Locator(0))], "The operands are incorrect");
+        assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(
+            hash.destination_type,
+            PlaintextType::Literal(LiteralType::Group),
+            "The destination type is incorrect"
+        );
+        assert_eq!(hash.to_string(), "hash_to_group.psd8 r0 into r1");
+        assert_eq!(hash, HashToGroupPSD8::from_bytes_le(&hash.to_bytes_le().unwrap()).unwrap());
+
+        // Ensure a declared destination type is rejected.
+        assert!(HashToGroupPSD8::<CurrentNetwork>::from_str("hash_to_group.psd8 r0 into r1 as group").is_err());
+    }
+
+    #[test]
+    fn test_parse_hash_many() {
+        let (string, hash) = HashManyPSD2::<CurrentNetwork>::parse("hash_many.psd2 r0 4u32 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(hash.operands[1], Operand::from_str("4u32").unwrap(), "The second operand is incorrect");
+        assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(
+            hash.destination_type,
+            PlaintextType::from_str("[field; 4u32]").unwrap(),
+            "The destination type is incorrect"
+        );
+        assert_eq!(hash.to_string(), "hash_many.psd2 r0 4u32 into r1");
+        assert_eq!(hash, HashManyPSD2::from_bytes_le(&hash.to_bytes_le().unwrap()).unwrap());
+
+        // Ensure the number of outputs must be a constant `u32`.
+        assert!(HashManyPSD2::<CurrentNetwork>::from_str("hash_many.psd2 r0 r1 into r2").is_err());
+        assert!(HashManyPSD2::<CurrentNetwork>::from_str("hash_many.psd2 r0 4u8 into r1").is_err());
+        assert!(HashManyPSD2::<CurrentNetwork>::from_str("hash_many.psd2 r0 0u32 into r1").is_err());
+        // Ensure a declared destination type is rejected.
+        assert!(HashManyPSD2::<CurrentNetwork>::from_str("hash_many.psd2 r0 4u32 into r1 as [field; 4u32]").is_err());
+    }
 }
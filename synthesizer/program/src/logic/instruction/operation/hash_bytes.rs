@@ -0,0 +1,291 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{ArrayType, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{U32, U8},
+};
+
+use sha2::{Digest, Sha256};
+
+/// SHA-256 hashes the bytes of the operand into a 32-byte digest.
+pub type HashBytesSha256<N> = HashBytesInstruction<N, { ByteHasher::Sha256 as u8 }>;
+/// Keccak256 hashes the bytes of the operand into a 32-byte digest.
+pub type HashBytesKeccak256<N> = HashBytesInstruction<N, { ByteHasher::Keccak256 as u8 }>;
+
+enum ByteHasher {
+    Sha256,
+    Keccak256,
+}
+
+/// The number of bytes in the digest.
+const DIGEST_SIZE_IN_BYTES: u32 = 32;
+
+/// Returns the digest type, i.e. `[u8; 32u32]`.
+fn digest_type<N: Network>() -> Result<PlaintextType<N>> {
+    Ok(PlaintextType::Array(ArrayType::new(PlaintextType::Literal(LiteralType::U8), vec![U32::new(
+        DIGEST_SIZE_IN_BYTES,
+    )])?))
+}
+
+/// Hashes the `u8` array operand into a `[u8; 32u32]` digest with a native hash function, storing the outcome
+/// in `destination`.
+///
+/// The digest matches that of the same hash function in external, non-zk systems. As the digest is computed
+/// natively and is not supported in circuit, the instruction may only be used in `finalize`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct HashBytesInstruction<N: Network, const VARIANT: u8> {
+    /// The operand.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> HashBytesInstruction<N, VARIANT> {
+    /// Initializes a new `hash_bytes` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Hash("hash_bytes.sha256"),
+            1 => Opcode::Hash("hash_bytes.keccak256"),
+            2.. => panic!("Invalid 'hash_bytes' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there is exactly one operand.
+        debug_assert!(self.operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> HashBytesInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the input bytes.
+        let input = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(Plaintext::Array(elements, _)) => elements
+                .iter()
+                .map(|element| match element {
+                    Plaintext::Literal(Literal::U8(byte), _) => Ok(**byte),
+                    _ => bail!("Instruction '{}' expects a u8 array", Self::opcode()),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("Instruction '{}' expects a u8 array", Self::opcode()),
+        };
+
+        // Hash the input bytes.
+        let digest: Vec<u8> = match VARIANT {
+            0 => Sha256::digest(&input).to_vec(),
+            1 => Vec::<u8>::from_bits_le(&N::hash_keccak256(&input.to_bits_le())?)?,
+            _ => bail!("Invalid 'hash_bytes' variant: {VARIANT}"),
+        };
+        ensure!(
+            digest.len() == DIGEST_SIZE_IN_BYTES as usize,
+            "Instruction '{}' produced a malformed digest",
+            Self::opcode()
+        );
+
+        // Store the output.
+        let output = digest.into_iter().map(|byte| Plaintext::from(Literal::U8(U8::new(byte)))).collect();
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::Array(output, Default::default())))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        bail!("Instruction '{}' is not supported in circuit", Self::opcode())
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 1 {
+            bail!("Instruction '{}' expects 1 input, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the input is a u8 array.
+        match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Array(array_type))
+                if array_type.next_element_type() == &PlaintextType::Literal(LiteralType::U8) => {}
+            input_type => bail!("Instruction '{}' expects a u8 array, found '{input_type}'", Self::opcode()),
+        }
+
+        Ok(vec![RegisterType::Plaintext(digest_type()?)])
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for HashBytesInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first], destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for HashBytesInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for HashBytesInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for HashBytesInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for HashBytesInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(1);
+        // Read the operands.
+        for _ in 0..1 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for HashBytesInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(error(format!("The number of operands must be 1, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, instruction) = HashBytesSha256::<CurrentNetwork>::parse("hash_bytes.sha256 r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(instruction.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(instruction.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(instruction.destination, Register::Locator(1), "The destination register is incorrect");
+    }
+}
@@ -0,0 +1,331 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Field,
+};
+
+/// Looks up the element at index `first` in the table `second`, storing the outcome in `destination`.
+///
+/// The index must be a `u8`, `u16`, or `u32`, and the table must be an array of literals (other than strings).
+/// Unlike `get`, the lookup is executed as a multiplexer over every entry of the table, so its cost depends
+/// only on the length of the table, which is fixed by its type. As an array cannot be written as an operand,
+/// the table is always loaded from a register, and it need not be a constant: a table of constants only makes
+/// the multiplexer cheaper to synthesize.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Lookup<N: Network> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> Lookup<N> {
+    /// Initializes a new `lookup` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Literal("lookup")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly two operands.
+        debug_assert!(self.operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> Lookup<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the index.
+        let index = match registers.load_literal(stack, &self.operands[0])? {
+            Literal::U8(index) => *index as usize,
+            Literal::U16(index) => *index as usize,
+            Literal::U32(index) => *index as usize,
+            literal => {
+                bail!("Instruction '{}' expects a u8, u16, or u32 index, found '{}'", Self::opcode(), literal.to_type())
+            }
+        };
+        // Retrieve the table.
+        let table = match registers.load(stack, &self.operands[1])? {
+            Value::Plaintext(Plaintext::Array(table, _)) => table,
+            _ => bail!("Instruction '{}' expects an array table", Self::opcode()),
+        };
+
+        // Retrieve the element at the index.
+        let output = match table.get(index) {
+            Some(Plaintext::Literal(literal, _)) => literal.clone(),
+            Some(_) => bail!("Instruction '{}' expects a table of literals", Self::opcode()),
+            None => {
+                bail!("Instruction '{}' index {index} is out of bounds for a table of {}", Self::opcode(), table.len())
+            }
+        };
+        // Store the output.
+        registers.store_literal(stack, &self.destination, output)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::{
+            traits::{Equal, FromBits, Inject, Ternary, ToBits},
+            Plaintext,
+            Value,
+        };
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the index, as a field element.
+        let index = match registers.load_literal_circuit(stack, &self.operands[0])? {
+            literal @ (circuit::Literal::U8(..) | circuit::Literal::U16(..) | circuit::Literal::U32(..)) => {
+                circuit::Field::from_bits_le(&literal.to_bits_le())
+            }
+            literal => {
+                bail!("Instruction '{}' expects a u8, u16, or u32 index, found '{}'", Self::opcode(), literal.to_type())
+            }
+        };
+        // Retrieve the table.
+        let table = match registers.load_circuit(stack, &self.operands[1])? {
+            Value::Plaintext(Plaintext::Array(table, _)) => table
+                .into_iter()
+                .map(|element| match element {
+                    Plaintext::Literal(literal, _) => Ok(literal),
+                    _ => bail!("Instruction '{}' expects a table of literals", Self::opcode()),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("Instruction '{}' expects an array table", Self::opcode()),
+        };
+        // Retrieve the first element of the table, which is selected by default.
+        let mut output = match table.first() {
+            Some(first) => first.clone(),
+            None => bail!("Instruction '{}' expects a non-empty table", Self::opcode()),
+        };
+
+        // Select the element at the index, with a multiplexer over every entry of the table.
+        let mut is_in_bounds = circuit::Boolean::constant(false);
+        for (i, element) in table.iter().enumerate() {
+            let is_selected = index.is_equal(&circuit::Field::constant(Field::from_u64(i as u64)));
+            output = circuit::Literal::ternary(&is_selected, element, &output);
+            is_in_bounds |= is_selected;
+        }
+        // Ensure the index is within the bounds of the table.
+        A::assert(is_in_bounds);
+
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, output)
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 2 {
+            bail!("Instruction '{}' expects 2 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the first operand is a valid index.
+        match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U8 | LiteralType::U16 | LiteralType::U32)) => {}
+            input_type => {
+                bail!("Instruction '{}' expects a u8, u16, or u32 index, found '{input_type}'", Self::opcode())
+            }
+        }
+
+        // Ensure the second operand is an array of literals, and return the element type.
+        match &input_types[1] {
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => match array_type.next_element_type() {
+                PlaintextType::Literal(LiteralType::String) => {
+                    bail!("Instruction '{}' does not support tables of strings", Self::opcode())
+                }
+                PlaintextType::Literal(literal_type) => {
+                    Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(*literal_type))])
+                }
+                _ => bail!("Instruction '{}' expects a table of literals, found '{}'", Self::opcode(), input_types[1]),
+            },
+            input_type => bail!("Instruction '{}' expects an array table, found '{input_type}'", Self::opcode()),
+        }
+    }
+}
+
+impl<N: Network> Parser for Lookup<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second], destination }))
+    }
+}
+
+impl<N: Network> FromStr for Lookup<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Lookup<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Lookup<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for Lookup<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(2);
+        // Read the operands.
+        for _ in 0..2 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for Lookup<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(error(format!("The number of operands must be 2, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, instruction) = Lookup::<CurrentNetwork>::parse("lookup r0 r1 into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(instruction.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(instruction.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(instruction.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(instruction.destination, Register::Locator(2), "The destination register is incorrect");
+    }
+}
@@ -755,6 +755,7 @@ mod tests {
                         macro_rules! check_condition {
                             ("ensure overflows halt") => {
                                 match *<$operation as $crate::Operation<_, _, _, 2>>::OPCODE {
+                                    "abs_diff" => should_succeed &= (*a).max(*b).checked_sub((*a).min(*b)).is_some(),
                                     "add" => should_succeed &= (*a).checked_add(*b).is_some(),
                                     "div" => should_succeed &= (*a).checked_div(*b).is_some(),
                                     "mul" => should_succeed &= (*a).checked_mul(*b).is_some(),
@@ -0,0 +1,299 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::merkle_node::MERKLE_NODE_DOMAIN;
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Field,
+};
+
+/// Computes the root of the Poseidon Merkle tree with the leaves in the field array `first`, storing the outcome in `destination`.
+///
+/// The leaves are padded with `0field` up to the next power of two, and each level is compressed as in `merkle.node`,
+/// so every leaf can be proven against the root with `merkle.verify`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MerkleRootPSD2<N: Network> {
+    /// The operand.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> MerkleRootPSD2<N> {
+    /// Initializes a new `merkle.root.psd2` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Hash("merkle.root.psd2")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there is exactly one operand.
+        debug_assert!(self.operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> MerkleRootPSD2<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the leaves.
+        let mut nodes = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(Plaintext::Array(leaves, _)) => leaves
+                .into_iter()
+                .map(|leaf| match leaf {
+                    Plaintext::Literal(Literal::Field(leaf), _) => Ok(leaf),
+                    _ => bail!("Instruction '{}' expects an array of field leaves", Self::opcode()),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("Instruction '{}' expects an array of field leaves", Self::opcode()),
+        };
+        ensure!(!nodes.is_empty(), "Instruction '{}' expects at least one leaf", Self::opcode());
+
+        // Pad the leaves to the next power of two.
+        nodes.resize(nodes.len().next_power_of_two(), Field::zero());
+        // Compress each level into its parent level, until only the root remains.
+        let domain = Field::<N>::new_domain_separator(MERKLE_NODE_DOMAIN);
+        while nodes.len() > 1 {
+            nodes = nodes
+                .chunks(2)
+                .map(|children| N::hash_psd2(&[domain, children[0], children[1]]))
+                .collect::<Result<_>>()?;
+        }
+
+        // Store the root.
+        registers.store_literal(stack, &self.destination, Literal::Field(nodes[0]))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::Inject;
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the leaves.
+        let mut nodes = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Array(leaves, _)) => leaves
+                .into_iter()
+                .map(|leaf| match leaf {
+                    circuit::Plaintext::Literal(circuit::Literal::Field(leaf), _) => Ok(leaf),
+                    _ => bail!("Instruction '{}' expects an array of field leaves", Self::opcode()),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("Instruction '{}' expects an array of field leaves", Self::opcode()),
+        };
+        ensure!(!nodes.is_empty(), "Instruction '{}' expects at least one leaf", Self::opcode());
+
+        // Pad the leaves to the next power of two.
+        nodes.resize(nodes.len().next_power_of_two(), circuit::Field::constant(Field::zero()));
+        // Compress each level into its parent level, until only the root remains.
+        let domain = circuit::Field::constant(Field::<N>::new_domain_separator(MERKLE_NODE_DOMAIN));
+        while nodes.len() > 1 {
+            nodes = nodes
+                .chunks(2)
+                .map(|children| A::hash_psd2(&[domain.clone(), children[0].clone(), children[1].clone()]))
+                .collect();
+        }
+
+        // Store the root.
+        registers.store_literal_circuit(stack, &self.destination, circuit::Literal::Field(nodes.swap_remove(0)))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 1 {
+            bail!("Instruction '{}' expects 1 input, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the operand is a one-dimensional array of fields.
+        match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Array(array_type))
+                if array_type.next_element_type() == &PlaintextType::Literal(LiteralType::Field) =>
+            {
+                Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))])
+            }
+            input_type => {
+                bail!("Instruction '{}' expects an array of field leaves, found '{input_type}'", Self::opcode())
+            }
+        }
+    }
+}
+
+impl<N: Network> Parser for MerkleRootPSD2<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first], destination }))
+    }
+}
+
+impl<N: Network> FromStr for MerkleRootPSD2<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for MerkleRootPSD2<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for MerkleRootPSD2<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for MerkleRootPSD2<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(1);
+        // Read the operands.
+        for _ in 0..1 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for MerkleRootPSD2<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(error(format!("The number of operands must be 1, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, instruction) = MerkleRootPSD2::<CurrentNetwork>::parse("merkle.root.psd2 r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(instruction.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(instruction.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(instruction.destination, Register::Locator(1), "The destination register is incorrect");
+    }
+}
@@ -0,0 +1,364 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::merkle_node::MERKLE_NODE_DOMAIN;
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{Boolean, Field},
+};
+
+/// Verifies that `leaf` is included under `root`, given the authentication `path` from the leaf to the root,
+/// storing the outcome in `destination`.
+///
+/// The path is an array of structs, each with a field member for the sibling node, followed by a boolean member
+/// that is `true` if the sibling is the left child. Each level is compressed as in `merkle.node`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MerkleVerify<N: Network> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> MerkleVerify<N> {
+    /// Initializes a new `merkle.verify` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Hash("merkle.verify")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly three operands.
+        debug_assert!(self.operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> MerkleVerify<N> {
+    /// Returns the sibling node and whether it is the left child, from the given path entry.
+    fn path_entry(level: &Plaintext<N>) -> Result<(Field<N>, bool)> {
+        match level {
+            Plaintext::Struct(members, _) => match members.values().collect::<Vec<_>>().as_slice() {
+                [Plaintext::Literal(Literal::Field(sibling), _), Plaintext::Literal(Literal::Boolean(is_left), _)] => {
+                    Ok((*sibling, **is_left))
+                }
+                _ => bail!("Instruction '{}' expects each path entry to be a (field, boolean)", Self::opcode()),
+            },
+            _ => bail!("Instruction '{}' expects each path entry to be a struct", Self::opcode()),
+        }
+    }
+
+    /// Returns the sibling node and whether it is the left child, from the given circuit path entry.
+    fn path_entry_circuit<A: circuit::Aleo<Network = N>>(
+        level: &circuit::Plaintext<A>,
+    ) -> Result<(circuit::Field<A>, circuit::Boolean<A>)> {
+        use circuit::{Literal, Plaintext};
+
+        match level {
+            Plaintext::Struct(members, _) => match members.values().collect::<Vec<_>>().as_slice() {
+                [Plaintext::Literal(Literal::Field(sibling), _), Plaintext::Literal(Literal::Boolean(is_left), _)] => {
+                    Ok((sibling.clone(), is_left.clone()))
+                }
+                _ => bail!("Instruction '{}' expects each path entry to be a (field, boolean)", Self::opcode()),
+            },
+            _ => bail!("Instruction '{}' expects each path entry to be a struct", Self::opcode()),
+        }
+    }
+
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the root and the leaf.
+        let root = match registers.load_literal(stack, &self.operands[0])? {
+            Literal::Field(root) => root,
+            literal => bail!("Instruction '{}' expects a field root, found '{}'", Self::opcode(), literal.to_type()),
+        };
+        let leaf = match registers.load_literal(stack, &self.operands[1])? {
+            Literal::Field(leaf) => leaf,
+            literal => bail!("Instruction '{}' expects a field leaf, found '{}'", Self::opcode(), literal.to_type()),
+        };
+        // Retrieve the path.
+        let path = match registers.load(stack, &self.operands[2])? {
+            Value::Plaintext(Plaintext::Array(path, _)) => path,
+            _ => bail!("Instruction '{}' expects the path to be an array", Self::opcode()),
+        };
+
+        // Recompute the root from the leaf, one level at a time.
+        let domain = Field::<N>::new_domain_separator(MERKLE_NODE_DOMAIN);
+        let mut node = leaf;
+        for level in &path {
+            let (sibling, is_left) = Self::path_entry(level)?;
+            node = match is_left {
+                true => N::hash_psd2(&[domain, sibling, node])?,
+                false => N::hash_psd2(&[domain, node, sibling])?,
+            };
+        }
+
+        // Store the output.
+        registers.store_literal(stack, &self.destination, Literal::Boolean(Boolean::new(node == root)))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::{
+            traits::{Equal, Ternary},
+            Inject,
+        };
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the root and the leaf.
+        let root = match registers.load_literal_circuit(stack, &self.operands[0])? {
+            circuit::Literal::Field(root) => root,
+            literal => bail!("Instruction '{}' expects a field root, found '{}'", Self::opcode(), literal.to_type()),
+        };
+        let leaf = match registers.load_literal_circuit(stack, &self.operands[1])? {
+            circuit::Literal::Field(leaf) => leaf,
+            literal => bail!("Instruction '{}' expects a field leaf, found '{}'", Self::opcode(), literal.to_type()),
+        };
+        // Retrieve the path.
+        let path = match registers.load_circuit(stack, &self.operands[2])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Array(path, _)) => path,
+            _ => bail!("Instruction '{}' expects the path to be an array", Self::opcode()),
+        };
+
+        // Recompute the root from the leaf, one level at a time.
+        let domain = circuit::Field::constant(Field::<N>::new_domain_separator(MERKLE_NODE_DOMAIN));
+        let mut node = leaf;
+        for level in &path {
+            let (sibling, is_left) = Self::path_entry_circuit::<A>(level)?;
+            // Order the children, and compress them into the parent node.
+            let left = circuit::Field::ternary(&is_left, &sibling, &node);
+            let right = circuit::Field::ternary(&is_left, &node, &sibling);
+            node = A::hash_psd2(&[domain.clone(), left, right]);
+        }
+
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, circuit::Literal::Boolean(node.is_equal(&root)))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the root and the leaf are fields.
+        for (name, input_type) in [("root", &input_types[0]), ("leaf", &input_types[1])] {
+            if input_type != &RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field)) {
+                bail!("Instruction '{}' expects a field {name}, found '{input_type}'", Self::opcode())
+            }
+        }
+
+        // Ensure the path is a one-dimensional array of (field, boolean) structs.
+        let is_valid_path = match &input_types[2] {
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => match array_type.next_element_type() {
+                PlaintextType::Struct(struct_name) => {
+                    let members = stack.program().get_struct(struct_name)?.members().values().collect::<Vec<_>>();
+                    members
+                        == [&PlaintextType::Literal(LiteralType::Field), &PlaintextType::Literal(LiteralType::Boolean)]
+                }
+                _ => false,
+            },
+            _ => false,
+        };
+        if !is_valid_path {
+            bail!(
+                "Instruction '{}' expects the path to be an array of (field, boolean) structs, found '{}'",
+                Self::opcode(),
+                input_types[2]
+            )
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+impl<N: Network> Parser for MerkleVerify<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third], destination }))
+    }
+}
+
+impl<N: Network> FromStr for MerkleVerify<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for MerkleVerify<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for MerkleVerify<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for MerkleVerify<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for MerkleVerify<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, instruction) = MerkleVerify::<CurrentNetwork>::parse("merkle.verify r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(instruction.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(instruction.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(instruction.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(instruction.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(instruction.destination, Register::Locator(3), "The destination register is incorrect");
+    }
+}
@@ -13,12 +13,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod address_fingerprint;
+pub use address_fingerprint::*;
+
 mod assert;
 pub use assert::*;
 
+mod assert_bool;
+pub use assert_bool::*;
+
+mod assert_nonzero;
+pub use assert_nonzero::*;
+
 mod async_;
 pub use async_::*;
 
+mod bits_to_int;
+pub use bits_to_int::*;
+
 mod call;
 pub use call::*;
 
@@ -28,9 +40,36 @@ pub use cast::*;
 mod commit;
 pub use commit::*;
 
+mod commit_add;
+pub use commit_add::*;
+
+mod commit_chain;
+pub use commit_chain::*;
+
+mod commit_ciphertext;
+pub use commit_ciphertext::*;
+
+mod commit_zero;
+pub use commit_zero::*;
+
+mod generator;
+pub use generator::*;
+
+mod get_member;
+pub use get_member::*;
+
 mod hash;
 pub use hash::*;
 
+mod hash_bytes;
+pub use hash_bytes::*;
+
+mod hash_concat;
+pub use hash_concat::*;
+
+mod hash_multi;
+pub use hash_multi::*;
+
 mod is;
 pub use is::*;
 
@@ -39,9 +78,51 @@ pub use literals::*;
 
 mod macros;
 
+mod lookup;
+pub use lookup::*;
+
+mod merkle_node;
+pub use merkle_node::*;
+
+mod merkle_root;
+pub use merkle_root::*;
+
+mod merkle_verify;
+pub use merkle_verify::*;
+
+mod popcount;
+pub use popcount::*;
+
+mod pow_const;
+pub use pow_const::*;
+
+mod randomizer;
+pub use randomizer::*;
+
+mod reveal;
+pub use reveal::*;
+
+mod serial_number;
+pub use serial_number::*;
+
+mod shuffle;
+pub use shuffle::*;
+
 mod sign_verify;
 pub use sign_verify::*;
 
+mod state_hash;
+pub use state_hash::*;
+
+mod ternary;
+pub use ternary::*;
+
+mod verify_commitments;
+pub use verify_commitments::*;
+
+mod xor_field;
+pub use xor_field::*;
+
 use crate::Opcode;
 use console::network::prelude::*;
 
@@ -77,6 +158,24 @@ crate::operation!(
     }
 );
 
+/// Computes the absolute difference of `first` and `second`, storing the outcome in `destination`.
+pub type AbsDiff<N> = BinaryLiteral<N, AbsDiffOperation<N>>;
+
+crate::operation!(
+    pub struct AbsDiffOperation<console::prelude::AbsDiff, circuit::traits::AbsDiff, abs_diff, "abs_diff"> {
+        (I8, I8) => I8 ("ensure overflows halt"),
+        (I16, I16) => I16 ("ensure overflows halt"),
+        (I32, I32) => I32 ("ensure overflows halt"),
+        (I64, I64) => I64 ("ensure overflows halt"),
+        (I128, I128) => I128 ("ensure overflows halt"),
+        (U8, U8) => U8,
+        (U16, U16) => U16,
+        (U32, U32) => U32,
+        (U64, U64) => U64,
+        (U128, U128) => U128,
+    }
+);
+
 /// Compute the absolute value of `first`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
 pub type AbsWrapped<N> = UnaryLiteral<N, AbsWrappedOperation<N>>;
 
@@ -735,9 +834,6 @@ crate::operation!(
     }
 );
 
-/// Selects `first`, if `condition` is true, otherwise selects `second`, storing the result in `destination`.
-pub type Ternary<N> = TernaryLiteral<N, TernaryOperation<N>>;
-
 crate::operation!(
     pub struct TernaryOperation<console::prelude::Ternary, circuit::traits::Ternary, ternary, "ternary"> {
         (Boolean, Address, Address) => Address,
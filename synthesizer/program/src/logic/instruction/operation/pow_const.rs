@@ -0,0 +1,335 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, PlaintextType, Register, RegisterType},
+};
+
+/// The maximum exponent supported by `pow.const`.
+const MAX_EXPONENT: u8 = 64;
+
+/// Raises the field `first` to a small constant `exponent`, storing the outcome in `destination`.
+///
+/// The power is computed with a square-and-multiply chain over the bits of the exponent,
+/// so `x^e` costs `floor(log2(e)) + popcount(e) - 1` multiplications (e.g. `x^5` costs 3).
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct PowConst<N: Network> {
+    /// The operand.
+    operands: Vec<Operand<N>>,
+    /// The constant exponent.
+    exponent: u8,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> PowConst<N> {
+    /// Initializes a new `pow.const` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, exponent: u8, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Sanity check the exponent.
+        Self::check_exponent(exponent)?;
+        // Return the instruction.
+        Ok(Self { operands, exponent, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Literal("pow.const")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there is exactly one operand.
+        debug_assert!(self.operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the constant exponent.
+    #[inline]
+    pub const fn exponent(&self) -> u8 {
+        self.exponent
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Ensures the exponent is a small, nonzero constant.
+    fn check_exponent(exponent: u8) -> Result<()> {
+        if exponent == 0 || exponent > MAX_EXPONENT {
+            bail!("Instruction '{}' expects an exponent between 1 and {MAX_EXPONENT}, found {exponent}", Self::opcode())
+        }
+        Ok(())
+    }
+
+    /// Returns the bits of the exponent after its most significant bit, in big-endian order.
+    fn exponent_bits(&self) -> impl Iterator<Item = bool> {
+        let exponent = self.exponent;
+        let num_bits = u8::BITS - exponent.leading_zeros();
+        (0..num_bits.saturating_sub(1)).rev().map(move |i| (exponent >> i) & 1 == 1)
+    }
+}
+
+impl<N: Network> PowConst<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+        // Ensure the exponent is valid.
+        Self::check_exponent(self.exponent)?;
+
+        // Retrieve the input.
+        let input = match registers.load_literal(stack, &self.operands[0])? {
+            Literal::Field(input) => input,
+            literal => bail!("Instruction '{}' expects a field, found '{}'", Self::opcode(), literal.to_type()),
+        };
+
+        // Compute the power with a square-and-multiply chain.
+        let mut output = input;
+        for bit in self.exponent_bits() {
+            output = output * output;
+            if bit {
+                output *= input;
+            }
+        }
+        // Store the output.
+        registers.store_literal(stack, &self.destination, Literal::Field(output))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+        // Ensure the exponent is valid.
+        Self::check_exponent(self.exponent)?;
+
+        // Retrieve the input.
+        let input = match registers.load_literal_circuit(stack, &self.operands[0])? {
+            circuit::Literal::Field(input) => input,
+            literal => bail!("Instruction '{}' expects a field, found '{}'", Self::opcode(), literal.to_type()),
+        };
+
+        // Compute the power with a square-and-multiply chain.
+        let mut output = input.clone();
+        for bit in self.exponent_bits() {
+            output = &output * &output;
+            if bit {
+                output = &output * &input;
+            }
+        }
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, circuit::Literal::Field(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 1 {
+            bail!("Instruction '{}' expects 1 input, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the exponent is valid.
+        Self::check_exponent(self.exponent)?;
+
+        // Ensure the input is a field.
+        if input_types[0] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field)) {
+            bail!("Instruction '{}' expects a field, found '{}'", Self::opcode(), input_types[0])
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))])
+    }
+}
+
+impl<N: Network> Parser for PowConst<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the exponent from the string.
+        let (string, exponent) = map_res(recognize(many1(one_of("0123456789"))), u8::from_str)(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        // Ensure the exponent is valid.
+        match Self::check_exponent(exponent) {
+            Ok(()) => Ok((string, Self { operands: vec![first], exponent, destination })),
+            Err(e) => map_res(fail, |_: ParserResult<Self>| Err(error(e.to_string())))(string),
+        }
+    }
+}
+
+impl<N: Network> FromStr for PowConst<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for PowConst<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for PowConst<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "{} into {}", self.exponent, self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for PowConst<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(1);
+        // Read the operands.
+        for _ in 0..1 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the exponent.
+        let exponent = u8::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Self::new(operands, exponent, destination).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for PowConst<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(error(format!("The number of operands must be 1, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the exponent.
+        self.exponent.write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, instruction) = PowConst::<CurrentNetwork>::parse("pow.const r0 5 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(instruction.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(instruction.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(instruction.exponent, 5, "The exponent is incorrect");
+        assert_eq!(instruction.destination, Register::Locator(1), "The destination register is incorrect");
+
+        // Ensure the exponent is bounded.
+        assert!(PowConst::<CurrentNetwork>::from_str("pow.const r0 0 into r1").is_err());
+        assert!(PowConst::<CurrentNetwork>::from_str("pow.const r0 256 into r1").is_err());
+    }
+
+    #[test]
+    fn test_exponent_bits() -> Result<()> {
+        let bits = |exponent| -> Result<Vec<bool>> {
+            Ok(PowConst::<CurrentNetwork>::from_str(&format!("pow.const r0 {exponent} into r1"))?
+                .exponent_bits()
+                .collect())
+        };
+        assert_eq!(bits(1)?, Vec::<bool>::new());
+        assert_eq!(bits(2)?, vec![false]);
+        assert_eq!(bits(5)?, vec![false, true]);
+        assert_eq!(bits(64)?, vec![false; 6]);
+        Ok(())
+    }
+}
@@ -0,0 +1,368 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{Field, U64},
+};
+
+/// Shuffles the array `first` with the field element `second` as the seed, storing the outcome in `destination`.
+///
+/// The permutation is a Fisher-Yates shuffle: for `i` from `n - 1` down to `1`, the element at index `i` is swapped
+/// with the element at index `j = (r * (i + 1)) >> 64`, where `r` is the low 64 bits of `hash.psd2([seed, i])`.
+/// The array elements must be literals (other than strings).
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Shuffle<N: Network> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> Shuffle<N> {
+    /// Initializes a new `shuffle` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Literal("shuffle")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly two operands.
+        debug_assert!(self.operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> Shuffle<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the array elements.
+        let mut elements = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(Plaintext::Array(elements, _)) => elements
+                .into_iter()
+                .map(|element| match element {
+                    Plaintext::Literal(literal, _) => Ok(literal),
+                    _ => bail!("Instruction '{}' expects an array of literals", Self::opcode()),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("Instruction '{}' expects an array of literals", Self::opcode()),
+        };
+        // Retrieve the seed.
+        let seed = match registers.load_literal(stack, &self.operands[1])? {
+            Literal::Field(seed) => seed,
+            literal => bail!("Instruction '{}' expects a field seed, found '{}'", Self::opcode(), literal.to_type()),
+        };
+
+        // Shuffle the elements.
+        for (index, swap_index) in Self::derive_permutation(&seed, elements.len())? {
+            elements.swap(index, swap_index);
+        }
+
+        // Store the output.
+        let output = Plaintext::Array(elements.into_iter().map(Plaintext::from).collect(), Default::default());
+        registers.store(stack, &self.destination, Value::Plaintext(output))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::{
+            traits::{Equal, FromBits, Inject, Ternary, ToBits},
+            Plaintext,
+            Value,
+        };
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the array elements.
+        let mut elements = match registers.load_circuit(stack, &self.operands[0])? {
+            Value::Plaintext(Plaintext::Array(elements, _)) => elements
+                .into_iter()
+                .map(|element| match element {
+                    Plaintext::Literal(literal, _) => Ok(literal),
+                    _ => bail!("Instruction '{}' expects an array of literals", Self::opcode()),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("Instruction '{}' expects an array of literals", Self::opcode()),
+        };
+        // Retrieve the seed.
+        let seed = match registers.load_literal_circuit(stack, &self.operands[1])? {
+            circuit::Literal::Field(seed) => seed,
+            literal => bail!("Instruction '{}' expects a field seed, found '{}'", Self::opcode(), literal.to_type()),
+        };
+
+        // Shuffle the elements, with a fixed network of conditional swaps selected by the seed.
+        for index in (1..elements.len()).rev() {
+            // Compute the swap index, as `(r * (index + 1)) >> 64`.
+            let index_field = circuit::Field::constant(Field::from_u64(index as u64));
+            let hash = A::hash_psd2(&[seed.clone(), index_field]);
+            let random = circuit::Field::from_bits_le(&hash.to_bits_le()[..64]);
+            let product = random * circuit::Field::constant(Field::from_u64(index as u64 + 1));
+            let swap_index = circuit::Field::from_bits_le(&product.to_bits_le()[64..]);
+
+            // Determine which of the candidate indices is the swap index.
+            let is_swap_index = (0..index)
+                .map(|candidate| swap_index.is_equal(&circuit::Field::constant(Field::from_u64(candidate as u64))))
+                .collect::<Vec<_>>();
+
+            // Select the element at the swap index, defaulting to the element at `index`.
+            let element_at_index = elements[index].clone();
+            let selected = is_swap_index
+                .iter()
+                .zip_eq(&elements[..index])
+                .fold(element_at_index.clone(), |selected, (is_selected, element)| {
+                    circuit::Literal::ternary(is_selected, element, &selected)
+                });
+            // Move the element at `index` to the swap index.
+            for (is_selected, element) in is_swap_index.iter().zip_eq(&mut elements[..index]) {
+                *element = circuit::Literal::ternary(is_selected, &element_at_index, element);
+            }
+            elements[index] = selected;
+        }
+
+        // Store the output.
+        let output = Plaintext::Array(elements.into_iter().map(Plaintext::from).collect(), Default::default());
+        registers.store_circuit(stack, &self.destination, Value::Plaintext(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 2 {
+            bail!("Instruction '{}' expects 2 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the second operand is a field element.
+        if input_types[1] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field)) {
+            bail!("Instruction '{}' expects a field seed, found '{}'", Self::opcode(), input_types[1])
+        }
+
+        // Ensure the first operand is an array of literals.
+        match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => match array_type.next_element_type() {
+                PlaintextType::Literal(LiteralType::String) => {
+                    bail!("Instruction '{}' does not support arrays of strings", Self::opcode())
+                }
+                PlaintextType::Literal(..) => Ok(vec![input_types[0].clone()]),
+                _ => bail!("Instruction '{}' expects an array of literals, found '{}'", Self::opcode(), input_types[0]),
+            },
+            input_type => bail!("Instruction '{}' expects an array, found '{input_type}'", Self::opcode()),
+        }
+    }
+}
+
+impl<N: Network> Shuffle<N> {
+    /// Returns the swaps of the Fisher-Yates shuffle for the given seed and number of elements,
+    /// as `(index, swap_index)` pairs, in the order they are applied.
+    pub fn derive_permutation(seed: &Field<N>, num_elements: usize) -> Result<Vec<(usize, usize)>> {
+        (1..num_elements)
+            .rev()
+            .map(|index| {
+                // Compute the low 64 bits of `hash.psd2([seed, index])`.
+                let hash = N::hash_psd2(&[*seed, Field::from_u64(index as u64)])?;
+                let random = *U64::<N>::from_bits_le(&hash.to_bits_le()[..64])?;
+                // Compute the swap index, as `(r * (index + 1)) >> 64`.
+                let swap_index = ((random as u128 * (index as u128 + 1)) >> 64) as usize;
+                Ok((index, swap_index))
+            })
+            .collect()
+    }
+}
+
+impl<N: Network> Parser for Shuffle<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second], destination }))
+    }
+}
+
+impl<N: Network> FromStr for Shuffle<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Shuffle<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Shuffle<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for Shuffle<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(2);
+        // Read the operands.
+        for _ in 0..2 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for Shuffle<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(error(format!("The number of operands must be 2, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, instruction) = Shuffle::<CurrentNetwork>::parse("shuffle r0 r1 into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(instruction.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(instruction.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(instruction.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(instruction.destination, Register::Locator(2), "The destination register is incorrect");
+    }
+
+    #[test]
+    fn test_derive_permutation() {
+        let mut rng = TestRng::default();
+
+        for num_elements in 0..=CurrentNetwork::MAX_ARRAY_ELEMENTS {
+            let seed = Uniform::rand(&mut rng);
+            let swaps = Shuffle::<CurrentNetwork>::derive_permutation(&seed, num_elements).unwrap();
+            // Ensure there is one swap per index, and each swap index is in bounds.
+            assert_eq!(swaps.len(), num_elements.saturating_sub(1));
+            for (index, swap_index) in swaps {
+                assert!(swap_index <= index);
+            }
+        }
+    }
+}
@@ -0,0 +1,311 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, PlaintextType, Register, RegisterType},
+    types::Field,
+};
+
+/// Hashes the register state given by the `operands` into a single field attestation, storing the outcome in `destination`.
+///
+/// The attestation is `hash.psd8` over the field encoding of each operand in operand order, where each encoding
+/// is prefixed by its length. The prefix binds the boundaries between operands, so the digest commits to
+/// the contents of each register, rather than to their concatenation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct StateHash<N: Network> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> StateHash<N> {
+    /// Initializes a new `state.hash` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(!operands.is_empty(), "Instruction '{}' must have at least one operand", Self::opcode());
+        ensure!(
+            operands.len() <= N::MAX_OPERANDS,
+            "Instruction '{}' must have at most {} operands",
+            Self::opcode(),
+            N::MAX_OPERANDS
+        );
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Literal("state.hash")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check the number of operands.
+        debug_assert!(
+            !self.operands.is_empty() && self.operands.len() <= N::MAX_OPERANDS,
+            "Instruction '{}' must have between 1 and {} operands",
+            Self::opcode(),
+            N::MAX_OPERANDS
+        );
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Ensures the number of operands is within bounds.
+    fn check_num_operands(num_operands: usize) -> Result<()> {
+        if num_operands == 0 || num_operands > N::MAX_OPERANDS {
+            bail!(
+                "Instruction '{}' expects between 1 and {} operands, found {num_operands} operands",
+                Self::opcode(),
+                N::MAX_OPERANDS
+            )
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> StateHash<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        Self::check_num_operands(self.operands.len())?;
+
+        // Construct the preimage from the length-prefixed fields of each operand.
+        let mut preimage = Vec::new();
+        for operand in &self.operands {
+            let fields = registers.load(stack, operand)?.to_fields()?;
+            preimage.push(Field::from_u64(fields.len() as u64));
+            preimage.extend(fields);
+        }
+        // Hash the preimage.
+        let output = N::hash_psd8(&preimage)?;
+        // Store the output.
+        registers.store_literal(stack, &self.destination, Literal::Field(output))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::{traits::ToFields, Inject};
+
+        // Ensure the number of operands is correct.
+        Self::check_num_operands(self.operands.len())?;
+
+        // Construct the preimage from the length-prefixed fields of each operand.
+        let mut preimage = Vec::new();
+        for operand in &self.operands {
+            let fields = registers.load_circuit(stack, operand)?.to_fields();
+            preimage.push(circuit::Field::constant(Field::from_u64(fields.len() as u64)));
+            preimage.extend(fields);
+        }
+        // Hash the preimage.
+        let output = A::hash_psd8(&preimage);
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, circuit::Literal::Field(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        Self::check_num_operands(input_types.len())?;
+
+        // Ensure none of the inputs are futures.
+        if input_types.iter().any(|input_type| matches!(input_type, RegisterType::Future(..))) {
+            bail!("Instruction '{}' cannot attest to a future", Self::opcode())
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))])
+    }
+}
+
+impl<N: Network> Parser for StateHash<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = many1(parse_operand)(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        // Ensure the number of operands is less than or equal to MAX_OPERANDS.
+        match operands.len() <= N::MAX_OPERANDS {
+            true => Ok((string, Self { operands, destination })),
+            false => map_res(fail, |_: ParserResult<Self>| {
+                Err(error(format!("The number of operands must be <= {}, found {}", N::MAX_OPERANDS, operands.len())))
+            })(string),
+        }
+    }
+}
+
+impl<N: Network> FromStr for StateHash<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for StateHash<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for StateHash<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is within bounds.
+        if self.operands.is_empty() || self.operands.len() > N::MAX_OPERANDS {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{}", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))?;
+        write!(f, " into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for StateHash<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of operands.
+        let num_operands = u8::read_le(&mut reader)? as usize;
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(num_operands.min(N::MAX_OPERANDS));
+        // Read the operands.
+        for _ in 0..num_operands {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Self::new(operands, destination).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for StateHash<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is within bounds.
+        if self.operands.is_empty() || self.operands.len() > N::MAX_OPERANDS {
+            return Err(error(format!("The number of operands must be between 1 and {}", N::MAX_OPERANDS)));
+        }
+        // Write the number of operands.
+        u8::try_from(self.operands.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, instruction) = StateHash::<CurrentNetwork>::parse("state.hash r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(instruction.operands.len(), 3, "The number of operands is incorrect");
+        for (index, operand) in instruction.operands.iter().enumerate() {
+            assert_eq!(operand, &Operand::Register(Register::Locator(index as u64)), "The operand is incorrect");
+        }
+        assert_eq!(instruction.destination, Register::Locator(3), "The destination register is incorrect");
+        assert_eq!(instruction.to_string(), "state.hash r0 r1 r2 into r3");
+
+        // Ensure at least one operand is required.
+        assert!(StateHash::<CurrentNetwork>::from_str("state.hash into r0").is_err());
+    }
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let expected = StateHash::<CurrentNetwork>::from_str("state.hash r0 1field r2 into r3")?;
+        let bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, StateHash::read_le(&bytes[..])?);
+        Ok(())
+    }
+}
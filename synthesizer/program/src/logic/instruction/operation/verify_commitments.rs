@@ -0,0 +1,366 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Boolean,
+};
+
+/// Verifies a batch of `commit.bhp256` openings, storing `true` in `destination` if and only if every opening is valid.
+///
+/// The operands are three arrays of equal length: the commitments `first` (as fields), the committed values `second`,
+/// and the randomizers `third` (as scalars). The opening at index `i` is valid if
+/// `commit.bhp256 second[i] third[i] into c as field` yields `c == first[i]`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct VerifyCommitments<N: Network> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> VerifyCommitments<N> {
+    /// Initializes a new `verify.commitments` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Literal("verify.commitments")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly three operands.
+        debug_assert!(self.operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> VerifyCommitments<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the commitments, values, and randomizers.
+        let commitments = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(Plaintext::Array(commitments, _)) => commitments
+                .into_iter()
+                .map(|commitment| match commitment {
+                    Plaintext::Literal(Literal::Field(commitment), _) => Ok(commitment),
+                    _ => bail!("Instruction '{}' expects an array of field commitments", Self::opcode()),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("Instruction '{}' expects an array of field commitments", Self::opcode()),
+        };
+        let values = match registers.load(stack, &self.operands[1])? {
+            Value::Plaintext(Plaintext::Array(values, _)) => values,
+            _ => bail!("Instruction '{}' expects an array of values", Self::opcode()),
+        };
+        let randomizers = match registers.load(stack, &self.operands[2])? {
+            Value::Plaintext(Plaintext::Array(randomizers, _)) => randomizers
+                .into_iter()
+                .map(|randomizer| match randomizer {
+                    Plaintext::Literal(Literal::Scalar(randomizer), _) => Ok(randomizer),
+                    _ => bail!("Instruction '{}' expects an array of scalar randomizers", Self::opcode()),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("Instruction '{}' expects an array of scalar randomizers", Self::opcode()),
+        };
+        // Ensure the arrays are of equal length.
+        ensure!(
+            commitments.len() == values.len() && values.len() == randomizers.len(),
+            "Instruction '{}' expects arrays of equal length",
+            Self::opcode()
+        );
+
+        // Recompute each commitment, and check that all of them match.
+        let mut is_valid = true;
+        for ((commitment, value), randomizer) in commitments.iter().zip_eq(&values).zip_eq(&randomizers) {
+            is_valid &= N::commit_bhp256(&value.to_bits_le(), randomizer)? == *commitment;
+        }
+
+        // Store the output.
+        registers.store_literal(stack, &self.destination, Literal::Boolean(Boolean::new(is_valid)))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::{
+            traits::{Equal, ToBits},
+            Inject,
+            Plaintext,
+            Value,
+        };
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the commitments, values, and randomizers.
+        let commitments = match registers.load_circuit(stack, &self.operands[0])? {
+            Value::Plaintext(Plaintext::Array(commitments, _)) => commitments
+                .into_iter()
+                .map(|commitment| match commitment {
+                    Plaintext::Literal(circuit::Literal::Field(commitment), _) => Ok(commitment),
+                    _ => bail!("Instruction '{}' expects an array of field commitments", Self::opcode()),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("Instruction '{}' expects an array of field commitments", Self::opcode()),
+        };
+        let values = match registers.load_circuit(stack, &self.operands[1])? {
+            Value::Plaintext(Plaintext::Array(values, _)) => values,
+            _ => bail!("Instruction '{}' expects an array of values", Self::opcode()),
+        };
+        let randomizers = match registers.load_circuit(stack, &self.operands[2])? {
+            Value::Plaintext(Plaintext::Array(randomizers, _)) => randomizers
+                .into_iter()
+                .map(|randomizer| match randomizer {
+                    Plaintext::Literal(circuit::Literal::Scalar(randomizer), _) => Ok(randomizer),
+                    _ => bail!("Instruction '{}' expects an array of scalar randomizers", Self::opcode()),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("Instruction '{}' expects an array of scalar randomizers", Self::opcode()),
+        };
+        // Ensure the arrays are of equal length.
+        ensure!(
+            commitments.len() == values.len() && values.len() == randomizers.len(),
+            "Instruction '{}' expects arrays of equal length",
+            Self::opcode()
+        );
+
+        // Recompute each commitment, and AND together whether each of them matches.
+        let mut is_valid = circuit::Boolean::constant(true);
+        for ((commitment, value), randomizer) in commitments.iter().zip_eq(&values).zip_eq(&randomizers) {
+            is_valid &= A::commit_bhp256(&value.to_bits_le(), randomizer).is_equal(commitment);
+        }
+
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, circuit::Literal::Boolean(is_valid))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the operands are arrays, and retrieve their lengths.
+        let mut lengths = Vec::with_capacity(3);
+        for (index, input_type) in input_types.iter().enumerate() {
+            let array_type = match input_type {
+                RegisterType::Plaintext(PlaintextType::Array(array_type)) => array_type,
+                _ => bail!("Instruction '{}' expects an array, found '{input_type}'", Self::opcode()),
+            };
+            // Ensure the commitments are fields, and the randomizers are scalars.
+            let expected_element_type = match index {
+                0 => Some(PlaintextType::Literal(LiteralType::Field)),
+                2 => Some(PlaintextType::Literal(LiteralType::Scalar)),
+                _ => None,
+            };
+            if let Some(expected_element_type) = expected_element_type {
+                ensure!(
+                    *array_type.next_element_type() == expected_element_type,
+                    "Instruction '{}' expects an array of '{expected_element_type}', found '{input_type}'",
+                    Self::opcode()
+                );
+            }
+            lengths.push(**array_type.length());
+        }
+        // Ensure the arrays are of equal length.
+        if lengths[0] != lengths[1] || lengths[1] != lengths[2] {
+            bail!(
+                "Instruction '{}' expects arrays of equal length, found lengths {}, {}, and {}",
+                Self::opcode(),
+                lengths[0],
+                lengths[1],
+                lengths[2]
+            )
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+impl<N: Network> Parser for VerifyCommitments<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third], destination }))
+    }
+}
+
+impl<N: Network> FromStr for VerifyCommitments<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for VerifyCommitments<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for VerifyCommitments<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for VerifyCommitments<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for VerifyCommitments<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, instruction) =
+            VerifyCommitments::<CurrentNetwork>::parse("verify.commitments r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(instruction.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(instruction.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(instruction.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(instruction.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(instruction.destination, Register::Locator(3), "The destination register is incorrect");
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::Register;
+use indexmap::IndexSet;
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Ensures every output register declared by a closure or function is written before it is output,
+    /// either as an input or as the destination of an instruction.
+    ///
+    /// This catches a forgotten store when the program is written, instead of when it is deployed.
+    pub fn validate_outputs_written(&self) -> Result<()> {
+        // Check the closures.
+        for closure in self.closures.values() {
+            Self::check_outputs_written(
+                "closure",
+                closure.name(),
+                closure.inputs().iter().map(|input| input.register()),
+                closure.instructions(),
+                closure.outputs().iter().map(|output| output.operand()),
+            )?;
+        }
+        // Check the functions.
+        for function in self.functions.values() {
+            Self::check_outputs_written(
+                "function",
+                function.name(),
+                function.inputs().iter().map(|input| input.register()),
+                function.instructions(),
+                function.outputs().iter().map(|output| output.operand()),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Ensures every output register is one of the given input registers, or the destination of an instruction.
+    fn check_outputs_written<'a>(
+        kind: &str,
+        name: &Identifier<N>,
+        inputs: impl Iterator<Item = &'a Register<N>>,
+        instructions: &[Instruction],
+        outputs: impl Iterator<Item = &'a Operand<N>>,
+    ) -> Result<()> {
+        // Collect the locators of the registers that are written.
+        let mut written: IndexSet<u64> = inputs.map(|register| register.locator()).collect();
+        written.extend(instructions.iter().flat_map(|instruction| instruction.destinations()).map(|r| r.locator()));
+
+        // Ensure each output register is written.
+        for operand in outputs {
+            if let Operand::Register(register) = operand {
+                ensure!(
+                    written.contains(&register.locator()),
+                    "The {kind} '{name}' outputs '{register}', but no input or instruction writes to 'r{}'",
+                    register.locator()
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_validate_outputs_written() -> Result<()> {
+        // Initialize a program whose outputs are all written.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program outputs.aleo;
+
+closure double:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
+
+function passthrough:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r0 as field.private;
+    output r1 as field.private;
+    output 1field as field.public;",
+        )?;
+        assert!(program.validate_outputs_written().is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_outputs_written_fails() -> Result<()> {
+        // Initialize a program that declares an output `r3`, which no instruction writes.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program outputs.aleo;
+
+function forgetful:
+    input r0 as field.private;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r3 as field.private;",
+        )?;
+        let error = program.validate_outputs_written().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The function 'forgetful' outputs 'r3', but no input or instruction writes to 'r3'"
+        );
+
+        // Ensure the same holds for a member access of an unwritten register.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program outputs.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+closure forgetful:
+    input r0 as point;
+    output r1.x as field;",
+        )?;
+        let error = program.validate_outputs_written().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The closure 'forgetful' outputs 'r1.x', but no input or instruction writes to 'r1'"
+        );
+        Ok(())
+    }
+}
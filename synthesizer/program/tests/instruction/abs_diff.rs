@@ -0,0 +1,70 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use circuit::{AleoV0, Eject, Inject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Literal, Register},
+    types::{I8, U8},
+};
+use snarkvm_synthesizer_program::{AbsDiff, AbsDiffOperation, Instruction, Operand, Operation};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+/// Evaluates and executes `abs_diff` on the given literals, returning the output.
+fn check_abs_diff(first: Literal<CurrentNetwork>, second: Literal<CurrentNetwork>) -> Result<Literal<CurrentNetwork>> {
+    // Evaluate the operation.
+    let output_a = AbsDiffOperation::<CurrentNetwork>::evaluate(&[first.clone(), second.clone()])?;
+    // Execute the operation.
+    let inputs = [first, second].map(|literal| circuit::Literal::new(circuit::Mode::Private, literal));
+    let output_b = AbsDiffOperation::<CurrentNetwork>::execute::<CurrentAleo>(&inputs)?;
+    assert_eq!(output_a, output_b.eject_value());
+    assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+    <CurrentAleo as circuit::Environment>::reset();
+    Ok(output_a)
+}
+
+#[test]
+fn test_abs_diff_parse() -> Result<()> {
+    let abs_diff = AbsDiff::<CurrentNetwork>::from_str("abs_diff r0 r1 into r2")?;
+    assert_eq!(abs_diff.operands(), &[
+        Operand::Register(Register::Locator(0)),
+        Operand::Register(Register::Locator(1))
+    ]);
+    assert_eq!(abs_diff.destinations(), vec![Register::Locator(2)]);
+
+    // Ensure the instruction is not mistaken for `abs`.
+    let instruction = Instruction::<CurrentNetwork>::from_str("abs_diff r0 r1 into r2;")?;
+    assert!(matches!(instruction, Instruction::AbsDiff(..)));
+    assert_eq!(instruction.to_string(), "abs_diff r0 r1 into r2;");
+    Ok(())
+}
+
+#[test]
+fn test_abs_diff_ordered_and_reversed() -> Result<()> {
+    // Ensure the absolute difference of unsigned integers does not underflow.
+    let (small, large) = (Literal::U8(U8::new(5)), Literal::U8(U8::new(9)));
+    assert_eq!(check_abs_diff(large.clone(), small.clone())?, Literal::U8(U8::new(4)));
+    assert_eq!(check_abs_diff(small.clone(), large)?, Literal::U8(U8::new(4)));
+    assert_eq!(check_abs_diff(small.clone(), small)?, Literal::U8(U8::new(0)));
+
+    // Ensure the absolute difference of signed integers spans zero.
+    let (negative, positive) = (Literal::I8(I8::new(-100)), Literal::I8(I8::new(27)));
+    assert_eq!(check_abs_diff(positive.clone(), negative.clone())?, Literal::I8(I8::new(127)));
+    assert_eq!(check_abs_diff(negative, positive)?, Literal::I8(I8::new(127)));
+    Ok(())
+}
@@ -0,0 +1,129 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::sample::sample_registers;
+
+use circuit::{AleoV0, Eject};
+use console::{
+    account::{Address, PrivateKey},
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::U64,
+};
+use snarkvm_synthesizer_program::{AddressFingerprint, Operand, Program, RegistersLoad, RegistersLoadCircuit};
+use synthesizer_process::{Process, Stack};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 10;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as address.private;
+    address.fingerprint r0 into r1;
+    output r1 as u64.private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Evaluates the fingerprint of the given address.
+fn evaluate_fingerprint(stack: &Stack<CurrentNetwork>, address: Address<CurrentNetwork>) -> Result<u64> {
+    // Initialize the operation.
+    let operation = AddressFingerprint::<CurrentNetwork>::from_str("address.fingerprint r0 into r1")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+
+    // Evaluate the operation.
+    let mut registers = sample_registers(stack, &function_name, &[(&Literal::Address(address), None)])?;
+    operation.evaluate(stack, &mut registers)?;
+    match registers.load(stack, &Operand::Register(Register::Locator(1)))? {
+        Value::Plaintext(Plaintext::Literal(Literal::U64(fingerprint), _)) => Ok(*fingerprint),
+        output => bail!("Expected a u64 fingerprint, found '{output}'"),
+    }
+}
+
+#[test]
+fn test_address_fingerprint() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+    // Initialize the operation.
+    let operation = AddressFingerprint::<CurrentNetwork>::from_str("address.fingerprint r0 into r1")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(1));
+
+    for _ in 0..ITERATIONS {
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+
+        // Compute the expected fingerprint.
+        let hash = CurrentNetwork::hash_psd2(&[address.to_field()?])?;
+        let expected = U64::<CurrentNetwork>::from_bits_le(&hash.to_bits_le()[..64])?;
+
+        // Ensure the fingerprint is deterministic.
+        assert_eq!(*expected, evaluate_fingerprint(&stack, address)?);
+        assert_eq!(*expected, evaluate_fingerprint(&stack, address)?);
+
+        // Ensure the execution matches the evaluation.
+        let literal = Literal::Address(address);
+        let mut registers = sample_registers(&stack, &function_name, &[(&literal, Some(circuit::Mode::Private))])?;
+        operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+        let output = registers.load_circuit(&stack, &destination_operand)?;
+        assert_eq!(Value::Plaintext(Plaintext::from(Literal::U64(expected))), output.eject_value());
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+    }
+    Ok(())
+}
+
+#[test]
+fn test_address_fingerprint_distribution() -> Result<()> {
+    const NUM_ADDRESSES: usize = 512;
+    const NUM_BUCKETS: usize = 16;
+
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+
+    // Bucket the fingerprints of random addresses.
+    let mut buckets = [0usize; NUM_BUCKETS];
+    for _ in 0..NUM_ADDRESSES {
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        buckets[evaluate_fingerprint(&stack, address)? as usize % NUM_BUCKETS] += 1;
+    }
+
+    // Ensure every bucket is within a generous margin of the expected load (32 per bucket).
+    let expected = NUM_ADDRESSES / NUM_BUCKETS;
+    for (index, count) in buckets.iter().enumerate() {
+        assert!(
+            (expected / 3..=expected * 3).contains(count),
+            "Bucket {index} holds {count} of {NUM_ADDRESSES} fingerprints (expected ~{expected})"
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,114 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::sample::{sample_finalize_registers, sample_registers};
+
+use circuit::AleoV0;
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal},
+    types::Boolean,
+};
+use snarkvm_synthesizer_program::{AssertBool, Program};
+use synthesizer_process::{Process, Stack};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack(mode: circuit::Mode) -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(&format!(
+        "program testing.aleo;
+            function run:
+                input r0 as boolean.{mode};
+                assert r0;
+                async run r0 into r1;
+                output r1 as testing.aleo/run.future;
+
+            finalize run:
+                input r0 as boolean.public;
+                assert r0;
+        "
+    ))?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+fn check_assert_bool(input: bool, mode: circuit::Mode) -> Result<()> {
+    // Initialize the stack.
+    let stack = sample_stack(mode)?;
+    // Initialize the operation.
+    let operation = AssertBool::<CurrentNetwork>::from_str("assert r0")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize the input.
+    let literal = Literal::Boolean(Boolean::new(input));
+
+    // Evaluate the operation.
+    let mut registers = sample_registers(&stack, &function_name, &[(&literal, None)])?;
+    let result_a = operation.evaluate(&stack, &mut registers);
+    assert_eq!(result_a.is_ok(), input, "Instruction '{operation}' is incorrect (console): {literal}");
+
+    // Execute the operation.
+    // Note: The circuit should execute in both cases, but only be satisfied if the input is true.
+    let mut registers = sample_registers(&stack, &function_name, &[(&literal, Some(mode))])?;
+    operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+    assert_eq!(
+        <CurrentAleo as circuit::Environment>::is_satisfied(),
+        input,
+        "Instruction '{operation}' is incorrect (circuit): {literal}.{mode}"
+    );
+    <CurrentAleo as circuit::Environment>::reset();
+
+    // Finalize the operation.
+    let mut registers = sample_finalize_registers(&stack, &function_name, &[&literal])?;
+    let result_c = operation.finalize(&stack, &mut registers);
+    assert_eq!(result_c.is_ok(), input, "Instruction '{operation}' is incorrect (finalize): {literal}");
+    Ok(())
+}
+
+#[test]
+fn test_assert_bool_succeeds() -> Result<()> {
+    for mode in [circuit::Mode::Public, circuit::Mode::Private] {
+        check_assert_bool(true, mode)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_assert_bool_halts() -> Result<()> {
+    for mode in [circuit::Mode::Public, circuit::Mode::Private] {
+        check_assert_bool(false, mode)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_assert_bool_rejects_non_boolean() -> Result<()> {
+    // Ensure a non-boolean operand fails to type check.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as u8.private;
+    assert r0;",
+    )?;
+    assert!(Stack::new(&Process::load()?, &program).is_err());
+    Ok(())
+}
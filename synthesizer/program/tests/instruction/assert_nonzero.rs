@@ -0,0 +1,121 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::sample::{sample_finalize_registers, sample_registers};
+
+use circuit::AleoV0;
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal},
+    types::{Field, U64},
+};
+use snarkvm_synthesizer_program::{AssertNonzero, Program};
+use synthesizer_process::{Process, Stack};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack(literal: &Literal<CurrentNetwork>, mode: circuit::Mode) -> Result<Stack<CurrentNetwork>> {
+    // Initialize the type.
+    let type_ = literal.to_type();
+    // Initialize the program.
+    let program = Program::from_str(&format!(
+        "program testing.aleo;
+            function run:
+                input r0 as {type_}.{mode};
+                assert.nonzero r0;
+                async run r0 into r1;
+                output r1 as testing.aleo/run.future;
+
+            finalize run:
+                input r0 as {type_}.public;
+                assert.nonzero r0;
+        "
+    ))?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+fn check_assert_nonzero(literal: Literal<CurrentNetwork>, is_nonzero: bool, mode: circuit::Mode) -> Result<()> {
+    // Initialize the stack.
+    let stack = sample_stack(&literal, mode)?;
+    // Initialize the operation.
+    let operation = AssertNonzero::<CurrentNetwork>::from_str("assert.nonzero r0")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+
+    // Evaluate the operation.
+    let mut registers = sample_registers(&stack, &function_name, &[(&literal, None)])?;
+    let result_a = operation.evaluate(&stack, &mut registers);
+    assert_eq!(result_a.is_ok(), is_nonzero, "Instruction '{operation}' is incorrect (console): {literal}");
+
+    // Execute the operation.
+    // Note: The circuit should execute in both cases, but only be satisfied if the input is non-zero.
+    let mut registers = sample_registers(&stack, &function_name, &[(&literal, Some(mode))])?;
+    operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+    assert_eq!(
+        <CurrentAleo as circuit::Environment>::is_satisfied(),
+        is_nonzero,
+        "Instruction '{operation}' is incorrect (circuit): {literal}.{mode}"
+    );
+    <CurrentAleo as circuit::Environment>::reset();
+
+    // Finalize the operation.
+    let mut registers = sample_finalize_registers(&stack, &function_name, &[&literal])?;
+    let result_c = operation.finalize(&stack, &mut registers);
+    assert_eq!(result_c.is_ok(), is_nonzero, "Instruction '{operation}' is incorrect (finalize): {literal}");
+    Ok(())
+}
+
+#[test]
+fn test_assert_nonzero_succeeds() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    for mode in [circuit::Mode::Public, circuit::Mode::Private] {
+        check_assert_nonzero(Literal::Field(Field::one()), true, mode)?;
+        check_assert_nonzero(Literal::Field(-Field::one()), true, mode)?;
+        check_assert_nonzero(Literal::U64(U64::new(rng.gen_range(1..u64::MAX))), true, mode)?;
+        check_assert_nonzero(Literal::I8(console::types::I8::new(-1)), true, mode)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_assert_nonzero_halts() -> Result<()> {
+    for mode in [circuit::Mode::Public, circuit::Mode::Private] {
+        check_assert_nonzero(Literal::Field(Field::zero()), false, mode)?;
+        check_assert_nonzero(Literal::U64(U64::zero()), false, mode)?;
+        check_assert_nonzero(Literal::I8(console::types::I8::zero()), false, mode)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_assert_nonzero_rejects_non_numeric() -> Result<()> {
+    // Ensure a non-numeric operand fails to type check.
+    for type_ in ["boolean", "group", "address"] {
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            "program testing.aleo;
+                function run:
+                    input r0 as {type_}.private;
+                    assert.nonzero r0;"
+        ))?;
+        assert!(Stack::new(&Process::load()?, &program).is_err());
+    }
+    Ok(())
+}
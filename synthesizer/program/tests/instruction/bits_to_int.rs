@@ -0,0 +1,138 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use circuit::{AleoV0, Eject, Inject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::{Boolean, I8, U32},
+};
+use snarkvm_synthesizer_program::{
+    BitsToInt,
+    Operand,
+    Program,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+};
+use synthesizer_process::{Authorization, CallStack, Process, Registers, Stack, StackProgramTypes};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 25;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as [boolean; 20u32].private;
+    input r1 as [boolean; 8u32].private;
+    bits_to_int r0 into r2 as u32;
+    bits_to_int r1 into r3 as i8;
+    output r2 as u32.private;
+    output r3 as i8.private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Returns the given bits as a boolean array value.
+fn to_array(bits: &[bool]) -> Value<CurrentNetwork> {
+    let elements = bits.iter().map(|bit| Plaintext::from(Literal::Boolean(Boolean::new(*bit)))).collect();
+    Value::Plaintext(Plaintext::Array(elements, Default::default()))
+}
+
+#[test]
+fn test_bits_to_int_round_trip() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+    // Initialize the operations.
+    let to_u32 = BitsToInt::<CurrentNetwork>::from_str("bits_to_int r0 into r2 as u32")?;
+    let to_i8 = BitsToInt::<CurrentNetwork>::from_str("bits_to_int r1 into r3 as i8")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+
+    for _ in 0..ITERATIONS {
+        // Sample the integers, where the `u32` fits in 20 bits.
+        let expected_u32 = U32::<CurrentNetwork>::new(u32::rand(&mut rng) >> 12);
+        let expected_i8 = I8::<CurrentNetwork>::rand(&mut rng);
+        // Convert the integers into boolean arrays.
+        let inputs = [to_array(&expected_u32.to_bits_le()[..20]), to_array(&expected_i8.to_bits_le())];
+
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::try_from((vec![], vec![]))?)?,
+            stack.get_register_types(&function_name)?.clone(),
+        );
+        for (index, input) in inputs.iter().enumerate() {
+            let register = Register::Locator(index as u64);
+            registers.store(&stack, &register, input.clone())?;
+            registers.store_circuit(&stack, &register, circuit::Value::new(circuit::Mode::Private, input.clone()))?;
+        }
+
+        // Evaluate and execute the operations.
+        for operation in [&to_u32, &to_i8] {
+            operation.evaluate(&stack, &mut registers)?;
+            operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+        }
+
+        // Ensure the integers are recovered from their bits.
+        for (index, expected) in [(2, Literal::U32(expected_u32)), (3, Literal::I8(expected_i8))] {
+            let operand = Operand::Register(Register::Locator(index));
+            let expected = Value::Plaintext(Plaintext::from(expected));
+            assert_eq!(expected, registers.load(&stack, &operand)?);
+            assert_eq!(expected, registers.load_circuit(&stack, &operand)?.eject_value());
+        }
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+    }
+    Ok(())
+}
+
+#[test]
+fn test_bits_to_int_rejects_invalid() -> Result<()> {
+    // Ensure an array that does not fit in the destination type is rejected.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as [boolean; 9u32].private;
+    bits_to_int r0 into r1 as u8;",
+    )?;
+    assert!(Stack::new(&Process::load()?, &program).is_err());
+
+    // Ensure a non-boolean array is rejected.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as [u8; 4u32].private;
+    bits_to_int r0 into r1 as u32;",
+    )?;
+    assert!(Stack::new(&Process::load()?, &program).is_err());
+    Ok(())
+}
@@ -21,13 +21,16 @@ use circuit::{AleoV0, Eject};
 use console::{
     network::MainnetV0,
     prelude::*,
-    program::{Identifier, Literal, LiteralType, Plaintext, Register, Value},
+    program::{Identifier, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
 };
 use snarkvm_synthesizer_program::{
     CommitBHP1024,
     CommitBHP256,
+    CommitBHP256Field,
+    CommitBHP256Hashed,
     CommitBHP512,
     CommitBHP768,
+    CommitError,
     CommitInstruction,
     CommitPED128,
     CommitPED64,
@@ -327,3 +330,506 @@ fn test_commit_ped128_is_consistent() {
     }
     check_commit!(CommitPED128);
 }
+
+#[test]
+fn test_commit_ped_output_feeds_group_arithmetic() -> Result<()> {
+    for opcode in [CommitPED64::<CurrentNetwork>::opcode(), CommitPED128::<CurrentNetwork>::opcode()] {
+        // Ensure a Pedersen commitment, declared as a group, can be used in group arithmetic.
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            "program testing.aleo;
+            function run:
+                input r0 as u32.private;
+                input r1 as scalar.private;
+                {opcode} r0 r1 into r2 as group;
+                add r2 r2 into r3;
+                mul r3 r1 into r4;
+                output r4 as group.private;"
+        ))?;
+        assert!(Stack::new(&Process::load()?, &program).is_ok(), "'{opcode}' should output a usable group");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_commit_ped_rejects_wide_inputs() -> Result<()> {
+    let (mode_a, mode_b) = (circuit::Mode::Private, circuit::Mode::Private);
+
+    // Inputs that fit within the Pedersen window are accepted.
+    for (opcode, type_a) in [
+        (CommitPED64::<CurrentNetwork>::opcode(), LiteralType::U32),
+        (CommitPED128::<CurrentNetwork>::opcode(), LiteralType::U64),
+        (CommitPED128::<CurrentNetwork>::opcode(), LiteralType::I64),
+    ] {
+        let (stack, _, _) = sample_stack(opcode, type_a, LiteralType::Scalar, mode_a, mode_b, LiteralType::Group)?;
+        assert!(stack.check_new_deployment().is_ok(), "'{opcode}' should accept a '{type_a}' input");
+    }
+
+    // Inputs that exceed the Pedersen window are rejected for new deployments, but still load.
+    for (opcode, type_a) in [
+        (CommitPED64::<CurrentNetwork>::opcode(), LiteralType::U64),
+        (CommitPED64::<CurrentNetwork>::opcode(), LiteralType::Field),
+        (CommitPED128::<CurrentNetwork>::opcode(), LiteralType::U128),
+        (CommitPED128::<CurrentNetwork>::opcode(), LiteralType::Address),
+    ] {
+        let (stack, _, _) = sample_stack(opcode, type_a, LiteralType::Scalar, mode_a, mode_b, LiteralType::Group)?;
+        assert!(stack.check_new_deployment().is_err(), "'{opcode}' should reject a '{type_a}' input");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_commit_bhp256_field_matches_scalar_path() -> Result<()> {
+    // Prepare the rng.
+    let mut rng = TestRng::default();
+
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    let mode = circuit::Mode::Private;
+    let destination_type = LiteralType::Field;
+
+    // Initialize the stacks.
+    let (field_stack, operands, destination) = sample_stack(
+        CommitBHP256Field::<CurrentNetwork>::opcode(),
+        LiteralType::U64,
+        LiteralType::Field,
+        mode,
+        mode,
+        destination_type,
+    )?;
+    let (scalar_stack, ..) = sample_stack(
+        CommitBHP256::<CurrentNetwork>::opcode(),
+        LiteralType::U64,
+        LiteralType::Scalar,
+        mode,
+        mode,
+        destination_type,
+    )?;
+
+    // Initialize the operations.
+    let field_operation =
+        CommitBHP256Field::<CurrentNetwork>::new(operands.clone(), destination.clone(), destination_type)?;
+    let scalar_operation = CommitBHP256::<CurrentNetwork>::new(operands, destination.clone(), destination_type)?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(destination);
+
+    // Checks that committing with the given field randomizer matches committing with the given scalar randomizer.
+    let check_field_randomizer = |field: console::types::Field<CurrentNetwork>,
+                                  scalar: console::types::Scalar<CurrentNetwork>,
+                                  rng: &mut TestRng|
+     -> Result<()> {
+        // Sample the input.
+        let input = Literal::U64(console::types::U64::rand(rng));
+
+        // Evaluate the field path.
+        let values = [(&input, None), (&Literal::Field(field), None)];
+        let mut registers = sample_registers(&field_stack, &function_name, &values)?;
+        field_operation.evaluate(&field_stack, &mut registers)?;
+        let field_output = registers.load(&field_stack, &destination_operand)?;
+
+        // Evaluate the scalar path.
+        let values = [(&input, None), (&Literal::Scalar(scalar), None)];
+        let mut registers = sample_registers(&scalar_stack, &function_name, &values)?;
+        scalar_operation.evaluate(&scalar_stack, &mut registers)?;
+        let scalar_output = registers.load(&scalar_stack, &destination_operand)?;
+
+        // Ensure the field path is equivalent to the scalar path after the cast.
+        assert_eq!(field_output, scalar_output);
+
+        // Execute the field path, and ensure it matches the evaluation.
+        let values = [(&input, Some(mode)), (&Literal::Field(field), Some(mode))];
+        let mut registers = sample_registers(&field_stack, &function_name, &values)?;
+        field_operation.execute::<CurrentAleo>(&field_stack, &mut registers)?;
+        let execute_output = registers.load_circuit(&field_stack, &destination_operand)?;
+        assert_eq!(field_output, execute_output.eject_value());
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+        Ok(())
+    };
+
+    // Compute the scalar field modulus, as a base field element.
+    let modulus = (-console::types::Scalar::<CurrentNetwork>::one()).to_field()? + console::types::Field::one();
+    // Note: Four multiples of the scalar field modulus fit in the base field, i.e. `4 * s < q`.
+    assert!(modulus * console::types::Field::from_u64(4) > modulus * console::types::Field::from_u64(3));
+    assert!(modulus * console::types::Field::from_u64(5) < modulus * console::types::Field::from_u64(4));
+
+    for i in 0..ITERATIONS {
+        // Sample the scalar randomizer.
+        let scalar = console::types::Scalar::<CurrentNetwork>::rand(&mut rng);
+        // Compute a field randomizer that is at least the scalar field modulus, and reduces to the scalar,
+        // as documented on `CommitBHP256Field`.
+        let multiple = console::types::Field::from_u64(1 + (i % 4) as u64);
+        let field = scalar.to_field()? + modulus * multiple;
+        assert!(field >= modulus);
+        check_field_randomizer(field, scalar, &mut rng)?;
+    }
+
+    // Returns the given field element, reduced modulo the scalar field, as a scalar.
+    let reduce = |mut field: console::types::Field<CurrentNetwork>| {
+        while field >= modulus {
+            field -= modulus;
+        }
+        console::types::Scalar::<CurrentNetwork>::from_field(&field)
+    };
+    // Check the boundaries `k * s - 1` and `k * s` of each multiple of the modulus, and the largest field element.
+    for k in 1..=4 {
+        let multiple = modulus * console::types::Field::from_u64(k);
+        let below = multiple - console::types::Field::one();
+        check_field_randomizer(below, reduce(below)?, &mut rng)?;
+        check_field_randomizer(multiple, console::types::Scalar::zero(), &mut rng)?;
+    }
+    let largest = -console::types::Field::<CurrentNetwork>::one();
+    check_field_randomizer(largest, reduce(largest)?, &mut rng)?;
+
+    // Ensure a scalar randomizer is rejected by `commit.bhp256.field`.
+    assert!(sample_stack(
+        CommitBHP256Field::<CurrentNetwork>::opcode(),
+        LiteralType::U64,
+        LiteralType::Scalar,
+        mode,
+        mode,
+        destination_type,
+    )
+    .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_commit_bhp256_hashed_differs_from_plain() -> Result<()> {
+    // Prepare the rng.
+    let mut rng = TestRng::default();
+
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    let mode = circuit::Mode::Private;
+    let destination_type = LiteralType::Field;
+
+    // Initialize the stacks.
+    let (hashed_stack, operands, destination) = sample_stack(
+        CommitBHP256Hashed::<CurrentNetwork>::opcode(),
+        LiteralType::U64,
+        LiteralType::Scalar,
+        mode,
+        mode,
+        destination_type,
+    )?;
+    let (plain_stack, ..) = sample_stack(
+        CommitBHP256::<CurrentNetwork>::opcode(),
+        LiteralType::U64,
+        LiteralType::Scalar,
+        mode,
+        mode,
+        LiteralType::Group,
+    )?;
+
+    // Initialize the operations.
+    let hashed_operation = CommitBHP256Hashed::<CurrentNetwork>::from_str("commit.bhp256.hashed r0 r1 into r2")?;
+    let plain_operation = CommitBHP256::<CurrentNetwork>::new(operands, destination.clone(), LiteralType::Group)?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(destination);
+
+    for _ in 0..ITERATIONS {
+        // Sample the input and the randomizer.
+        let input = Literal::U64(console::types::U64::rand(&mut rng));
+        let randomizer = Literal::Scalar(console::types::Scalar::<CurrentNetwork>::rand(&mut rng));
+
+        // Evaluate the hashed commitment.
+        let values = [(&input, None), (&randomizer, None)];
+        let mut registers = sample_registers(&hashed_stack, &function_name, &values)?;
+        hashed_operation.evaluate(&hashed_stack, &mut registers)?;
+        let hashed_output = registers.load(&hashed_stack, &destination_operand)?;
+
+        // Evaluate the plain commitment.
+        let mut registers = sample_registers(&plain_stack, &function_name, &values)?;
+        plain_operation.evaluate(&plain_stack, &mut registers)?;
+        let plain_output = registers.load(&plain_stack, &destination_operand)?;
+
+        // Ensure the hashed commitment differs from the plain commitment, and hashes both of its coordinates.
+        let commitment = match plain_output {
+            Value::Plaintext(Plaintext::Literal(Literal::Group(group), ..)) => group,
+            _ => bail!("Expected a group output"),
+        };
+        assert_ne!(hashed_output, Value::Plaintext(Plaintext::from(Literal::Field(commitment.to_x_coordinate()))));
+        let expected =
+            <CurrentNetwork as Network>::hash_psd2(&[commitment.to_x_coordinate(), commitment.to_y_coordinate()])?;
+        assert_eq!(hashed_output, Value::Plaintext(Plaintext::from(Literal::Field(expected))));
+
+        // Execute the hashed commitment, and ensure it matches the evaluation.
+        let values = [(&input, Some(mode)), (&randomizer, Some(mode))];
+        let mut registers = sample_registers(&hashed_stack, &function_name, &values)?;
+        hashed_operation.execute::<CurrentAleo>(&hashed_stack, &mut registers)?;
+        let execute_output = registers.load_circuit(&hashed_stack, &destination_operand)?;
+        assert_eq!(hashed_output, execute_output.eject_value());
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+    }
+    Ok(())
+}
+
+#[test]
+fn test_commit_error_on_invalid_randomizer() -> Result<()> {
+    // Prepare the rng.
+    let mut rng = TestRng::default();
+
+    // Note: The registers are typed by a `commit.bhp256.field` program, which declares a field randomizer.
+    let mode = circuit::Mode::Private;
+    let (stack, operands, destination) = sample_stack(
+        CommitBHP256Field::<CurrentNetwork>::opcode(),
+        LiteralType::U64,
+        LiteralType::Field,
+        mode,
+        mode,
+        LiteralType::Group,
+    )?;
+    let operation = CommitBHP256::<CurrentNetwork>::new(operands, destination, LiteralType::Group)?;
+    let function_name = Identifier::from_str("run")?;
+
+    // Sample the input and the (invalid) randomizer.
+    let input = Literal::U64(console::types::U64::rand(&mut rng));
+    let randomizer = Literal::Field(console::types::Field::<CurrentNetwork>::rand(&mut rng));
+
+    // Ensure evaluation fails with the expected error.
+    let mut registers = sample_registers(&stack, &function_name, &[(&input, None), (&randomizer, None)])?;
+    let error = operation.evaluate(&stack, &mut registers).unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<CommitError>(),
+        Some(CommitError::InvalidRandomizerType { phase: "evaluation", expected: LiteralType::Scalar })
+    ));
+    assert_eq!(error.to_string(), "Invalid randomizer type for the commit evaluation, expected a scalar");
+
+    // Ensure execution fails with the expected error.
+    let mut registers = sample_registers(&stack, &function_name, &[(&input, Some(mode)), (&randomizer, Some(mode))])?;
+    let error = operation.execute::<CurrentAleo>(&stack, &mut registers).unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<CommitError>(),
+        Some(CommitError::InvalidRandomizerType { phase: "execution", expected: LiteralType::Scalar })
+    ));
+    assert_eq!(error.to_string(), "Invalid randomizer type for the commit execution, expected a scalar");
+    <CurrentAleo as circuit::Environment>::reset();
+    Ok(())
+}
+
+#[test]
+fn test_commit_error_on_invalid_output_types() -> Result<()> {
+    let mode = circuit::Mode::Private;
+    let (stack, operands, destination) = sample_stack(
+        CommitPED64::<CurrentNetwork>::opcode(),
+        LiteralType::U32,
+        LiteralType::Scalar,
+        mode,
+        mode,
+        LiteralType::Group,
+    )?;
+    let literal = |literal_type| RegisterType::Plaintext(PlaintextType::Literal(literal_type));
+
+    // Ensure an incorrect number of input types is rejected.
+    let operation = CommitPED64::<CurrentNetwork>::new(operands.clone(), destination.clone(), LiteralType::Group)?;
+    let error = operation.output_types(&stack, &[literal(LiteralType::U32)]).unwrap_err();
+    assert!(matches!(error.downcast_ref::<CommitError>(), Some(CommitError::InvalidNumberOfInputs { found: 1, .. })));
+    assert_eq!(error.to_string(), "Instruction 'commit.ped64' expects 2 inputs, found 1 inputs");
+
+    // Ensure an input wider than the Pedersen window is accepted by `output_types`, and rejected for new deployments.
+    let input_types = [literal(LiteralType::U64), literal(LiteralType::Scalar)];
+    assert!(operation.output_types(&stack, &input_types).is_ok());
+    let error = operation.check_deployment_types(&stack, &input_types).unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<CommitError>(),
+        Some(CommitError::InputTooLarge { max_num_bits: 64, input_type, .. }) if input_type == "u64"
+    ));
+
+    // Ensure a non-plaintext input is rejected for new deployments.
+    let record = RegisterType::Record(Identifier::from_str("token")?);
+    let error = operation.check_deployment_types(&stack, &[record, literal(LiteralType::Scalar)]).unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<CommitError>(),
+        Some(CommitError::NonPlaintextInput { input_type, .. }) if input_type == "token.record"
+    ));
+    assert_eq!(error.to_string(), "Instruction 'commit.ped64' expects a plaintext input, found 'token.record'");
+
+    // Ensure `commit.bhp256.field` rejects a scalar randomizer.
+    let operation = CommitBHP256Field::<CurrentNetwork>::new(operands, destination, LiteralType::Group)?;
+    let error = operation.output_types(&stack, &[literal(LiteralType::U64), literal(LiteralType::Scalar)]).unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<CommitError>(),
+        Some(CommitError::ExpectedFieldRandomizer { randomizer_type, .. }) if randomizer_type == "scalar"
+    ));
+    assert_eq!(error.to_string(), "Instruction 'commit.bhp256.field' expects a field randomizer, found 'scalar'");
+    Ok(())
+}
+
+#[test]
+fn test_commit_error_on_non_scalar_randomizer_type() -> Result<()> {
+    let mode = circuit::Mode::Private;
+    let (stack, operands, destination) = sample_stack(
+        CommitBHP256::<CurrentNetwork>::opcode(),
+        LiteralType::U64,
+        LiteralType::Scalar,
+        mode,
+        mode,
+        LiteralType::Group,
+    )?;
+    let literal = |literal_type| RegisterType::Plaintext(PlaintextType::Literal(literal_type));
+
+    // Ensure a field randomizer is accepted by `output_types`, and rejected for new deployments.
+    let operation = CommitBHP256::<CurrentNetwork>::new(operands, destination, LiteralType::Group)?;
+    let scalar_input_types = [literal(LiteralType::U64), literal(LiteralType::Scalar)];
+    assert!(operation.check_deployment_types(&stack, &scalar_input_types).is_ok());
+    let input_types = [literal(LiteralType::U64), literal(LiteralType::Field)];
+    assert!(operation.output_types(&stack, &input_types).is_ok());
+    let error = operation.check_deployment_types(&stack, &input_types).unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<CommitError>(),
+        Some(CommitError::ExpectedScalarRandomizer { randomizer_type, .. }) if randomizer_type == "field"
+    ));
+    assert_eq!(error.to_string(), "Instruction 'commit.bhp256' expects a scalar randomizer, found 'field'");
+
+    // Ensure a program with a field randomizer still loads, and is rejected as a new deployment,
+    // for every scalar committer.
+    for opcode in [
+        CommitBHP256::<CurrentNetwork>::opcode(),
+        CommitBHP512::<CurrentNetwork>::opcode(),
+        CommitBHP768::<CurrentNetwork>::opcode(),
+        CommitBHP1024::<CurrentNetwork>::opcode(),
+        CommitPED64::<CurrentNetwork>::opcode(),
+        CommitPED128::<CurrentNetwork>::opcode(),
+        CommitBHP256Hashed::<CurrentNetwork>::opcode(),
+    ] {
+        let (stack, _, _) = sample_stack(opcode, LiteralType::U8, LiteralType::Field, mode, mode, LiteralType::Group)?;
+        assert!(stack.check_new_deployment().is_err(), "'{opcode}' should reject a 'field' randomizer");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_commit_with_literal_randomizer() -> Result<()> {
+    // Prepare the rng.
+    let mut rng = TestRng::default();
+
+    // Initialize a program that commits with a literal randomizer.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as u64.private;
+    commit.bhp256 r0 5scalar into r1 as group;
+    output r1 as group.private;",
+    )?;
+    let stack = Stack::new(&Process::load()?, &program)?;
+
+    // Initialize the operation.
+    let operation = CommitBHP256::<CurrentNetwork>::from_str("commit.bhp256 r0 5scalar into r1 as group")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(1));
+    // Initialize the randomizer.
+    let randomizer = console::types::Scalar::<CurrentNetwork>::from_str("5scalar")?;
+
+    for _ in 0..ITERATIONS {
+        let input = Literal::U64(console::types::U64::rand(&mut rng));
+        let mode = circuit::Mode::Private;
+
+        // Ensure the evaluation matches the commitment with the literal randomizer.
+        let mut registers = sample_registers(&stack, &function_name, &[(&input, Some(mode))])?;
+        operation.evaluate(&stack, &mut registers)?;
+        let output_a = registers.load(&stack, &destination_operand)?;
+        let expected = <CurrentNetwork as Network>::commit_to_group_bhp256(
+            &Value::Plaintext(Plaintext::from(input)).to_bits_le(),
+            &randomizer,
+        )?;
+        assert_eq!(output_a, Value::Plaintext(Plaintext::from(Literal::Group(expected))));
+
+        // Ensure the execution matches the evaluation.
+        operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+        let output_b = registers.load_circuit(&stack, &destination_operand)?;
+        assert_eq!(output_a, output_b.eject_value());
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+    }
+    Ok(())
+}
+
+#[test]
+fn test_commit_preimage_bits_match() -> Result<()> {
+    use circuit::{traits::ToBits as _, Inject};
+
+    for plaintext in [
+        "5u64",
+        "[1field, 2field, 3field]",
+        "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, amount: 10u64 }",
+        "{ a: [true, false], b: { c: 0group, d: -7i8 } }",
+    ] {
+        let value = Value::<CurrentNetwork>::Plaintext(Plaintext::from_str(plaintext)?);
+        for mode in [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+            // Ensure the evaluation and execution derive the same preimage bits for the commitment.
+            let circuit_value = circuit::Value::<CurrentAleo>::new(mode, value.clone());
+            assert_eq!(
+                value.to_bits_le(),
+                circuit_value.to_bits_le().eject_value(),
+                "(mode = {mode}, value = {value})"
+            );
+            <CurrentAleo as circuit::Environment>::reset();
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_commit_bhp256_to_address() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize a program that commits to an owner address with a salt, into a pseudo-address.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as address.private;
+    input r1 as scalar.private;
+    commit.bhp256 r0 r1 into r2 as address;
+    output r2 as address.private;",
+    )?;
+    let stack = Stack::new(&Process::load()?, &program)?;
+
+    // Initialize the operation.
+    let operation = CommitBHP256::<CurrentNetwork>::from_str("commit.bhp256 r0 r1 into r2 as address")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(2));
+
+    // Evaluates and executes the operation, and returns the output.
+    let commit = |owner: &Literal<CurrentNetwork>, salt: &Literal<CurrentNetwork>| -> Result<Value<CurrentNetwork>> {
+        let mode = Some(circuit::Mode::Private);
+        let mut registers = sample_registers(&stack, &function_name, &[(owner, mode), (salt, mode)])?;
+        operation.evaluate(&stack, &mut registers)?;
+        let output_a = registers.load(&stack, &destination_operand)?;
+        operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+        let output_b = registers.load_circuit(&stack, &destination_operand)?;
+        assert_eq!(output_a, output_b.eject_value());
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+        Ok(output_a)
+    };
+
+    for _ in 0..ITERATIONS {
+        let owner = Literal::Address(console::types::Address::new(Uniform::rand(&mut rng)));
+        let salt_scalar = console::types::Scalar::<CurrentNetwork>::rand(&mut rng);
+        let salt = Literal::Scalar(salt_scalar);
+
+        // Ensure the output is the address of the commitment.
+        let output = commit(&owner, &salt)?;
+        let owner_bits = Value::Plaintext(Plaintext::from(owner.clone())).to_bits_le();
+        let commitment = <CurrentNetwork as Network>::commit_to_group_bhp256(&owner_bits, &salt_scalar)?;
+        let expected = console::types::Address::new(commitment);
+        assert_eq!(output, Value::Plaintext(Plaintext::from(Literal::Address(expected))));
+        // Ensure the output parses as a valid address.
+        assert_eq!(console::types::Address::<CurrentNetwork>::from_str(&expected.to_string())?, expected);
+
+        // Ensure the output is deterministic for a fixed owner and salt.
+        assert_eq!(output, commit(&owner, &salt)?);
+        // Ensure a distinct salt yields a distinct address.
+        assert_ne!(output, commit(&owner, &Literal::Scalar(Uniform::rand(&mut rng)))?);
+    }
+    Ok(())
+}
@@ -0,0 +1,116 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::sample::sample_registers;
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::{Group, Scalar, U32},
+};
+use snarkvm_synthesizer_program::{CommitAdd, Operand, Program, RegistersLoad, RegistersLoadCircuit};
+use synthesizer_process::{Process, Stack};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 25;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as group.private;
+    input r1 as group.private;
+    commit.add r0 r1 into r2;
+    output r2 as group.private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Returns the Pedersen commitment to the given value under the given randomizer.
+fn commit(value: u64, randomizer: &Scalar<CurrentNetwork>) -> Result<Group<CurrentNetwork>> {
+    CurrentNetwork::commit_to_group_ped64(&value.to_bits_le(), randomizer)
+}
+
+#[test]
+fn test_commit_add() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+    // Initialize the operation.
+    let operation = CommitAdd::<CurrentNetwork>::from_str("commit.add r0 r1 into r2")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(2));
+
+    for _ in 0..ITERATIONS {
+        // Sample the values, so that their sum does not overflow, and the randomizers.
+        let (a, b) = (rng.gen::<u32>() as u64, rng.gen::<u32>() as u64);
+        let (r_a, r_b) = (Scalar::<CurrentNetwork>::rand(&mut rng), Scalar::<CurrentNetwork>::rand(&mut rng));
+
+        // Commit to the values.
+        let first = Literal::Group(commit(a, &r_a)?);
+        let second = Literal::Group(commit(b, &r_b)?);
+        // Compute the commitment to the sum, under the sum of the randomizers.
+        let expected = Value::Plaintext(Plaintext::from(Literal::Group(commit(a + b, &(r_a + r_b))?)));
+
+        // Ensure `commit.add(commit(a, r_a), commit(b, r_b)) == commit(a + b, r_a + r_b)` in evaluate.
+        let mut registers = sample_registers(&stack, &function_name, &[(&first, None), (&second, None)])?;
+        operation.evaluate(&stack, &mut registers)?;
+        assert_eq!(expected, registers.load(&stack, &destination_operand)?);
+
+        // Ensure `commit.add(commit(a, r_a), commit(b, r_b)) == commit(a + b, r_a + r_b)` in execute.
+        let mode = Some(circuit::Mode::Private);
+        let mut registers = sample_registers(&stack, &function_name, &[(&first, mode), (&second, mode)])?;
+        operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+        assert_eq!(expected, registers.load_circuit(&stack, &destination_operand)?.eject_value());
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+    }
+    Ok(())
+}
+
+#[test]
+fn test_commit_add_type_prefix_offset() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Returns the commitment to a `u32` literal, as computed by `commit.ped64`.
+    let commit_literal = |value: u32, randomizer: &Scalar<CurrentNetwork>| {
+        let value = Value::<CurrentNetwork>::from(Literal::U32(U32::new(value)));
+        CurrentNetwork::commit_to_group_ped64(&value.to_bits_le(), randomizer)
+    };
+
+    for _ in 0..ITERATIONS {
+        let (a, b) = (rng.gen::<u16>() as u32, rng.gen::<u16>() as u32);
+        let (r_a, r_b) = (Scalar::<CurrentNetwork>::rand(&mut rng), Scalar::<CurrentNetwork>::rand(&mut rng));
+
+        // Ensure the sum of literal commitments is offset from the commitment to the sum by the commitment to zero.
+        let sum = commit_literal(a, &r_a)? + commit_literal(b, &r_b)?;
+        let expected = commit_literal(a + b, &(r_a + r_b))? + commit_literal(0, &Scalar::zero())?;
+        assert_eq!(expected, sum);
+    }
+    Ok(())
+}
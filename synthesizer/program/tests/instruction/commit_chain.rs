@@ -0,0 +1,135 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::sample::sample_registers;
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::{Field, Scalar, U64},
+};
+use snarkvm_synthesizer_program::{CommitChainBHP256, Operand, Program, RegistersLoad, RegistersLoadCircuit};
+use synthesizer_process::{Process, Stack};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 10;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    input r1 as u64.private;
+    input r2 as scalar.private;
+    commit.chain.bhp256 r0 r1 r2 into r3;
+    output r3 as field.private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Appends `value` to the chain at `previous`, checking that evaluate and execute agree.
+fn append(
+    stack: &Stack<CurrentNetwork>,
+    previous: Field<CurrentNetwork>,
+    value: U64<CurrentNetwork>,
+    randomizer: Scalar<CurrentNetwork>,
+) -> Result<Field<CurrentNetwork>> {
+    // Initialize the operation.
+    let operation = CommitChainBHP256::<CurrentNetwork>::from_str("commit.chain.bhp256 r0 r1 r2 into r3")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(3));
+
+    // Initialize the inputs.
+    let previous = Literal::Field(previous);
+    let value = Literal::U64(value);
+    let randomizer = Literal::Scalar(randomizer);
+
+    // Evaluate the operation.
+    let mut evaluate_registers =
+        sample_registers(stack, &function_name, &[(&previous, None), (&value, None), (&randomizer, None)])?;
+    operation.evaluate(stack, &mut evaluate_registers)?;
+    let output_a = evaluate_registers.load(stack, &destination_operand)?;
+
+    // Execute the operation.
+    let mode = Some(circuit::Mode::Private);
+    let mut execute_registers =
+        sample_registers(stack, &function_name, &[(&previous, mode), (&value, mode), (&randomizer, mode)])?;
+    operation.execute::<CurrentAleo>(stack, &mut execute_registers)?;
+    let output_b = execute_registers.load_circuit(stack, &destination_operand)?;
+
+    // Check the outputs.
+    assert_eq!(output_a, output_b.eject_value(), "The evaluation and execution outputs do not match");
+    assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "The circuit is not satisfied");
+    <CurrentAleo as circuit::Environment>::reset();
+
+    match output_a {
+        Value::Plaintext(Plaintext::Literal(Literal::Field(output), _)) => Ok(output),
+        output => bail!("Expected a field commitment, found '{output}'"),
+    }
+}
+
+#[test]
+fn test_commit_chain_bhp256() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+
+    for _ in 0..ITERATIONS {
+        let previous = Field::<CurrentNetwork>::rand(&mut rng);
+        let value = U64::<CurrentNetwork>::rand(&mut rng);
+        let randomizer = Scalar::<CurrentNetwork>::rand(&mut rng);
+
+        // Ensure the chain commitment is the BHP256 commitment to `previous || value`.
+        let mut preimage = previous.to_bits_le();
+        preimage.extend(Value::<CurrentNetwork>::from(Literal::U64(value)).to_bits_le());
+        let expected = CurrentNetwork::commit_bhp256(&preimage, &randomizer)?;
+        assert_eq!(expected, append(&stack, previous, value, randomizer)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_commit_chain_bhp256_is_order_dependent() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+
+    for _ in 0..ITERATIONS {
+        let genesis = Field::<CurrentNetwork>::rand(&mut rng);
+        let first = U64::<CurrentNetwork>::rand(&mut rng);
+        let second = U64::<CurrentNetwork>::rand(&mut rng);
+        let (r0, r1) = (Scalar::rand(&mut rng), Scalar::rand(&mut rng));
+
+        // Append the values in both orders, with the same randomizers.
+        let forward = append(&stack, append(&stack, genesis, first, r0)?, second, r1)?;
+        let reversed = append(&stack, append(&stack, genesis, second, r0)?, first, r1)?;
+        assert_ne!(forward, reversed, "Reordering the appended values must change the chain commitment");
+    }
+    Ok(())
+}
@@ -0,0 +1,148 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use circuit::{AleoV0, Eject};
+use console::{
+    account::{Address, PrivateKey, ViewKey},
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Record, Register, Value},
+    types::Scalar,
+};
+use snarkvm_synthesizer_program::{
+    CommitCiphertextBHP256,
+    Operand,
+    Program,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+};
+use synthesizer_process::{Authorization, CallStack, Process, Registers, Stack, StackProgramTypes};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 5;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function run:
+    input r0 as token.record;
+    input r1 as scalar.private;
+    commit.ciphertext.bhp256 r0 r1 into r2;
+    output r2 as field.private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Returns the registers for `run`, storing the given record and randomizer.
+fn sample_registers(
+    stack: &Stack<CurrentNetwork>,
+    record: &Record<CurrentNetwork, Plaintext<CurrentNetwork>>,
+    randomizer: Scalar<CurrentNetwork>,
+) -> Result<Registers<CurrentNetwork, CurrentAleo>> {
+    // Initialize the registers.
+    let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+        CallStack::evaluate(Authorization::try_from((vec![], vec![]))?)?,
+        stack.get_register_types(&Identifier::from_str("run")?)?.clone(),
+    );
+    // Store the inputs.
+    let inputs = [Value::Record(record.clone()), Value::Plaintext(Plaintext::from(Literal::Scalar(randomizer)))];
+    for (index, input) in inputs.into_iter().enumerate() {
+        let register = Register::Locator(index as u64);
+        registers.store(stack, &register, input.clone())?;
+        registers.store_circuit(stack, &register, circuit::Value::new(circuit::Mode::Private, input))?;
+    }
+    Ok(registers)
+}
+
+#[test]
+fn test_commit_ciphertext() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+    // Initialize the operation.
+    let operation = CommitCiphertextBHP256::<CurrentNetwork>::from_str("commit.ciphertext.bhp256 r0 r1 into r2")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(2));
+
+    for _ in 0..ITERATIONS {
+        // Sample an owner, and a record with a nonce from a known randomizer.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let owner = Address::try_from(&private_key)?;
+        let randomizer = Scalar::<CurrentNetwork>::rand(&mut rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let amount = u64::rand(&mut rng);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {owner}.private, amount: {amount}u64.private, _nonce: {nonce}.public }}"
+        ))?;
+
+        // Evaluate and execute the operation.
+        let mut registers = sample_registers(&stack, &record, randomizer)?;
+        operation.evaluate(&stack, &mut registers)?;
+        let output_a = registers.load(&stack, &destination_operand)?;
+        operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+        let output_b = registers.load_circuit(&stack, &destination_operand)?;
+        assert_eq!(output_a, output_b.eject_value());
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+
+        // Reopen the commitment over the record ciphertext.
+        let ciphertext = record.encrypt(randomizer)?;
+        let expected = CurrentNetwork::commit_bhp256(&ciphertext.to_bits_le(), &randomizer)?;
+        assert_eq!(output_a, Value::Plaintext(Plaintext::from(Literal::Field(expected))));
+        // Ensure the committed ciphertext decrypts to the record.
+        assert_eq!(ciphertext.decrypt(&view_key)?, record);
+
+        // Ensure a randomizer that does not correspond to the record nonce is rejected.
+        let mut registers = sample_registers(&stack, &record, randomizer + Scalar::one())?;
+        assert!(operation.evaluate(&stack, &mut registers).is_err());
+        operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+        assert!(!<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+    }
+    Ok(())
+}
+
+#[test]
+fn test_commit_ciphertext_rejects_non_record() -> Result<()> {
+    // Ensure a non-record operand fails to type check.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    input r1 as scalar.private;
+    commit.ciphertext.bhp256 r0 r1 into r2;
+    output r2 as field.private;",
+    )?;
+    assert!(Stack::new(&Process::load()?, &program).is_err());
+    Ok(())
+}
@@ -0,0 +1,155 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::sample::sample_registers;
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::{Group, Scalar},
+};
+use snarkvm_synthesizer_program::{CommitZeroPED64, Operand, Program, RegistersLoad, RegistersLoadCircuit};
+use synthesizer_process::{Process, Stack};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 25;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as scalar.private;
+    commit.zero.ped64 r0 into r1;
+    output r1 as group.private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Returns the Pedersen commitment to the given value under the given randomizer.
+fn commit(value: u64, randomizer: &Scalar<CurrentNetwork>) -> Result<Group<CurrentNetwork>> {
+    CurrentNetwork::commit_to_group_ped64(&value.to_bits_le(), randomizer)
+}
+
+#[test]
+fn test_commit_zero() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+    // Initialize the operation.
+    let operation = CommitZeroPED64::<CurrentNetwork>::from_str("commit.zero.ped64 r0 into r1")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(1));
+
+    for _ in 0..ITERATIONS {
+        // Sample the randomizer.
+        let randomizer = Scalar::<CurrentNetwork>::rand(&mut rng);
+        let input = Literal::Scalar(randomizer);
+        // Compute the commitment to zero.
+        let expected = Value::Plaintext(Plaintext::from(Literal::Group(commit(0, &randomizer)?)));
+
+        // Ensure `commit.zero.ped64(r) == commit.ped64(0u64, r)` in evaluate.
+        let mut registers = sample_registers(&stack, &function_name, &[(&input, None)])?;
+        operation.evaluate(&stack, &mut registers)?;
+        assert_eq!(expected, registers.load(&stack, &destination_operand)?);
+
+        // Ensure `commit.zero.ped64(r) == commit.ped64(0u64, r)` in execute.
+        let mode = Some(circuit::Mode::Private);
+        let mut registers = sample_registers(&stack, &function_name, &[(&input, mode)])?;
+        operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+        assert_eq!(expected, registers.load_circuit(&stack, &destination_operand)?.eject_value());
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+    }
+    Ok(())
+}
+
+#[test]
+fn test_commit_zero_is_additive_identity() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as u64.private;
+    input r1 as scalar.private;
+    input r2 as scalar.private;
+    commit.ped64 r0 r1 into r3 as group;
+    commit.zero.ped64 r2 into r4;
+    commit.add r3 r4 into r5;
+    output r5 as group.private;",
+    )?;
+    // Ensure the program type-checks.
+    Stack::new(&Process::load()?, &program)?;
+
+    // Initialize the operation.
+    let operation = CommitZeroPED64::<CurrentNetwork>::from_str("commit.zero.ped64 r0 into r1")?;
+    let stack = sample_stack()?;
+    let function_name = Identifier::from_str("run")?;
+    let destination_operand = Operand::Register(Register::Locator(1));
+
+    for _ in 0..ITERATIONS {
+        let value = rng.gen::<u64>();
+        let (r_value, r_zero) = (Scalar::<CurrentNetwork>::rand(&mut rng), Scalar::<CurrentNetwork>::rand(&mut rng));
+
+        // Compute the commitment to zero.
+        let mut registers = sample_registers(&stack, &function_name, &[(&Literal::Scalar(r_zero), None)])?;
+        operation.evaluate(&stack, &mut registers)?;
+        let zero = match registers.load(&stack, &destination_operand)? {
+            Value::Plaintext(Plaintext::Literal(Literal::Group(zero), _)) => zero,
+            value => bail!("Expected a group element, found '{value}'"),
+        };
+
+        // Ensure `commit(x, r_x) + commit.zero(r_0) == commit(x, r_x + r_0)`.
+        assert_eq!(commit(value, &(r_value + r_zero))?, commit(value, &r_value)? + zero);
+    }
+
+    // Ensure the commitment to zero under a zero randomizer is the group identity.
+    assert_eq!(Group::<CurrentNetwork>::zero(), commit(0, &Scalar::zero())?);
+    Ok(())
+}
+
+#[test]
+fn test_commit_zero_rejects_non_scalar_randomizer() -> Result<()> {
+    // Initialize a program with a field randomizer.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    commit.zero.ped64 r0 into r1;
+    output r1 as group.private;",
+    )?;
+
+    // Ensure the program fails to type-check.
+    assert!(Stack::new(&Process::load()?, &program).is_err());
+    Ok(())
+}
@@ -0,0 +1,80 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::sample::sample_registers;
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::{Field, Scalar},
+};
+use snarkvm_synthesizer_program::{Generator, Operand, Program, RegistersLoad, RegistersLoadCircuit};
+use synthesizer_process::{Process, Stack};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    generator into r1;
+    output r1 as group.private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+#[test]
+fn test_generator() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+    // Initialize the operation.
+    let operation = Generator::<CurrentNetwork>::from_str("generator into r1")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(1));
+
+    // Compute the expected generator, as used by `g_scalar_multiply`.
+    let generator = <CurrentNetwork as Network>::g_scalar_multiply(&Scalar::one());
+    let expected = Value::Plaintext(Plaintext::from(Literal::Group(generator)));
+
+    // Evaluate the operation.
+    let input = Literal::Field(Field::<CurrentNetwork>::rand(&mut rng));
+    let mut evaluate_registers = sample_registers(&stack, &function_name, &[(&input, None)])?;
+    operation.evaluate(&stack, &mut evaluate_registers)?;
+    assert_eq!(expected, evaluate_registers.load(&stack, &destination_operand)?);
+
+    // Execute the operation.
+    let mut execute_registers = sample_registers(&stack, &function_name, &[(&input, Some(circuit::Mode::Private))])?;
+    operation.execute::<CurrentAleo>(&stack, &mut execute_registers)?;
+    let output = execute_registers.load_circuit(&stack, &destination_operand)?;
+    assert_eq!(expected, output.eject_value());
+    assert!(output.eject_mode().is_constant(), "The generator must be a constant");
+    assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+    <CurrentAleo as circuit::Environment>::reset();
+    Ok(())
+}
@@ -0,0 +1,136 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use circuit::{AleoV0, Eject, Inject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Register, Value},
+    types::{Field, U64},
+};
+use snarkvm_synthesizer_program::{
+    GetMember,
+    Operand,
+    Program,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+};
+use synthesizer_process::{Authorization, CallStack, Process, Registers, Stack, StackProgramTypes};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 10;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+struct point:
+    x as field;
+    y as u64;
+
+function run:
+    input r0 as point.private;
+    get_member r0 into r1 as y;
+    output r1 as u64.private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+#[test]
+fn test_get_member() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+    // Initialize the operation.
+    let operation = GetMember::<CurrentNetwork>::from_str("get_member r0 into r1 as y")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(1));
+
+    for _ in 0..ITERATIONS {
+        // Sample a struct.
+        let x = Field::<CurrentNetwork>::rand(&mut rng);
+        let y = U64::<CurrentNetwork>::rand(&mut rng);
+        let point = Value::<CurrentNetwork>::from_str(&format!("{{ x: {x}, y: {y} }}"))?;
+        let expected = Value::<CurrentNetwork>::from_str(&y.to_string())?;
+
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::try_from((vec![], vec![]))?)?,
+            stack.get_register_types(&function_name)?.clone(),
+        );
+        let register = Register::Locator(0);
+        registers.store(&stack, &register, point.clone())?;
+        registers.store_circuit(&stack, &register, circuit::Value::new(circuit::Mode::Private, point))?;
+
+        // Ensure the member is extracted in evaluate.
+        operation.evaluate(&stack, &mut registers)?;
+        assert_eq!(expected, registers.load(&stack, &destination_operand)?);
+
+        // Ensure the member is extracted in execute.
+        operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+        let output = registers.load_circuit(&stack, &destination_operand)?;
+        assert_eq!(expected, output.eject_value());
+        assert!(output.eject_mode().is_private(), "The member must keep the mode of the struct");
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+    }
+    Ok(())
+}
+
+#[test]
+fn test_get_member_rejects_unknown_member() -> Result<()> {
+    // Initialize a program that reads a member the struct does not have.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+struct point:
+    x as field;
+    y as u64;
+
+function run:
+    input r0 as point.private;
+    get_member r0 into r1 as z;
+    output r1 as u64.private;",
+    )?;
+    // Ensure the stack rejects the program.
+    let error = Stack::new(&Process::load()?, &program).unwrap_err();
+    assert!(error.to_string().contains("has no member named 'z'"), "Unexpected error: {error}");
+
+    // Initialize a program that reads a member from a literal.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    get_member r0 into r1 as x;",
+    )?;
+    // Ensure the stack rejects the program.
+    assert!(Stack::new(&Process::load()?, &program).is_err());
+    Ok(())
+}
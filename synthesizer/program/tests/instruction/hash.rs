@@ -40,6 +40,9 @@ use snarkvm_synthesizer_program::{
     HashSha3_256,
     HashSha3_384,
     HashSha3_512,
+    HashToGroupPSD8,
+    HashToScalarPSD2,
+    HashToScalarPSD4,
     Opcode,
     Operand,
     Program,
@@ -246,6 +249,158 @@ test_hash!(hash_sha3_256, HashSha3_256, 5);
 test_hash!(hash_sha3_384, HashSha3_384, 5);
 test_hash!(hash_sha3_512, HashSha3_512, 5);
 
+test_hash!(hash_to_scalar_psd2, HashToScalarPSD2, ITERATIONS);
+test_hash!(hash_to_scalar_psd4, HashToScalarPSD4, ITERATIONS);
+
+#[test]
+fn test_hash_to_scalar_matches_native() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize the destination type.
+    let destination_type = PlaintextType::Literal(LiteralType::Scalar);
+
+    for _ in 0..ITERATIONS {
+        let literal = Literal::Field(Uniform::rand(&mut rng));
+        let fields = Value::Plaintext(Plaintext::from(literal.clone())).to_fields()?;
+
+        macro_rules! check_native {
+            ($hash:ident, $native:ident) => {{
+                let opcode = $hash::<CurrentNetwork>::opcode();
+                let (stack, operands, destination) =
+                    sample_stack(opcode, literal.to_type(), circuit::Mode::Private, destination_type.clone())?;
+                let operation = $hash::<CurrentNetwork>::new(operands, destination.clone(), destination_type.clone())?;
+                let destination_operand = Operand::Register(destination);
+
+                // Evaluate the operation.
+                let mut evaluate_registers = sample_registers(&stack, &function_name, &[(&literal, None)])?;
+                operation.evaluate(&stack, &mut evaluate_registers)?;
+                let output_a = evaluate_registers.load(&stack, &destination_operand)?;
+
+                // Execute the operation.
+                let mode = Some(circuit::Mode::Private);
+                let mut execute_registers = sample_registers(&stack, &function_name, &[(&literal, mode)])?;
+                operation.execute::<CurrentAleo>(&stack, &mut execute_registers)?;
+                let output_b = execute_registers.load_circuit(&stack, &destination_operand)?;
+
+                // Ensure the evaluation, execution, and native hash agree.
+                let expected = Value::Plaintext(Plaintext::from(Literal::Scalar(CurrentNetwork::$native(&fields)?)));
+                assert_eq!(output_a, expected, "'{opcode}' disagrees with the native hash");
+                assert_eq!(output_b.eject_value(), expected, "'{opcode}' disagrees with the native hash in-circuit");
+                assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "The circuit is not satisfied");
+                <CurrentAleo as circuit::Environment>::reset();
+            }};
+        }
+
+        check_native!(HashToScalarPSD2, hash_to_scalar_psd2);
+        check_native!(HashToScalarPSD4, hash_to_scalar_psd4);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_hash_to_field_matches_native() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize the destination type.
+    let destination_type = PlaintextType::Literal(LiteralType::Field);
+
+    for _ in 0..ITERATIONS {
+        let literal = Literal::Field(Uniform::rand(&mut rng));
+        let value = Value::Plaintext(Plaintext::from(literal.clone()));
+        let (fields, bits) = (value.to_fields()?, value.to_bits_le());
+
+        macro_rules! check_native {
+            ($hash:ident, $native:ident, $input:expr) => {{
+                let opcode = $hash::<CurrentNetwork>::opcode();
+                let (stack, operands, destination) =
+                    sample_stack(opcode, literal.to_type(), circuit::Mode::Private, destination_type.clone())?;
+                let operation = $hash::<CurrentNetwork>::new(operands, destination.clone(), destination_type.clone())?;
+                let destination_operand = Operand::Register(destination);
+
+                // Evaluate the operation.
+                let mut evaluate_registers = sample_registers(&stack, &function_name, &[(&literal, None)])?;
+                operation.evaluate(&stack, &mut evaluate_registers)?;
+                let output_a = evaluate_registers.load(&stack, &destination_operand)?;
+
+                // Execute the operation.
+                let mode = Some(circuit::Mode::Private);
+                let mut execute_registers = sample_registers(&stack, &function_name, &[(&literal, mode)])?;
+                operation.execute::<CurrentAleo>(&stack, &mut execute_registers)?;
+                let output_b = execute_registers.load_circuit(&stack, &destination_operand)?;
+
+                // Ensure the evaluation, execution, and native hash agree.
+                let expected = Value::Plaintext(Plaintext::from(Literal::Field(CurrentNetwork::$native($input)?)));
+                assert_eq!(output_a, expected, "'{opcode}' disagrees with the native hash");
+                assert_eq!(output_b.eject_value(), expected, "'{opcode}' disagrees with the native hash in-circuit");
+                assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "The circuit is not satisfied");
+                <CurrentAleo as circuit::Environment>::reset();
+            }};
+        }
+
+        check_native!(HashBHP256, hash_bhp256, &bits);
+        check_native!(HashPSD2, hash_psd2, &fields);
+        check_native!(HashPSD4, hash_psd4, &fields);
+        check_native!(HashPSD8, hash_psd8, &fields);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_hash_to_group_matches_native() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    hash_to_group.psd8 r0 into r1;
+    output r1 as group.private;",
+    )?;
+    // Initialize the stack.
+    let stack = Stack::new(&Process::load()?, &program)?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize the operation.
+    let operation = HashToGroupPSD8::<CurrentNetwork>::from_str("hash_to_group.psd8 r0 into r1")?;
+    // Initialize the destination operand.
+    let destination_operand = Operand::Register(Register::Locator(1));
+
+    for _ in 0..ITERATIONS {
+        let literal = Literal::Field(Uniform::rand(&mut rng));
+        let fields = Value::Plaintext(Plaintext::from(literal.clone())).to_fields()?;
+
+        // Evaluate the operation.
+        let mut evaluate_registers = sample_registers(&stack, &function_name, &[(&literal, None)])?;
+        operation.evaluate(&stack, &mut evaluate_registers)?;
+        let output_a = evaluate_registers.load(&stack, &destination_operand)?;
+
+        // Execute the operation.
+        let mode = Some(circuit::Mode::Private);
+        let mut execute_registers = sample_registers(&stack, &function_name, &[(&literal, mode)])?;
+        operation.execute::<CurrentAleo>(&stack, &mut execute_registers)?;
+        let output_b = execute_registers.load_circuit(&stack, &destination_operand)?;
+
+        // Ensure the evaluation, execution, and native hash agree.
+        let group = CurrentNetwork::hash_to_group_psd8(&fields)?;
+        let expected = Value::Plaintext(Plaintext::from(Literal::Group(group)));
+        assert_eq!(output_a, expected, "'hash_to_group.psd8' disagrees with the native hash");
+        assert_eq!(output_b.eject_value(), expected, "'hash_to_group.psd8' disagrees with the native hash in-circuit");
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "The circuit is not satisfied");
+        <CurrentAleo as circuit::Environment>::reset();
+
+        // Ensure the output is on the curve and in the prime-order subgroup.
+        assert_eq!(console::types::Group::from_x_coordinate(group.to_x_coordinate())?, group);
+    }
+    Ok(())
+}
+
 // Note this test must be explicitly written, instead of using the macro, because HashPED64 fails on certain input types.
 #[test]
 fn test_hash_ped64_is_consistent() {
@@ -0,0 +1,127 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Register, Value},
+};
+use snarkvm_synthesizer_program::{
+    FinalizeGlobalState,
+    HashBytesKeccak256,
+    HashBytesSha256,
+    Operand,
+    Program,
+    RegistersLoad,
+    RegistersStore,
+};
+use synthesizer_process::{FinalizeRegisters, Process, Stack, StackProgramTypes};
+
+type CurrentNetwork = MainnetV0;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as [u8; 3u32].public;
+    async run r0 into r1;
+    output r1 as testing.aleo/run.future;
+
+finalize run:
+    input r0 as [u8; 3u32].public;
+    hash_bytes.sha256 r0 into r1;
+    hash_bytes.keccak256 r0 into r2;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Returns the given hex string as a `u8` array value.
+fn hex_to_value(hex: &str) -> Result<Value<CurrentNetwork>> {
+    let bytes =
+        (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16)).collect::<Result<Vec<_>, _>>()?;
+    Value::from_str(&format!("[{}]", bytes.iter().map(|byte| format!("{byte}u8")).collect::<Vec<_>>().join(", ")))
+}
+
+#[test]
+fn test_hash_bytes_known_vectors() -> Result<()> {
+    // Initialize the stack.
+    let stack = sample_stack()?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+
+    // Initialize the finalize registers, with the input "abc".
+    let mut registers = FinalizeRegisters::<CurrentNetwork>::new(
+        FinalizeGlobalState::from(1, 1, [0; 32]),
+        <CurrentNetwork as Network>::TransitionID::default(),
+        function_name,
+        stack.get_finalize_types(&function_name)?.clone(),
+    );
+    registers.store(&stack, &Register::Locator(0), Value::from_str("[97u8, 98u8, 99u8]")?)?;
+
+    // Ensure the SHA-256 digest matches the known vector.
+    let operation = HashBytesSha256::<CurrentNetwork>::from_str("hash_bytes.sha256 r0 into r1")?;
+    operation.finalize(&stack, &mut registers)?;
+    let expected = hex_to_value("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")?;
+    assert_eq!(registers.load(&stack, &Operand::Register(Register::Locator(1)))?, expected);
+
+    // Ensure the Keccak-256 digest matches the known vector.
+    let operation = HashBytesKeccak256::<CurrentNetwork>::from_str("hash_bytes.keccak256 r0 into r2")?;
+    operation.finalize(&stack, &mut registers)?;
+    let expected = hex_to_value("4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45")?;
+    assert_eq!(registers.load(&stack, &Operand::Register(Register::Locator(2)))?, expected);
+    Ok(())
+}
+
+#[test]
+fn test_hash_bytes_is_not_supported_in_functions() -> Result<()> {
+    // Ensure the instruction is rejected outside of `finalize`.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as [u8; 3u32].private;
+    hash_bytes.sha256 r0 into r1;
+    output r1 as [u8; 32u32].private;",
+    )?;
+    assert!(Stack::new(&Process::load()?, &program).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_hash_bytes_rejects_non_byte_input() -> Result<()> {
+    // Ensure a non-`u8` array fails to type check.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as [u16; 3u32].public;
+    async run r0 into r1;
+    output r1 as testing.aleo/run.future;
+
+finalize run:
+    input r0 as [u16; 3u32].public;
+    hash_bytes.sha256 r0 into r1;",
+    )?;
+    assert!(Stack::new(&Process::load()?, &program).is_err());
+    Ok(())
+}
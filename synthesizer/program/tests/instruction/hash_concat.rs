@@ -0,0 +1,106 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::sample::sample_registers;
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::{Field, U64},
+};
+use snarkvm_synthesizer_program::{HashConcatPSD8, HashPSD8, Operand, Program, RegistersLoad, RegistersLoadCircuit};
+use synthesizer_process::{Process, Stack};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 10;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    input r1 as u64.private;
+    input r2 as field.private;
+    hash.concat.psd8 r0 r1 r2 into r3;
+    hash.concat.psd8 r0 into r4;
+    hash.psd8 r0 into r5 as field;
+    output r3 as field.private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+#[test]
+fn test_hash_concat_matches_separate_hash() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+    // Initialize the operations.
+    let concat = HashConcatPSD8::<CurrentNetwork>::from_str("hash.concat.psd8 r0 r1 r2 into r3")?;
+    let single = HashConcatPSD8::<CurrentNetwork>::from_str("hash.concat.psd8 r0 into r4")?;
+    let hash = HashPSD8::<CurrentNetwork>::from_str("hash.psd8 r0 into r5 as field")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+
+    for _ in 0..ITERATIONS {
+        // Sample the inputs.
+        let inputs = [
+            Literal::Field(Field::<CurrentNetwork>::rand(&mut rng)),
+            Literal::U64(U64::rand(&mut rng)),
+            Literal::Field(Field::<CurrentNetwork>::rand(&mut rng)),
+        ];
+
+        // Evaluate the operations.
+        let values = inputs.iter().map(|input| (input, None)).collect::<Vec<_>>();
+        let mut registers = sample_registers(&stack, &function_name, &values)?;
+        concat.evaluate(&stack, &mut registers)?;
+        single.evaluate(&stack, &mut registers)?;
+        hash.evaluate(&stack, &mut registers)?;
+        let output = registers.load(&stack, &Operand::Register(Register::Locator(3)))?;
+
+        // Ensure the output matches `hash.psd8` over the concatenated fields.
+        let mut preimage = Vec::new();
+        for input in &inputs {
+            preimage.extend(Value::Plaintext(Plaintext::from(input)).to_fields()?);
+        }
+        let expected = <CurrentNetwork as Network>::hash_psd8(&preimage)?;
+        assert_eq!(output, Value::Plaintext(Plaintext::from(Literal::Field(expected))));
+        // Ensure a single operand matches `hash.psd8`.
+        assert_eq!(
+            registers.load(&stack, &Operand::Register(Register::Locator(4)))?,
+            registers.load(&stack, &Operand::Register(Register::Locator(5)))?
+        );
+
+        // Execute the operation, and ensure it matches the evaluation.
+        let values = inputs.iter().map(|input| (input, Some(circuit::Mode::Private))).collect::<Vec<_>>();
+        let mut registers = sample_registers(&stack, &function_name, &values)?;
+        concat.execute::<CurrentAleo>(&stack, &mut registers)?;
+        let circuit_output = registers.load_circuit(&stack, &Operand::Register(Register::Locator(3)))?;
+        assert_eq!(output, circuit_output.eject_value());
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+    }
+    Ok(())
+}
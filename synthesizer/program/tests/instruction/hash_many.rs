@@ -0,0 +1,116 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::sample::sample_registers;
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::Field,
+};
+use snarkvm_synthesizer_program::{HashManyPSD2, Operand, Program, RegistersLoad, RegistersLoadCircuit};
+use synthesizer_process::{Process, Stack};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 25;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    hash_many.psd2 r0 4u32 into r1;
+    output r1 as [field; 4u32].private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+#[test]
+fn test_hash_many_psd2() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    let stack = sample_stack()?;
+
+    // Initialize the operation.
+    let operation = HashManyPSD2::<CurrentNetwork>::from_str("hash_many.psd2 r0 4u32 into r1")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(1));
+
+    for _ in 0..ITERATIONS {
+        let input = Field::<CurrentNetwork>::rand(&mut rng);
+        let literal = Literal::Field(input);
+        let mut registers = sample_registers(&stack, &function_name, &[(&literal, Some(circuit::Mode::Private))])?;
+
+        // Evaluate the operation.
+        operation.evaluate(&stack, &mut registers)?;
+        let output_a = registers.load(&stack, &destination_operand)?;
+
+        // Execute the operation.
+        operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+        let output_b = registers.load_circuit(&stack, &destination_operand)?;
+
+        // Ensure the evaluation and execution agree.
+        assert_eq!(output_a, output_b.eject_value(), "The evaluation and execution disagree");
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "The circuit is not satisfied");
+        <CurrentAleo as circuit::Environment>::reset();
+
+        // Ensure the output is an array of 4 fields, matching the native hash.
+        let elements = match output_a {
+            Value::Plaintext(Plaintext::Array(elements, _)) => elements,
+            _ => bail!("Expected an array output"),
+        };
+        assert_eq!(elements.len(), 4, "The output array length is incorrect");
+        let expected = <CurrentNetwork as Network>::hash_many_psd2(&[input], 4);
+        for (element, expected) in elements.iter().zip_eq(expected) {
+            assert_eq!(element, &Plaintext::from(Literal::Field(expected)));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_hash_many_rejects_invalid_number_of_outputs() -> Result<()> {
+    for (num_outputs, output_type) in [("r1", "[field; 4u32]"), ("4u64", "[field; 4u32]"), ("4u32", "[field; 3u32]")] {
+        // Initialize a program with an invalid number of outputs.
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    input r1 as u32.private;
+    hash_many.psd2 r0 {num_outputs} into r2;
+    output r2 as {output_type}.private;"
+        ));
+
+        // Ensure the program fails to parse or type-check.
+        if let Ok(program) = program {
+            assert!(Stack::new(&Process::load()?, &program).is_err());
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,118 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use circuit::{AleoV0, Eject, Inject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::Field,
+};
+use snarkvm_synthesizer_program::{
+    HashMulti,
+    Operand,
+    Program,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+};
+use synthesizer_process::{Authorization, CallStack, Process, Registers, Stack, StackProgramTypes};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 10;
+const NUM_DOMAINS: usize = 3;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(&format!(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    input r1 as [field; {NUM_DOMAINS}u32].private;
+    hash.multi r0 r1 into r2;
+    output r2 as [field; {NUM_DOMAINS}u32].private;"
+    ))?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+#[test]
+fn test_hash_multi() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+    // Initialize the operation.
+    let operation = HashMulti::<CurrentNetwork>::from_str("hash.multi r0 r1 into r2")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(2));
+
+    for _ in 0..ITERATIONS {
+        // Sample the input and the domains.
+        let input = Value::Plaintext(Plaintext::from(Literal::Field(Field::<CurrentNetwork>::rand(&mut rng))));
+        let domains: Vec<_> = (0..NUM_DOMAINS).map(|_| Field::<CurrentNetwork>::rand(&mut rng)).collect();
+        let domains_value = Value::Plaintext(Plaintext::Array(
+            domains.iter().map(|domain| Plaintext::from(Literal::Field(*domain))).collect(),
+            Default::default(),
+        ));
+
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::try_from((vec![], vec![]))?)?,
+            stack.get_register_types(&function_name)?.clone(),
+        );
+        for (index, value) in [&input, &domains_value].into_iter().enumerate() {
+            let register = Register::Locator(index as u64);
+            registers.store(&stack, &register, value.clone())?;
+            registers.store_circuit(&stack, &register, circuit::Value::new(circuit::Mode::Private, value.clone()))?;
+        }
+
+        // Evaluate and execute the operation.
+        operation.evaluate(&stack, &mut registers)?;
+        let output_a = registers.load(&stack, &destination_operand)?;
+        operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+        let output_b = registers.load_circuit(&stack, &destination_operand)?;
+        assert_eq!(output_a, output_b.eject_value(), "The evaluation and execution disagree");
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "The circuit is not satisfied");
+        <CurrentAleo as circuit::Environment>::reset();
+
+        // Retrieve the outputs.
+        let outputs = match output_a {
+            Value::Plaintext(Plaintext::Array(outputs, _)) => outputs,
+            _ => bail!("Expected a field array"),
+        };
+        assert_eq!(outputs.len(), NUM_DOMAINS);
+
+        // Ensure each output is the hash of the input under its domain.
+        for (domain, output) in domains.iter().zip_eq(&outputs) {
+            let expected = CurrentNetwork::hash_psd2(&[vec![*domain], input.to_fields()?].concat())?;
+            assert_eq!(output, &Plaintext::from(Literal::Field(expected)));
+        }
+        // Ensure the outputs are distinct per domain.
+        for (i, output) in outputs.iter().enumerate() {
+            assert!(outputs[i + 1..].iter().all(|other| other != output), "Found a repeated output");
+        }
+    }
+    Ok(())
+}
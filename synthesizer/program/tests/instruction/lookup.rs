@@ -0,0 +1,138 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::U8,
+};
+use snarkvm_synthesizer_program::{
+    Lookup,
+    Operand,
+    Program,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+};
+use synthesizer_process::{Authorization, CallStack, Process, Registers, Stack, StackProgramTypes};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+/// The S-box used as the lookup table.
+const TABLE: [u8; 8] = [0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5];
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as u8.private;
+    input r1 as [u8; 8u32].private;
+    lookup r0 r1 into r2;
+    output r2 as u8.private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Returns the registers for `run`, storing the given index and table.
+fn sample_registers(stack: &Stack<CurrentNetwork>, index: u8) -> Result<Registers<CurrentNetwork, CurrentAleo>> {
+    // Initialize the registers.
+    let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+        CallStack::evaluate(Authorization::try_from((vec![], vec![]))?)?,
+        stack.get_register_types(&Identifier::from_str("run")?)?.clone(),
+    );
+    // Store the inputs, injecting the table as a constant.
+    let index = Value::Plaintext(Plaintext::from(Literal::U8(U8::new(index))));
+    let table = Value::Plaintext(Plaintext::Array(
+        TABLE.iter().map(|entry| Plaintext::from(Literal::U8(U8::new(*entry)))).collect(),
+        Default::default(),
+    ));
+    for (index, (input, mode)) in
+        [(index, circuit::Mode::Private), (table, circuit::Mode::Constant)].into_iter().enumerate()
+    {
+        let register = Register::Locator(index as u64);
+        registers.store(stack, &register, input.clone())?;
+        registers.store_circuit(stack, &register, circuit::Value::new(mode, input))?;
+    }
+    Ok(registers)
+}
+
+#[test]
+fn test_lookup() -> Result<()> {
+    // Initialize the stack.
+    let stack = sample_stack()?;
+    // Initialize the operation.
+    let operation = Lookup::<CurrentNetwork>::from_str("lookup r0 r1 into r2")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(2));
+
+    for (index, entry) in TABLE.iter().enumerate() {
+        // Evaluate and execute the operation.
+        let mut registers = sample_registers(&stack, index as u8)?;
+        operation.evaluate(&stack, &mut registers)?;
+        let output_a = registers.load(&stack, &destination_operand)?;
+        operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+        let output_b = registers.load_circuit(&stack, &destination_operand)?;
+
+        // Ensure the output is the table entry at the index.
+        assert_eq!(output_a, Value::Plaintext(Plaintext::from(Literal::U8(U8::new(*entry)))));
+        assert_eq!(output_a, output_b.eject_value());
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+    }
+    Ok(())
+}
+
+#[test]
+fn test_lookup_out_of_bounds() -> Result<()> {
+    // Initialize the stack.
+    let stack = sample_stack()?;
+    // Initialize the operation.
+    let operation = Lookup::<CurrentNetwork>::from_str("lookup r0 r1 into r2")?;
+
+    // Ensure an out-of-bounds index fails to evaluate, and leaves the circuit unsatisfied.
+    let mut registers = sample_registers(&stack, TABLE.len() as u8)?;
+    assert!(operation.evaluate(&stack, &mut registers).is_err());
+    operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+    assert!(!<CurrentAleo as circuit::Environment>::is_satisfied());
+    <CurrentAleo as circuit::Environment>::reset();
+    Ok(())
+}
+
+#[test]
+fn test_lookup_rejects_invalid_index() -> Result<()> {
+    // Ensure a field index fails to type check.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    input r1 as [u8; 8u32].private;
+    lookup r0 r1 into r2;
+    output r2 as u8.private;",
+    )?;
+    assert!(Stack::new(&Process::load()?, &program).is_err());
+    Ok(())
+}
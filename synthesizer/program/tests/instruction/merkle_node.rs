@@ -0,0 +1,101 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::sample::sample_registers;
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::Field,
+};
+use snarkvm_synthesizer_program::{MerkleNode, Operand, Program, RegistersLoad, RegistersLoadCircuit};
+use synthesizer_process::{Process, Stack};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 25;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    input r1 as field.private;
+    merkle.node r0 r1 into r2;
+    output r2 as field.private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+#[test]
+fn test_merkle_node() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+    // Initialize the operation.
+    let operation = MerkleNode::<CurrentNetwork>::from_str("merkle.node r0 r1 into r2")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(2));
+
+    for _ in 0..ITERATIONS {
+        // Sample the children.
+        let left = Field::<CurrentNetwork>::rand(&mut rng);
+        let right = Field::<CurrentNetwork>::rand(&mut rng);
+        let (left_literal, right_literal) = (Literal::Field(left), Literal::Field(right));
+
+        // Evaluate the operation.
+        let values = [(&left_literal, None), (&right_literal, None)];
+        let mut evaluate_registers = sample_registers(&stack, &function_name, &values)?;
+        operation.evaluate(&stack, &mut evaluate_registers)?;
+        let output_a = evaluate_registers.load(&stack, &destination_operand)?;
+
+        // Execute the operation.
+        let mode = Some(circuit::Mode::Private);
+        let values = [(&left_literal, mode), (&right_literal, mode)];
+        let mut execute_registers = sample_registers(&stack, &function_name, &values)?;
+        operation.execute::<CurrentAleo>(&stack, &mut execute_registers)?;
+        let output_b = execute_registers.load_circuit(&stack, &destination_operand)?;
+
+        // Ensure the evaluation and execution agree.
+        assert_eq!(output_a, output_b.eject_value(), "The evaluation and execution disagree");
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "The circuit is not satisfied");
+        <CurrentAleo as circuit::Environment>::reset();
+
+        // Ensure the domain tag separates the node hash from a plain `hash.psd2` of the children.
+        let plain = Value::Plaintext(Plaintext::from(Literal::Field(CurrentNetwork::hash_psd2(&[left, right])?)));
+        assert_ne!(output_a, plain, "The node hash must be domain-separated from 'hash.psd2'");
+
+        // Ensure the node hash is not symmetric in its children.
+        if left != right {
+            let swapped = [(&right_literal, None), (&left_literal, None)];
+            let mut swapped_registers = sample_registers(&stack, &function_name, &swapped)?;
+            operation.evaluate(&stack, &mut swapped_registers)?;
+            assert_ne!(output_a, swapped_registers.load(&stack, &destination_operand)?);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,155 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::Field,
+};
+use snarkvm_synthesizer_program::{
+    MerkleRootPSD2,
+    Operand,
+    Program,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+};
+use synthesizer_process::{Authorization, CallStack, Process, Registers, Stack, StackProgramTypes};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 10;
+
+/// Samples the stack for an array of `num_leaves` leaves. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack(num_leaves: usize) -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(&format!(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as [field; {num_leaves}u32].private;
+    merkle.root.psd2 r0 into r1;
+    output r1 as field.private;"
+    ))?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Returns the Merkle node of the given children.
+fn merkle_node(left: Field<CurrentNetwork>, right: Field<CurrentNetwork>) -> Result<Field<CurrentNetwork>> {
+    CurrentNetwork::hash_psd2(&[Field::new_domain_separator("AleoMerkleNode0"), left, right])
+}
+
+/// Evaluates and executes the operation on the given leaves, and returns the root.
+fn check_merkle_root(stack: &Stack<CurrentNetwork>, leaves: &[Field<CurrentNetwork>]) -> Result<Field<CurrentNetwork>> {
+    // Initialize the operation.
+    let operation = MerkleRootPSD2::<CurrentNetwork>::from_str("merkle.root.psd2 r0 into r1")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(1));
+
+    // Initialize the registers.
+    let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+        CallStack::evaluate(Authorization::try_from((vec![], vec![]))?)?,
+        stack.get_register_types(&function_name)?.clone(),
+    );
+    // Store the leaves in the console and circuit registers.
+    let leaves = leaves.iter().map(|leaf| Plaintext::from(Literal::Field(*leaf))).collect::<Vec<_>>();
+    let value = Value::Plaintext(Plaintext::Array(leaves, Default::default()));
+    let register = Register::Locator(0);
+    registers.store(stack, &register, value.clone())?;
+    registers.store_circuit(stack, &register, circuit::Value::new(circuit::Mode::Private, value))?;
+
+    // Evaluate the operation.
+    operation.evaluate(stack, &mut registers)?;
+    let output_a = registers.load(stack, &destination_operand)?;
+
+    // Execute the operation.
+    operation.execute::<CurrentAleo>(stack, &mut registers)?;
+    let output_b = registers.load_circuit(stack, &destination_operand)?;
+
+    // Ensure the evaluation and execution agree.
+    assert_eq!(output_a, output_b.eject_value(), "The evaluation and execution disagree");
+    assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "The circuit is not satisfied");
+    <CurrentAleo as circuit::Environment>::reset();
+
+    match output_a {
+        Value::Plaintext(Plaintext::Literal(Literal::Field(output), _)) => Ok(output),
+        _ => bail!("Expected a field output"),
+    }
+}
+
+#[test]
+fn test_merkle_root() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stacks.
+    let stack_1 = sample_stack(1)?;
+    let stack_4 = sample_stack(4)?;
+    let stack_5 = sample_stack(5)?;
+
+    for _ in 0..ITERATIONS {
+        let leaves: Vec<Field<CurrentNetwork>> = (0..5).map(|_| Uniform::rand(&mut rng)).collect();
+        let zero = Field::zero();
+
+        // Ensure the root of a single leaf is the leaf.
+        assert_eq!(leaves[0], check_merkle_root(&stack_1, &leaves[..1])?);
+
+        // Ensure the root of four leaves matches the tree constructed with `merkle.node`.
+        let left = merkle_node(leaves[0], leaves[1])?;
+        let right = merkle_node(leaves[2], leaves[3])?;
+        assert_eq!(merkle_node(left, right)?, check_merkle_root(&stack_4, &leaves[..4])?);
+
+        // Ensure the root of five leaves matches the tree constructed with three `0field` padding leaves.
+        let padded = merkle_node(merkle_node(leaves[4], zero)?, merkle_node(zero, zero)?)?;
+        let expected = merkle_node(merkle_node(left, right)?, padded)?;
+        assert_eq!(expected, check_merkle_root(&stack_5, &leaves)?);
+
+        // Ensure the root changes if any leaf changes.
+        for i in 0..leaves.len() {
+            let mut tampered = leaves.clone();
+            tampered[i] = Uniform::rand(&mut rng);
+            assert_ne!(expected, check_merkle_root(&stack_5, &tampered)?);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_root_rejects_invalid_operand_type() -> Result<()> {
+    for input_type in ["field", "[u64; 4u32]", "[[field; 2u32]; 2u32]"] {
+        // Initialize a program whose operand is not an array of fields.
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            r"
+program testing.aleo;
+
+function run:
+    input r0 as {input_type}.private;
+    merkle.root.psd2 r0 into r1;
+    output r1 as field.private;"
+        ))?;
+
+        // Ensure the program fails to type-check.
+        assert!(Stack::new(&Process::load()?, &program).is_err(), "The operand type '{input_type}' was accepted");
+    }
+    Ok(())
+}
@@ -0,0 +1,180 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::Field,
+};
+use snarkvm_synthesizer_program::{
+    MerkleVerify,
+    Operand,
+    Program,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+};
+use synthesizer_process::{Authorization, CallStack, Process, Registers, Stack, StackProgramTypes};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 10;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+struct level:
+    sibling as field;
+    is_left as boolean;
+
+function run:
+    input r0 as field.private;
+    input r1 as field.private;
+    input r2 as [level; 2u32].private;
+    merkle.verify r0 r1 r2 into r3;
+    output r3 as boolean.private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Returns the Merkle node of the given children.
+fn merkle_node(left: Field<CurrentNetwork>, right: Field<CurrentNetwork>) -> Result<Field<CurrentNetwork>> {
+    CurrentNetwork::hash_psd2(&[Field::new_domain_separator("AleoMerkleNode0"), left, right])
+}
+
+/// Returns the path value for the given `(sibling, is_left)` levels.
+fn path_value(path: &[(Field<CurrentNetwork>, bool)]) -> Result<Value<CurrentNetwork>> {
+    let levels = path.iter().map(|(sibling, is_left)| format!("{{ sibling: {sibling}, is_left: {is_left} }}"));
+    Value::from_str(&format!("[{}]", levels.collect::<Vec<_>>().join(", ")))
+}
+
+/// Evaluates and executes the operation on the given inputs, and returns the outcome.
+fn check_merkle_verify(
+    stack: &Stack<CurrentNetwork>,
+    root: Field<CurrentNetwork>,
+    leaf: Field<CurrentNetwork>,
+    path: &[(Field<CurrentNetwork>, bool)],
+) -> Result<bool> {
+    // Initialize the operation.
+    let operation = MerkleVerify::<CurrentNetwork>::from_str("merkle.verify r0 r1 r2 into r3")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(3));
+
+    // Initialize the registers.
+    let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+        CallStack::evaluate(Authorization::try_from((vec![], vec![]))?)?,
+        stack.get_register_types(&function_name)?.clone(),
+    );
+    // Store the inputs in the console and circuit registers.
+    let root = Value::Plaintext(Plaintext::from(Literal::Field(root)));
+    let leaf = Value::Plaintext(Plaintext::from(Literal::Field(leaf)));
+    for (index, value) in [root, leaf, path_value(path)?].into_iter().enumerate() {
+        let register = Register::Locator(index as u64);
+        registers.store(stack, &register, value.clone())?;
+        registers.store_circuit(stack, &register, circuit::Value::new(circuit::Mode::Private, value))?;
+    }
+
+    // Evaluate the operation.
+    operation.evaluate(stack, &mut registers)?;
+    let output_a = registers.load(stack, &destination_operand)?;
+
+    // Execute the operation.
+    operation.execute::<CurrentAleo>(stack, &mut registers)?;
+    let output_b = registers.load_circuit(stack, &destination_operand)?;
+
+    // Ensure the evaluation and execution agree.
+    assert_eq!(output_a, output_b.eject_value(), "The evaluation and execution disagree");
+    assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "The circuit is not satisfied");
+    <CurrentAleo as circuit::Environment>::reset();
+
+    match output_a {
+        Value::Plaintext(Plaintext::Literal(Literal::Boolean(output), _)) => Ok(*output),
+        _ => bail!("Expected a boolean output"),
+    }
+}
+
+#[test]
+fn test_merkle_verify() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+
+    for _ in 0..ITERATIONS {
+        // Sample a tree with four leaves.
+        let leaves: Vec<Field<CurrentNetwork>> = (0..4).map(|_| Uniform::rand(&mut rng)).collect();
+        let left = merkle_node(leaves[0], leaves[1])?;
+        let right = merkle_node(leaves[2], leaves[3])?;
+        let root = merkle_node(left, right)?;
+
+        // Ensure the path of every leaf verifies.
+        let paths = [
+            [(leaves[1], false), (right, false)],
+            [(leaves[0], true), (right, false)],
+            [(leaves[3], false), (left, true)],
+            [(leaves[2], true), (left, true)],
+        ];
+        for (leaf, path) in leaves.iter().zip_eq(&paths) {
+            assert!(check_merkle_verify(&stack, root, *leaf, path)?, "The valid proof was rejected");
+        }
+
+        // Ensure a tampered leaf is rejected.
+        let tampered_leaf = Uniform::rand(&mut rng);
+        assert!(!check_merkle_verify(&stack, root, tampered_leaf, &paths[0])?, "The tampered leaf was accepted");
+
+        // Ensure a tampered sibling is rejected.
+        let tampered_path = [(Uniform::rand(&mut rng), false), (right, false)];
+        assert!(!check_merkle_verify(&stack, root, leaves[0], &tampered_path)?, "The tampered sibling was accepted");
+
+        // Ensure a tampered direction is rejected.
+        let tampered_path = [(leaves[1], true), (right, false)];
+        assert!(!check_merkle_verify(&stack, root, leaves[0], &tampered_path)?, "The tampered direction was accepted");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_verify_rejects_invalid_path_type() -> Result<()> {
+    // Initialize a program whose path levels are not (field, boolean) structs.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+struct level:
+    is_left as boolean;
+    sibling as field;
+
+function run:
+    input r0 as field.private;
+    input r1 as field.private;
+    input r2 as [level; 2u32].private;
+    merkle.verify r0 r1 r2 into r3;
+    output r3 as boolean.private;",
+    )?;
+    assert!(Stack::new(&Process::load()?, &program).is_err());
+    Ok(())
+}
@@ -13,7 +13,36 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod abs_diff;
+mod address_fingerprint;
 mod assert;
+mod assert_bool;
+mod assert_nonzero;
+mod bits_to_int;
 mod commit;
+mod commit_add;
+mod commit_chain;
+mod commit_ciphertext;
+mod commit_zero;
+mod generator;
+mod get_member;
 mod hash;
+mod hash_bytes;
+mod hash_concat;
+mod hash_many;
+mod hash_multi;
 mod is;
+mod lookup;
+mod merkle_node;
+mod merkle_root;
+mod merkle_verify;
+mod popcount;
+mod pow_const;
+mod randomizer;
+mod reveal;
+mod serial_number;
+mod shuffle;
+mod state_hash;
+mod ternary;
+mod verify_commitments;
+mod xor_field;
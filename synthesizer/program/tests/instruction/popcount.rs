@@ -0,0 +1,109 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::sample::sample_registers;
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, LiteralType, Plaintext, Register, Value},
+    types::{I128, I64, I8, U128, U16, U32, U64, U8},
+};
+use snarkvm_synthesizer_program::{Operand, Popcount, Program, RegistersLoad, RegistersLoadCircuit};
+use synthesizer_process::{Process, Stack};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 25;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack(literal_type: LiteralType) -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(&format!(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as {literal_type}.private;
+    popcount r0 into r1;
+    output r1 as u32.private;"
+    ))?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+fn check_popcount(literal: &Literal<CurrentNetwork>, expected: u32) {
+    // Initialize the stack.
+    let stack = sample_stack(literal.to_type()).unwrap();
+    // Initialize the operation.
+    let operation = Popcount::<CurrentNetwork>::from_str("popcount r0 into r1").unwrap();
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run").unwrap();
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(1));
+
+    // Evaluate the operation.
+    let mut evaluate_registers = sample_registers(&stack, &function_name, &[(literal, None)]).unwrap();
+    operation.evaluate(&stack, &mut evaluate_registers).unwrap();
+    let output_a = evaluate_registers.load(&stack, &destination_operand).unwrap();
+
+    // Execute the operation.
+    let mode = Some(circuit::Mode::Private);
+    let mut execute_registers = sample_registers(&stack, &function_name, &[(literal, mode)]).unwrap();
+    operation.execute::<CurrentAleo>(&stack, &mut execute_registers).unwrap();
+    let output_b = execute_registers.load_circuit(&stack, &destination_operand).unwrap();
+
+    // Check the outputs.
+    let expected = Value::Plaintext(Plaintext::from(Literal::U32(U32::new(expected))));
+    assert_eq!(expected, output_a, "The result of the evaluation is incorrect for '{literal}'");
+    assert_eq!(expected, output_b.eject_value(), "The result of the execution is incorrect for '{literal}'");
+    assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "The circuit is not satisfied");
+
+    // Reset the circuit.
+    <CurrentAleo as circuit::Environment>::reset();
+}
+
+#[test]
+fn test_popcount_known_values() {
+    check_popcount(&Literal::U8(U8::new(0)), 0);
+    check_popcount(&Literal::U8(U8::new(u8::MAX)), 8);
+    check_popcount(&Literal::U16(U16::new(0b1010_0000_0000_0001)), 3);
+    check_popcount(&Literal::U32(U32::new(0b1011)), 3);
+    check_popcount(&Literal::U64(U64::new(1 << 63)), 1);
+    check_popcount(&Literal::U128(U128::new(u128::MAX)), 128);
+    check_popcount(&Literal::I8(I8::new(-1)), 8);
+    check_popcount(&Literal::I64(I64::new(i64::MIN)), 1);
+    check_popcount(&Literal::I128(I128::new(0x0f0f)), 8);
+}
+
+#[test]
+fn test_popcount_random_values() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let value = U64::<CurrentNetwork>::rand(&mut rng);
+        check_popcount(&Literal::U64(value), value.count_ones());
+    }
+}
+
+#[test]
+fn test_popcount_rejects_non_integer() {
+    // Ensure a program that counts the bits of a field is rejected.
+    assert!(sample_stack(LiteralType::Field).is_err());
+    assert!(sample_stack(LiteralType::Boolean).is_err());
+}
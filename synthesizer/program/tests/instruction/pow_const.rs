@@ -0,0 +1,125 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::sample::sample_registers;
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::Field,
+};
+use snarkvm_synthesizer_program::{Operand, PowConst, Program, RegistersLoad, RegistersLoadCircuit};
+use synthesizer_process::{Process, Stack};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 25;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack(exponent: u8) -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(&format!(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    pow.const r0 {exponent} into r1;
+    output r1 as field.private;"
+    ))?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+#[test]
+fn test_pow_const() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(1));
+
+    for exponent in [1u8, 2, 3, 5, 7, 16, 64] {
+        // Initialize the stack and the operation.
+        let stack = sample_stack(exponent)?;
+        let operation = PowConst::<CurrentNetwork>::from_str(&format!("pow.const r0 {exponent} into r1"))?;
+
+        for _ in 0..ITERATIONS {
+            // Sample the input.
+            let input = Field::<CurrentNetwork>::rand(&mut rng);
+            let literal = Literal::Field(input);
+            let expected = Value::Plaintext(Plaintext::from(Literal::Field(input.pow(Field::from_u8(exponent)))));
+
+            // Evaluate the operation.
+            let mut registers = sample_registers(&stack, &function_name, &[(&literal, None)])?;
+            operation.evaluate(&stack, &mut registers)?;
+            assert_eq!(expected, registers.load(&stack, &destination_operand)?);
+
+            // Execute the operation.
+            let mut registers = sample_registers(&stack, &function_name, &[(&literal, Some(circuit::Mode::Private))])?;
+            operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+            assert_eq!(expected, registers.load_circuit(&stack, &destination_operand)?.eject_value());
+            assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+            <CurrentAleo as circuit::Environment>::reset();
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_pow_const_constraints() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack and the operation.
+    let stack = sample_stack(5)?;
+    let operation = PowConst::<CurrentNetwork>::from_str("pow.const r0 5 into r1")?;
+    let function_name = Identifier::from_str("run")?;
+
+    // Execute the operation on a private input.
+    let literal = Literal::Field(Field::<CurrentNetwork>::rand(&mut rng));
+    let mut registers = sample_registers(&stack, &function_name, &[(&literal, Some(circuit::Mode::Private))])?;
+    let num_constraints = <CurrentAleo as circuit::Environment>::num_constraints();
+    operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+
+    // Ensure `x^5` is computed with 3 multiplications, as `x^2`, `x^4`, and `x^4 * x`.
+    assert_eq!(3, <CurrentAleo as circuit::Environment>::num_constraints() - num_constraints);
+    assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+    <CurrentAleo as circuit::Environment>::reset();
+    Ok(())
+}
+
+#[test]
+fn test_pow_const_rejects_invalid() -> Result<()> {
+    // Ensure the exponent must be small and nonzero.
+    assert!(sample_stack(0).is_err());
+    assert!(sample_stack(65).is_err());
+
+    // Ensure the input must be a field.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as u64.private;
+    pow.const r0 5 into r1;",
+    )?;
+    assert!(Stack::new(&Process::load()?, &program).is_err());
+    Ok(())
+}
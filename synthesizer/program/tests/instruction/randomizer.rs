@@ -0,0 +1,112 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::sample::sample_registers;
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Register},
+    types::Field,
+};
+use snarkvm_synthesizer_program::{Operand, Program, Randomizer, RegistersLoad, RegistersLoadCircuit};
+use synthesizer_process::{Process, Stack};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 25;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    randomizer r0 5 into r1;
+    output r1 as scalar.private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+#[test]
+fn test_randomizer() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+    // Initialize the operations.
+    let operation = Randomizer::<CurrentNetwork>::from_str("randomizer r0 5 into r1")?;
+    let other = Randomizer::<CurrentNetwork>::from_str("randomizer r0 6 into r1")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(1));
+
+    for _ in 0..ITERATIONS {
+        // Sample the seed.
+        let seed = Literal::Field(Field::<CurrentNetwork>::rand(&mut rng));
+
+        // Evaluate the operation twice.
+        let mut evaluate_registers = sample_registers(&stack, &function_name, &[(&seed, None)])?;
+        operation.evaluate(&stack, &mut evaluate_registers)?;
+        let output_a = evaluate_registers.load(&stack, &destination_operand)?;
+        let mut repeat_registers = sample_registers(&stack, &function_name, &[(&seed, None)])?;
+        operation.evaluate(&stack, &mut repeat_registers)?;
+        assert_eq!(
+            output_a,
+            repeat_registers.load(&stack, &destination_operand)?,
+            "The randomizer is not deterministic"
+        );
+
+        // Ensure a different index yields a different randomizer.
+        let mut other_registers = sample_registers(&stack, &function_name, &[(&seed, None)])?;
+        other.evaluate(&stack, &mut other_registers)?;
+        assert_ne!(output_a, other_registers.load(&stack, &destination_operand)?, "The indices must be distinct");
+
+        // Execute the operation.
+        let mut execute_registers = sample_registers(&stack, &function_name, &[(&seed, Some(circuit::Mode::Private))])?;
+        operation.execute::<CurrentAleo>(&stack, &mut execute_registers)?;
+        let output_b = execute_registers.load_circuit(&stack, &destination_operand)?;
+
+        // Ensure the evaluation and execution agree.
+        assert_eq!(output_a, output_b.eject_value(), "The evaluation and execution disagree");
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "The circuit is not satisfied");
+        <CurrentAleo as circuit::Environment>::reset();
+    }
+    Ok(())
+}
+
+#[test]
+fn test_randomizer_rejects_non_field_seed() -> Result<()> {
+    // Ensure a non-field seed fails to type check.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as scalar.private;
+    randomizer r0 5 into r1;
+    output r1 as scalar.private;",
+    )?;
+    assert!(Stack::new(&Process::load()?, &program).is_err());
+    Ok(())
+}
@@ -0,0 +1,120 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::sample::sample_registers;
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, LiteralType, Plaintext, Register, Value},
+    types::{Boolean, Scalar, U64},
+};
+use snarkvm_synthesizer_program::{Operand, Program, RegistersLoad, RegistersLoadCircuit, Reveal};
+use synthesizer_process::{Process, Stack};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 25;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    input r1 as u64.private;
+    input r2 as scalar.private;
+    reveal r0 r1 r2 into r3;
+    output r3 as boolean.private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+fn check_reveal(
+    stack: &Stack<CurrentNetwork>,
+    commitment: &Literal<CurrentNetwork>,
+    value: &Literal<CurrentNetwork>,
+    randomizer: &Literal<CurrentNetwork>,
+    expected: bool,
+) {
+    // Initialize the operation.
+    let operation = Reveal::<CurrentNetwork>::from_str("reveal r0 r1 r2 into r3").unwrap();
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run").unwrap();
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(3));
+
+    // Evaluate the operation.
+    let values = [(commitment, None), (value, None), (randomizer, None)];
+    let mut evaluate_registers = sample_registers(stack, &function_name, &values).unwrap();
+    operation.evaluate(stack, &mut evaluate_registers).unwrap();
+    let output_a = evaluate_registers.load(stack, &destination_operand).unwrap();
+
+    // Execute the operation.
+    let mode = Some(circuit::Mode::Private);
+    let values = [(commitment, mode), (value, mode), (randomizer, mode)];
+    let mut execute_registers = sample_registers(stack, &function_name, &values).unwrap();
+    operation.execute::<CurrentAleo>(stack, &mut execute_registers).unwrap();
+    let output_b = execute_registers.load_circuit(stack, &destination_operand).unwrap();
+
+    // Check the outputs.
+    let expected = Value::Plaintext(Plaintext::from(Literal::Boolean(Boolean::new(expected))));
+    assert_eq!(expected, output_a, "The result of the evaluation is incorrect");
+    assert_eq!(expected, output_b.eject_value(), "The result of the execution is incorrect");
+    assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "The circuit is not satisfied");
+
+    // Reset the circuit.
+    <CurrentAleo as circuit::Environment>::reset();
+}
+
+#[test]
+fn test_reveal() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+
+    for _ in 0..ITERATIONS {
+        // Sample a value and a randomizer.
+        let value = Literal::U64(U64::rand(&mut rng));
+        let randomizer = Scalar::rand(&mut rng);
+
+        // Compute the commitment, as `commit.bhp256 value randomizer into commitment as field`.
+        let preimage = Value::Plaintext(Plaintext::from(&value)).to_bits_le();
+        let commitment = Literal::Group(CurrentNetwork::commit_to_group_bhp256(&preimage, &randomizer)?)
+            .cast_lossy(LiteralType::Field)?;
+
+        // Check that the valid opening is accepted.
+        check_reveal(&stack, &commitment, &value, &Literal::Scalar(randomizer), true);
+
+        // Check that an opening with an incorrect randomizer is rejected.
+        let wrong_randomizer = Literal::Scalar(Scalar::rand(&mut rng));
+        check_reveal(&stack, &commitment, &value, &wrong_randomizer, false);
+
+        // Check that an opening with an incorrect value is rejected.
+        let wrong_value = Literal::U64(U64::rand(&mut rng));
+        if wrong_value != value {
+            check_reveal(&stack, &commitment, &wrong_value, &Literal::Scalar(randomizer), false);
+        }
+    }
+    Ok(())
+}
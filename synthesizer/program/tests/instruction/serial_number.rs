@@ -0,0 +1,133 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::sample::sample_registers;
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::Field,
+};
+use snarkvm_synthesizer_program::{Operand, Program, RegistersLoad, RegistersLoadCircuit, SerialNumber};
+use synthesizer_process::{Process, Stack};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 25;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    input r1 as field.public;
+    serial_number r0 r1 into r2;
+    output r2 as field.public;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Evaluates and executes the operation on the given secret and commitment, and returns the serial number.
+fn check_serial_number(
+    stack: &Stack<CurrentNetwork>,
+    secret: Field<CurrentNetwork>,
+    commitment: Field<CurrentNetwork>,
+) -> Result<Field<CurrentNetwork>> {
+    // Initialize the operation.
+    let operation = SerialNumber::<CurrentNetwork>::from_str("serial_number r0 r1 into r2")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(2));
+
+    let (secret, commitment) = (Literal::Field(secret), Literal::Field(commitment));
+    let values = [(&secret, Some(circuit::Mode::Private)), (&commitment, Some(circuit::Mode::Public))];
+    let mut registers = sample_registers(stack, &function_name, &values)?;
+
+    // Evaluate the operation.
+    operation.evaluate(stack, &mut registers)?;
+    let output_a = registers.load(stack, &destination_operand)?;
+
+    // Execute the operation.
+    operation.execute::<CurrentAleo>(stack, &mut registers)?;
+    let output_b = registers.load_circuit(stack, &destination_operand)?;
+
+    // Ensure the evaluation and execution agree.
+    assert_eq!(output_a, output_b.eject_value(), "The evaluation and execution disagree");
+    assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "The circuit is not satisfied");
+    <CurrentAleo as circuit::Environment>::reset();
+
+    match output_a {
+        Value::Plaintext(Plaintext::Literal(Literal::Field(output), _)) => Ok(output),
+        _ => bail!("Expected a field output"),
+    }
+}
+
+#[test]
+fn test_serial_number() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    let stack = sample_stack()?;
+
+    for _ in 0..ITERATIONS {
+        let secret = Uniform::rand(&mut rng);
+        let (commitment_a, commitment_b) = (Uniform::rand(&mut rng), Uniform::rand(&mut rng));
+
+        // Ensure the serial number is the Poseidon PRF of the commitment under the secret.
+        let serial_number_a = check_serial_number(&stack, secret, commitment_a)?;
+        assert_eq!(serial_number_a, <CurrentNetwork as Network>::prf_psd2(&secret, &[commitment_a])?);
+
+        // Ensure the same record yields the same serial number.
+        assert_eq!(serial_number_a, check_serial_number(&stack, secret, commitment_a)?);
+
+        // Ensure distinct records under the same secret yield distinct serial numbers.
+        let serial_number_b = check_serial_number(&stack, secret, commitment_b)?;
+        assert_ne!(serial_number_a, serial_number_b);
+
+        // Ensure the same record under a distinct secret yields a distinct serial number.
+        assert_ne!(serial_number_a, check_serial_number(&stack, Uniform::rand(&mut rng), commitment_a)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_serial_number_rejects_non_field_operands() -> Result<()> {
+    for (secret_type, commitment_type) in [("scalar", "field"), ("field", "group"), ("u64", "field")] {
+        // Initialize a program with a non-field operand.
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            r"
+program testing.aleo;
+
+function run:
+    input r0 as {secret_type}.private;
+    input r1 as {commitment_type}.public;
+    serial_number r0 r1 into r2;
+    output r2 as field.public;"
+        ))?;
+
+        // Ensure the program fails to type-check.
+        assert!(Stack::new(&Process::load()?, &program).is_err());
+    }
+    Ok(())
+}
@@ -0,0 +1,154 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::{Field, U8},
+};
+use snarkvm_synthesizer_program::{
+    Operand,
+    Program,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+    Shuffle,
+};
+use synthesizer_process::{Authorization, CallStack, Process, Registers, Stack, StackProgramTypes};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 5;
+const NUM_ELEMENTS: u8 = 8;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as [u8; 8u32].private;
+    input r1 as field.private;
+    shuffle r0 r1 into r2;
+    output r2 as [u8; 8u32].private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Returns the registers for `run`, storing the given array and seed.
+fn sample_registers(
+    stack: &Stack<CurrentNetwork>,
+    array: &Value<CurrentNetwork>,
+    seed: Field<CurrentNetwork>,
+) -> Result<Registers<CurrentNetwork, CurrentAleo>> {
+    // Initialize the registers.
+    let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+        CallStack::evaluate(Authorization::try_from((vec![], vec![]))?)?,
+        stack.get_register_types(&Identifier::from_str("run")?)?.clone(),
+    );
+    // Store the inputs.
+    let inputs = [array.clone(), Value::Plaintext(Plaintext::from(Literal::Field(seed)))];
+    for (index, input) in inputs.into_iter().enumerate() {
+        let register = Register::Locator(index as u64);
+        registers.store(stack, &register, input.clone())?;
+        registers.store_circuit(stack, &register, circuit::Value::new(circuit::Mode::Private, input))?;
+    }
+    Ok(registers)
+}
+
+/// Returns the elements of the given array, as `u8` values.
+fn to_u8s(array: &Value<CurrentNetwork>) -> Vec<u8> {
+    match array {
+        Value::Plaintext(Plaintext::Array(elements, _)) => elements
+            .iter()
+            .map(|element| match element {
+                Plaintext::Literal(Literal::U8(value), _) => **value,
+                _ => panic!("Expected a u8 element"),
+            })
+            .collect(),
+        _ => panic!("Expected an array"),
+    }
+}
+
+#[test]
+fn test_shuffle() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+    // Initialize the operation.
+    let operation = Shuffle::<CurrentNetwork>::from_str("shuffle r0 r1 into r2")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(2));
+
+    // Initialize the array.
+    let input = (0..NUM_ELEMENTS).collect::<Vec<_>>();
+    let array = Value::Plaintext(Plaintext::Array(
+        input.iter().map(|value| Plaintext::from(Literal::U8(U8::new(*value)))).collect(),
+        Default::default(),
+    ));
+
+    for _ in 0..ITERATIONS {
+        let seed = Field::<CurrentNetwork>::rand(&mut rng);
+
+        // Evaluate and execute the operation.
+        let mut registers = sample_registers(&stack, &array, seed)?;
+        operation.evaluate(&stack, &mut registers)?;
+        let output_a = registers.load(&stack, &destination_operand)?;
+        operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+        let output_b = registers.load_circuit(&stack, &destination_operand)?;
+        assert_eq!(output_a, output_b.eject_value());
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+
+        // Ensure the output is a permutation of the input.
+        let mut output = to_u8s(&output_a);
+        output.sort_unstable();
+        assert_eq!(output, input);
+
+        // Ensure the output matches the derived permutation.
+        let mut expected = input.clone();
+        for (index, swap_index) in Shuffle::<CurrentNetwork>::derive_permutation(&seed, expected.len())? {
+            expected.swap(index, swap_index);
+        }
+        assert_eq!(to_u8s(&output_a), expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_shuffle_rejects_non_array() -> Result<()> {
+    // Ensure a non-array operand fails to type check.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as u8.private;
+    input r1 as field.private;
+    shuffle r0 r1 into r2;
+    output r2 as u8.private;",
+    )?;
+    assert!(Stack::new(&Process::load()?, &program).is_err());
+    Ok(())
+}
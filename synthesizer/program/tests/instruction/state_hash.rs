@@ -0,0 +1,111 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::sample::sample_registers;
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::{Field, U64},
+};
+use snarkvm_synthesizer_program::{Operand, Program, RegistersLoad, RegistersLoadCircuit, StateHash};
+use synthesizer_process::{Process, Stack};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 10;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    input r1 as u64.private;
+    input r2 as field.private;
+    state.hash r0 r1 r2 into r3;
+    output r3 as field.private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Returns the attestation of the given register contents, checking that evaluate and execute agree.
+fn attest(stack: &Stack<CurrentNetwork>, inputs: &[Literal<CurrentNetwork>; 3]) -> Result<Value<CurrentNetwork>> {
+    // Initialize the operation.
+    let operation = StateHash::<CurrentNetwork>::from_str("state.hash r0 r1 r2 into r3")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(3));
+
+    // Evaluate the operation.
+    let values = inputs.iter().map(|input| (input, None)).collect::<Vec<_>>();
+    let mut registers = sample_registers(stack, &function_name, &values)?;
+    operation.evaluate(stack, &mut registers)?;
+    let output_a = registers.load(stack, &destination_operand)?;
+
+    // Execute the operation.
+    let values = inputs.iter().map(|input| (input, Some(circuit::Mode::Private))).collect::<Vec<_>>();
+    let mut registers = sample_registers(stack, &function_name, &values)?;
+    operation.execute::<CurrentAleo>(stack, &mut registers)?;
+    let output_b = registers.load_circuit(stack, &destination_operand)?;
+
+    // Check the outputs.
+    assert_eq!(output_a, output_b.eject_value());
+    assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+    <CurrentAleo as circuit::Environment>::reset();
+    Ok(output_a)
+}
+
+#[test]
+fn test_state_hash() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+
+    for _ in 0..ITERATIONS {
+        // Sample the register contents.
+        let (a, b) = (Field::<CurrentNetwork>::rand(&mut rng), Field::<CurrentNetwork>::rand(&mut rng));
+        let n = U64::<CurrentNetwork>::rand(&mut rng);
+        let inputs = [Literal::Field(a), Literal::U64(n), Literal::Field(b)];
+
+        // Ensure the attestation is the Poseidon8 hash of the length-prefixed field encodings.
+        let mut preimage = Vec::new();
+        for input in &inputs {
+            let fields = Value::Plaintext(Plaintext::from(input)).to_fields()?;
+            preimage.push(Field::from_u64(fields.len() as u64));
+            preimage.extend(fields);
+        }
+        let expected = Value::Plaintext(Plaintext::from(Literal::Field(CurrentNetwork::hash_psd8(&preimage)?)));
+        let digest = attest(&stack, &inputs)?;
+        assert_eq!(expected, digest);
+
+        // Ensure different register contents yield different digests.
+        let changed = attest(&stack, &[Literal::Field(a), Literal::U64(n), Literal::Field(a + Field::one())])?;
+        assert_ne!(digest, changed);
+        let swapped = attest(&stack, &[Literal::Field(b), Literal::U64(n), Literal::Field(a)])?;
+        assert_ne!(digest, swapped);
+    }
+    Ok(())
+}
@@ -0,0 +1,155 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use circuit::{AleoV0, Eject, Inject};
+use console::{
+    account::{Address, PrivateKey},
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::{Boolean, Group, U64},
+};
+use snarkvm_synthesizer_program::{
+    Operand,
+    Program,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+    Ternary,
+};
+use synthesizer_process::{Authorization, CallStack, Process, Registers, Stack, StackProgramTypes};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 10;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function run:
+    input r0 as boolean.private;
+    input r1 as token.record;
+    input r2 as token.record;
+    ternary r0 r1 r2 into r3;
+    output r3 as token.record;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Samples a `token` record. Note: Do not replicate this for real program use, it is insecure.
+fn sample_record(rng: &mut TestRng) -> Result<Value<CurrentNetwork>> {
+    let owner = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+    let amount = U64::<CurrentNetwork>::rand(rng);
+    let nonce = Group::<CurrentNetwork>::rand(rng);
+    Value::from_str(&format!("{{ owner: {owner}.private, amount: {amount}.private, _nonce: {nonce}.public }}"))
+}
+
+fn check_ternary(
+    stack: &Stack<CurrentNetwork>,
+    condition: bool,
+    first: &Value<CurrentNetwork>,
+    second: &Value<CurrentNetwork>,
+) -> Result<()> {
+    // Initialize the operation.
+    let operation = Ternary::<CurrentNetwork>::from_str("ternary r0 r1 r2 into r3")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(3));
+
+    // Initialize the registers.
+    let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+        CallStack::evaluate(Authorization::try_from((vec![], vec![]))?)?,
+        stack.get_register_types(&function_name)?.clone(),
+    );
+    // Store the inputs in the console and circuit registers.
+    let condition_value = Value::Plaintext(Plaintext::from(Literal::Boolean(Boolean::new(condition))));
+    for (index, value) in [&condition_value, first, second].into_iter().enumerate() {
+        let register = Register::Locator(index as u64);
+        registers.store(stack, &register, value.clone())?;
+        registers.store_circuit(stack, &register, circuit::Value::new(circuit::Mode::Private, value.clone()))?;
+    }
+
+    // Evaluate the operation.
+    operation.evaluate(stack, &mut registers)?;
+    let output_a = registers.load(stack, &destination_operand)?;
+
+    // Execute the operation.
+    operation.execute::<CurrentAleo>(stack, &mut registers)?;
+    let output_b = registers.load_circuit(stack, &destination_operand)?;
+
+    // Check the outputs.
+    let expected = if condition { first } else { second };
+    assert_eq!(expected, &output_a, "The result of the evaluation is incorrect");
+    assert_eq!(expected, &output_b.eject_value(), "The result of the execution is incorrect");
+    assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "The circuit is not satisfied");
+
+    // Reset the circuit.
+    <CurrentAleo as circuit::Environment>::reset();
+    Ok(())
+}
+
+#[test]
+fn test_ternary_record() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+
+    for _ in 0..ITERATIONS {
+        // Sample two records.
+        let first = sample_record(&mut rng)?;
+        let second = sample_record(&mut rng)?;
+
+        // Check that the correct record is selected.
+        check_ternary(&stack, true, &first, &second)?;
+        check_ternary(&stack, false, &first, &second)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_ternary_mismatched_types() -> Result<()> {
+    // Initialize a program that selects between a record and a literal.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function run:
+    input r0 as boolean.private;
+    input r1 as token.record;
+    input r2 as u64.private;
+    ternary r0 r1 r2 into r3;",
+    )?;
+    // Ensure the stack rejects the program.
+    assert!(Stack::new(&Process::load()?, &program).is_err());
+    Ok(())
+}
@@ -0,0 +1,158 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::{Field, Scalar, U64},
+};
+use snarkvm_synthesizer_program::{
+    Operand,
+    Program,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+    VerifyCommitments,
+};
+use synthesizer_process::{Authorization, CallStack, Process, Registers, Stack, StackProgramTypes};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const NUM_ELEMENTS: usize = 4;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as [field; 4u32].private;
+    input r1 as [u64; 4u32].private;
+    input r2 as [scalar; 4u32].private;
+    verify.commitments r0 r1 r2 into r3;
+    output r3 as boolean.private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Returns the given literals as an array value.
+fn to_array(literals: impl IntoIterator<Item = Literal<CurrentNetwork>>) -> Value<CurrentNetwork> {
+    Value::Plaintext(Plaintext::Array(literals.into_iter().map(Plaintext::from).collect(), Default::default()))
+}
+
+/// Evaluates and executes `verify.commitments` on the given openings, returning the output.
+fn check_verify_commitments(
+    stack: &Stack<CurrentNetwork>,
+    commitments: &[Field<CurrentNetwork>],
+    values: &[U64<CurrentNetwork>],
+    randomizers: &[Scalar<CurrentNetwork>],
+) -> Result<bool> {
+    // Initialize the operation.
+    let operation = VerifyCommitments::<CurrentNetwork>::from_str("verify.commitments r0 r1 r2 into r3")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(3));
+
+    // Initialize the registers.
+    let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+        CallStack::evaluate(Authorization::try_from((vec![], vec![]))?)?,
+        stack.get_register_types(&Identifier::from_str("run")?)?.clone(),
+    );
+    // Store the inputs.
+    let inputs = [
+        to_array(commitments.iter().map(|commitment| Literal::Field(*commitment))),
+        to_array(values.iter().map(|value| Literal::U64(*value))),
+        to_array(randomizers.iter().map(|randomizer| Literal::Scalar(*randomizer))),
+    ];
+    for (index, input) in inputs.into_iter().enumerate() {
+        let register = Register::Locator(index as u64);
+        registers.store(stack, &register, input.clone())?;
+        registers.store_circuit(stack, &register, circuit::Value::new(circuit::Mode::Private, input))?;
+    }
+
+    // Evaluate and execute the operation.
+    operation.evaluate(stack, &mut registers)?;
+    let output_a = registers.load(stack, &destination_operand)?;
+    operation.execute::<CurrentAleo>(stack, &mut registers)?;
+    let output_b = registers.load_circuit(stack, &destination_operand)?;
+    assert_eq!(output_a, output_b.eject_value());
+    assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+    <CurrentAleo as circuit::Environment>::reset();
+
+    match output_a {
+        Value::Plaintext(Plaintext::Literal(Literal::Boolean(output), _)) => Ok(*output),
+        _ => bail!("Expected a boolean output"),
+    }
+}
+
+#[test]
+fn test_verify_commitments() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Initialize the stack.
+    let stack = sample_stack()?;
+
+    // Sample the openings, and compute the commitments as `commit.bhp256 value randomizer into c as field`.
+    let values = (0..NUM_ELEMENTS).map(|_| U64::rand(&mut rng)).collect::<Vec<_>>();
+    let randomizers = (0..NUM_ELEMENTS).map(|_| Scalar::rand(&mut rng)).collect::<Vec<_>>();
+    let commitments = values
+        .iter()
+        .zip_eq(&randomizers)
+        .map(|(value, randomizer)| {
+            let value = Plaintext::<CurrentNetwork>::from(Literal::U64(*value));
+            CurrentNetwork::commit_bhp256(&value.to_bits_le(), randomizer)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Ensure a batch of valid openings is accepted.
+    assert!(check_verify_commitments(&stack, &commitments, &values, &randomizers)?);
+
+    // Ensure a batch with one invalid opening is rejected.
+    let mut invalid_values = values.clone();
+    invalid_values[NUM_ELEMENTS / 2] = U64::new(*invalid_values[NUM_ELEMENTS / 2] ^ 1);
+    assert!(!check_verify_commitments(&stack, &commitments, &invalid_values, &randomizers)?);
+
+    // Ensure a batch with one invalid randomizer is rejected.
+    let mut invalid_randomizers = randomizers.clone();
+    invalid_randomizers[0] = Scalar::rand(&mut rng);
+    assert!(!check_verify_commitments(&stack, &commitments, &values, &invalid_randomizers)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_verify_commitments_rejects_mismatched_lengths() -> Result<()> {
+    // Ensure arrays of different lengths fail to type check.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as [field; 4u32].private;
+    input r1 as [u64; 3u32].private;
+    input r2 as [scalar; 4u32].private;
+    verify.commitments r0 r1 r2 into r3;
+    output r3 as boolean.private;",
+    )?;
+    assert!(Stack::new(&Process::load()?, &program).is_err());
+    Ok(())
+}
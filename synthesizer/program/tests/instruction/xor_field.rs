@@ -0,0 +1,164 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::sample::sample_registers;
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::Field,
+};
+use snarkvm_synthesizer_program::{Operand, Program, RegistersLoad, RegistersLoadCircuit, XorField};
+use synthesizer_process::{Process, Stack};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 25;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack() -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    input r1 as field.private;
+    xor.field r0 r1 into r2;
+    output r2 as field.private;",
+    )?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Evaluates and executes the operation on the given fields, and returns the outcome.
+fn check_xor_field(
+    stack: &Stack<CurrentNetwork>,
+    first: Field<CurrentNetwork>,
+    second: Field<CurrentNetwork>,
+) -> Result<Field<CurrentNetwork>> {
+    // Initialize the operation.
+    let operation = XorField::<CurrentNetwork>::from_str("xor.field r0 r1 into r2")?;
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(Register::Locator(2));
+
+    let (first, second) = (Literal::Field(first), Literal::Field(second));
+    let mode = Some(circuit::Mode::Private);
+    let mut registers = sample_registers(stack, &function_name, &[(&first, mode), (&second, mode)])?;
+
+    // Evaluate the operation.
+    operation.evaluate(stack, &mut registers)?;
+    let output_a = registers.load(stack, &destination_operand)?;
+
+    // Execute the operation.
+    operation.execute::<CurrentAleo>(stack, &mut registers)?;
+    let output_b = registers.load_circuit(stack, &destination_operand)?;
+
+    // Ensure the evaluation and execution agree.
+    assert_eq!(output_a, output_b.eject_value(), "The evaluation and execution disagree");
+    assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "The circuit is not satisfied");
+    <CurrentAleo as circuit::Environment>::reset();
+
+    match output_a {
+        Value::Plaintext(Plaintext::Literal(Literal::Field(output), _)) => Ok(output),
+        _ => bail!("Expected a field output"),
+    }
+}
+
+#[test]
+fn test_xor_field_known_patterns() -> Result<()> {
+    let stack = sample_stack()?;
+    let field = |string: &str| Field::<CurrentNetwork>::from_str(string);
+
+    // 0b1100 ^ 0b1010 == 0b0110
+    assert_eq!(field("6field")?, check_xor_field(&stack, field("12field")?, field("10field")?)?);
+    // 0b1111 ^ 0b0101 == 0b1010
+    assert_eq!(field("10field")?, check_xor_field(&stack, field("15field")?, field("5field")?)?);
+    // 2^128 ^ 1 == 2^128 + 1
+    let two_pow_128 = field("340282366920938463463374607431768211456field")?;
+    assert_eq!(two_pow_128 + Field::one(), check_xor_field(&stack, two_pow_128, Field::one())?);
+    // 3 ^ 1 == 2, whereas 3 + 1 == 4 in the field.
+    assert_eq!(field("2field")?, check_xor_field(&stack, field("3field")?, field("1field")?)?);
+    Ok(())
+}
+
+#[test]
+fn test_xor_field() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    let stack = sample_stack()?;
+
+    for _ in 0..ITERATIONS {
+        let (a, b) = (rng.gen::<u128>(), rng.gen::<u128>());
+        let (first, second) = (Field::<CurrentNetwork>::from_u128(a), Field::<CurrentNetwork>::from_u128(b));
+
+        // Ensure the outcome matches the XOR of the integers.
+        assert_eq!(Field::from_u128(a ^ b), check_xor_field(&stack, first, second)?);
+        // Ensure `x ^ x == 0` and `x ^ 0 == x`.
+        let x = Uniform::rand(&mut rng);
+        assert_eq!(Field::zero(), check_xor_field(&stack, x, x)?);
+        assert_eq!(x, check_xor_field(&stack, x, Field::zero())?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_xor_field_halts_on_non_canonical_outcome() -> Result<()> {
+    let stack = sample_stack()?;
+
+    // Initialize the operation.
+    let operation = XorField::<CurrentNetwork>::from_str("xor.field r0 r1 into r2")?;
+    let function_name = Identifier::from_str("run")?;
+
+    // As the modulus is odd, `(modulus - 1) ^ 1` is the modulus, which is not a valid field element.
+    let (first, second) = (Literal::Field(-Field::<CurrentNetwork>::one()), Literal::Field(Field::one()));
+    let mode = Some(circuit::Mode::Private);
+
+    // Ensure evaluation fails.
+    let mut registers = sample_registers(&stack, &function_name, &[(&first, mode), (&second, mode)])?;
+    assert!(operation.evaluate(&stack, &mut registers).is_err());
+
+    // Ensure execution is not satisfied.
+    operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+    assert!(!<CurrentAleo as circuit::Environment>::is_satisfied());
+    <CurrentAleo as circuit::Environment>::reset();
+    Ok(())
+}
+
+#[test]
+fn test_xor_field_rejects_non_field_operands() -> Result<()> {
+    // Initialize a program with an integer operand.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    input r1 as u64.private;
+    xor.field r0 r1 into r2;
+    output r2 as field.private;",
+    )?;
+
+    // Ensure the program fails to type-check.
+    assert!(Stack::new(&Process::load()?, &program).is_err());
+    Ok(())
+}